@@ -0,0 +1,26 @@
+-- Accounts whose emails only differ in case or surrounding spaces cannot be merged blindly, they
+-- own reviews, claims and tokens. The migration stops and lists them so they are settled by hand
+DO
+$$
+    DECLARE
+        conflicts TEXT;
+    BEGIN
+        SELECT STRING_AGG(normalized || ' (' || ids || ')', '; ' ORDER BY normalized)
+        INTO conflicts
+        FROM (SELECT LOWER(TRIM(email))                                          AS normalized,
+                     STRING_AGG(id || ' ' || email, ', ' ORDER BY created_at, id) AS ids
+              FROM users
+              GROUP BY LOWER(TRIM(email))
+              HAVING COUNT(*) > 1) AS duplicates;
+
+        IF conflicts IS NOT NULL THEN
+            RAISE EXCEPTION 'users share an email once lowercased and trimmed: %', conflicts
+                USING HINT = 'merge or rename these accounts, then run the migration again';
+        END IF;
+    END
+$$;
+
+UPDATE users
+SET email = LOWER(TRIM(email));
+
+CREATE UNIQUE INDEX IF NOT EXISTS users_email_unique_idx ON users (LOWER(email));
@@ -0,0 +1,55 @@
+CREATE TABLE IF NOT EXISTS roles
+(
+    name        VARCHAR(50) PRIMARY KEY,
+    description VARCHAR(255) NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS permissions
+(
+    name        VARCHAR(100) PRIMARY KEY,
+    description VARCHAR(255) NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS role_permissions
+(
+    role_name       VARCHAR(50)  NOT NULL REFERENCES roles (name) ON DELETE CASCADE,
+    permission_name VARCHAR(100) NOT NULL REFERENCES permissions (name) ON DELETE CASCADE,
+    PRIMARY KEY (role_name, permission_name)
+);
+
+CREATE TABLE IF NOT EXISTS user_roles
+(
+    user_id    VARCHAR(36) NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    role_name  VARCHAR(50) NOT NULL REFERENCES roles (name) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (user_id, role_name)
+);
+
+INSERT INTO roles (name, description)
+VALUES ('admin', 'Full access to the platform'),
+       ('moderator', 'Moderates user generated content'),
+       ('business-owner', 'Manages the customer services they own'),
+       ('regular-user', 'Default role for signed up users');
+
+INSERT INTO permissions (name, description)
+VALUES ('users:update-any', 'Update any user'),
+       ('users:delete-any', 'Delete any user'),
+       ('roles:manage', 'Grant and revoke user roles'),
+       ('customer-services:create', 'Create customer services'),
+       ('customer-services:update-any', 'Update any customer service'),
+       ('customer-services:delete-any', 'Delete any customer service');
+
+INSERT INTO role_permissions (role_name, permission_name)
+VALUES ('admin', 'users:update-any'),
+       ('admin', 'users:delete-any'),
+       ('admin', 'roles:manage'),
+       ('admin', 'customer-services:create'),
+       ('admin', 'customer-services:update-any'),
+       ('admin', 'customer-services:delete-any'),
+       ('moderator', 'customer-services:update-any'),
+       ('business-owner', 'customer-services:create'),
+       ('regular-user', 'customer-services:create');
+
+INSERT INTO user_roles (user_id, role_name, created_at)
+SELECT id, 'regular-user', NOW()
+FROM users;
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS customer_services
+(
+    id          VARCHAR(36) PRIMARY KEY,
+    name        VARCHAR(255)     NOT NULL,
+    description VARCHAR(2048)    NOT NULL DEFAULT '',
+    latitude    DOUBLE PRECISION NOT NULL,
+    longitude   DOUBLE PRECISION NOT NULL,
+    phone       VARCHAR(20)      NOT NULL,
+    website     VARCHAR(2048)    NULL,
+    photos      JSONB            NOT NULL DEFAULT '[]',
+    tags        JSONB            NOT NULL DEFAULT '{}',
+    categories  TEXT[]           NOT NULL DEFAULT '{}',
+    deleted     BOOLEAN          NOT NULL DEFAULT FALSE,
+    created_at  TIMESTAMPTZ      NOT NULL,
+    updated_at  TIMESTAMPTZ      NOT NULL,
+    deleted_at  TIMESTAMPTZ      NULL
+);
+
+CREATE INDEX IF NOT EXISTS customer_services_created_at_idx ON customer_services (created_at) WHERE deleted = FALSE;
@@ -0,0 +1 @@
+DROP INDEX IF EXISTS customer_services_location_idx;
@@ -0,0 +1,2 @@
+-- Backs the bounding box prefilter of the nearby search
+CREATE INDEX IF NOT EXISTS customer_services_location_idx ON customer_services (latitude, longitude) WHERE deleted = FALSE;
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS customer_services_geohash_8_idx;
+DROP INDEX IF EXISTS customer_services_geohash_6_idx;
+DROP INDEX IF EXISTS customer_services_geohash_4_idx;
+ALTER TABLE customer_services
+    DROP COLUMN geohash_8,
+    DROP COLUMN geohash_6,
+    DROP COLUMN geohash_4;
@@ -0,0 +1,70 @@
+ALTER TABLE customer_services
+    ADD COLUMN geohash_4 VARCHAR(4) NOT NULL DEFAULT '',
+    ADD COLUMN geohash_6 VARCHAR(6) NOT NULL DEFAULT '',
+    ADD COLUMN geohash_8 VARCHAR(8) NOT NULL DEFAULT '';
+
+-- Same bit interleaving as domain::vo::geohash::Geohash::encode, only used to backfill
+CREATE FUNCTION customer_services_geohash(lat DOUBLE PRECISION, lon DOUBLE PRECISION, hash_length INTEGER)
+    RETURNS TEXT AS
+$$
+DECLARE
+    base32    CONSTANT TEXT := '0123456789bcdefghjkmnpqrstuvwxyz';
+    min_lat   DOUBLE PRECISION := -90;
+    max_lat   DOUBLE PRECISION := 90;
+    min_lon   DOUBLE PRECISION := -180;
+    max_lon   DOUBLE PRECISION := 180;
+    mid       DOUBLE PRECISION;
+    hash      TEXT := '';
+    bits      INTEGER := 0;
+    bit_count INTEGER := 0;
+    even_bit  BOOLEAN := TRUE;
+BEGIN
+    WHILE length(hash) < hash_length LOOP
+        IF even_bit THEN
+            mid := (min_lon + max_lon) / 2;
+            IF lon >= mid THEN
+                bits := bits * 2 + 1;
+                min_lon := mid;
+            ELSE
+                bits := bits * 2;
+                max_lon := mid;
+            END IF;
+        ELSE
+            mid := (min_lat + max_lat) / 2;
+            IF lat >= mid THEN
+                bits := bits * 2 + 1;
+                min_lat := mid;
+            ELSE
+                bits := bits * 2;
+                max_lat := mid;
+            END IF;
+        END IF;
+        even_bit := NOT even_bit;
+        bit_count := bit_count + 1;
+        IF bit_count = 5 THEN
+            hash := hash || substr(base32, bits + 1, 1);
+            bits := 0;
+            bit_count := 0;
+        END IF;
+    END LOOP;
+    RETURN hash;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+UPDATE customer_services
+SET geohash_8 = customer_services_geohash(latitude, longitude, 8);
+UPDATE customer_services
+SET geohash_4 = LEFT(geohash_8, 4),
+    geohash_6 = LEFT(geohash_8, 6);
+
+DROP FUNCTION customer_services_geohash(DOUBLE PRECISION, DOUBLE PRECISION, INTEGER);
+
+ALTER TABLE customer_services
+    ALTER COLUMN geohash_4 DROP DEFAULT,
+    ALTER COLUMN geohash_6 DROP DEFAULT,
+    ALTER COLUMN geohash_8 DROP DEFAULT;
+
+-- pattern_ops so the same index serves cell equality and LIKE 'prefix%' lookups
+CREATE INDEX customer_services_geohash_4_idx ON customer_services (geohash_4 varchar_pattern_ops) WHERE deleted = FALSE;
+CREATE INDEX customer_services_geohash_6_idx ON customer_services (geohash_6 varchar_pattern_ops) WHERE deleted = FALSE;
+CREATE INDEX customer_services_geohash_8_idx ON customer_services (geohash_8 varchar_pattern_ops) WHERE deleted = FALSE;
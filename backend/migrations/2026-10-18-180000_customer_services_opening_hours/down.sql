@@ -0,0 +1,3 @@
+ALTER TABLE customer_services
+    DROP COLUMN timezone,
+    DROP COLUMN opening_hours;
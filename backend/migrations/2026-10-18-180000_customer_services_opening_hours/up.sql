@@ -0,0 +1,3 @@
+ALTER TABLE customer_services
+    ADD COLUMN opening_hours VARCHAR(1024),
+    ADD COLUMN timezone      VARCHAR(64) NOT NULL DEFAULT 'America/Sao_Paulo';
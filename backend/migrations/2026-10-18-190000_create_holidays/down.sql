@@ -0,0 +1,5 @@
+DELETE FROM permissions WHERE name = 'holidays:manage';
+ALTER TABLE customer_services
+    DROP COLUMN city,
+    DROP COLUMN state;
+DROP TABLE IF EXISTS holidays;
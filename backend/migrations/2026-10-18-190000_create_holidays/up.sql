@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS holidays
+(
+    id         VARCHAR(36) PRIMARY KEY,
+    name       VARCHAR(120) NOT NULL,
+    date       DATE         NOT NULL,
+    recurring  BOOLEAN      NOT NULL DEFAULT FALSE,
+    state      VARCHAR(2),
+    city       VARCHAR(120),
+    created_at TIMESTAMPTZ  NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS holidays_date_idx ON holidays (date);
+
+-- Where a place is, so state and municipal holidays can be matched against it
+ALTER TABLE customer_services
+    ADD COLUMN state VARCHAR(2),
+    ADD COLUMN city  VARCHAR(120);
+
+INSERT INTO permissions (name, description)
+VALUES ('holidays:manage', 'Create and delete regional holidays');
+
+INSERT INTO role_permissions (role_name, permission_name)
+VALUES ('admin', 'holidays:manage');
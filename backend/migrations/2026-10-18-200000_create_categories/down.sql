@@ -0,0 +1,3 @@
+DELETE FROM permissions WHERE name = 'categories:manage';
+DROP INDEX IF EXISTS customer_services_categories_idx;
+DROP TABLE IF EXISTS categories;
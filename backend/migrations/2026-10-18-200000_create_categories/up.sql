@@ -0,0 +1,56 @@
+CREATE TABLE IF NOT EXISTS categories
+(
+    id          VARCHAR(36) PRIMARY KEY,
+    slug        VARCHAR(50) NOT NULL CONSTRAINT categories_slug_unique UNIQUE,
+    parent_slug VARCHAR(50) NULL REFERENCES categories (slug) ON DELETE RESTRICT,
+    labels      JSONB       NOT NULL DEFAULT '{}',
+    created_at  TIMESTAMPTZ NOT NULL,
+    updated_at  TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS categories_parent_slug_idx ON categories (parent_slug);
+
+-- Free-form categories become slugs, each distinct one a top level category labeled with its
+-- original text. Truncating can leave a trailing hyphen and symbols-only names leave nothing, so
+-- the slug is trimmed again after LEFT and empty ones are dropped, every slug kept must be a valid
+-- category. The function only lives for this migration, both statements below slug the same way
+CREATE FUNCTION pg_temp.category_slug(category TEXT)
+    RETURNS TEXT
+    LANGUAGE sql
+    IMMUTABLE
+AS
+$$
+SELECT TRIM(BOTH '-' FROM LEFT(TRIM(BOTH '-' FROM REGEXP_REPLACE(
+        LOWER(TRANSLATE(category, 'áàâãäéèêëíìîïóòôõöúùûüçñÁÀÂÃÄÉÈÊËÍÌÎÏÓÒÔÕÖÚÙÛÜÇÑ',
+                        'aaaaaeeeeiiiiooooouuuucnAAAAAEEEEIIIIOOOOOUUUUCN')),
+        '[^a-z0-9]+', '-', 'g')), 50))
+$$;
+
+-- Spellings ending in the same slug share a category, labeled with the most used one
+INSERT INTO categories (id, slug, parent_slug, labels, created_at, updated_at)
+SELECT GEN_RANDOM_UUID()::VARCHAR, slug, NULL, JSONB_BUILD_OBJECT('pt-BR', label), NOW(), NOW()
+FROM (SELECT DISTINCT ON (slug) slug, label
+      FROM (SELECT pg_temp.category_slug(category) AS slug,
+                   LEFT(TRIM(category), 120)       AS label,
+                   COUNT(*)                        AS uses
+            FROM customer_services,
+                 UNNEST(categories) AS category
+            GROUP BY 1, 2) AS spellings
+      WHERE slug <> ''
+      ORDER BY slug, uses DESC, label) AS used
+ON CONFLICT (slug) DO NOTHING;
+
+UPDATE customer_services
+SET categories = ARRAY(
+        SELECT DISTINCT slug
+        FROM (SELECT pg_temp.category_slug(category) AS slug
+              FROM UNNEST(categories) AS category) AS slugs
+        WHERE slug <> '');
+
+CREATE INDEX IF NOT EXISTS customer_services_categories_idx ON customer_services USING GIN (categories);
+
+INSERT INTO permissions (name, description)
+VALUES ('categories:manage', 'Create, update and delete categories of the taxonomy');
+
+INSERT INTO role_permissions (role_name, permission_name)
+VALUES ('admin', 'categories:manage');
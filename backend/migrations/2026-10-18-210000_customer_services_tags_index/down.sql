@@ -0,0 +1 @@
+DROP INDEX IF EXISTS customer_services_tags_idx;
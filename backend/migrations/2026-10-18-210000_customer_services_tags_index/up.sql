@@ -0,0 +1,2 @@
+-- jsonb_path_ops only serves containment (@>), which is the only operator tag filters use
+CREATE INDEX IF NOT EXISTS customer_services_tags_idx ON customer_services USING GIN (tags jsonb_path_ops);
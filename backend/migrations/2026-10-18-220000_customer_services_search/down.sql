@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS customer_services_search_idx;
+DROP FUNCTION IF EXISTS customer_service_search_vector(TEXT, TEXT, TEXT[], JSONB);
+DROP TEXT SEARCH CONFIGURATION IF EXISTS portuguese_unaccent;
+DROP EXTENSION IF EXISTS unaccent;
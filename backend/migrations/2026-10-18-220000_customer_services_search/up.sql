@@ -0,0 +1,30 @@
+CREATE EXTENSION IF NOT EXISTS unaccent;
+
+-- Portuguese stemming on accent-folded words, "São João" and "sao joao" share lexemes
+CREATE TEXT SEARCH CONFIGURATION portuguese_unaccent (COPY = pg_catalog.portuguese);
+ALTER TEXT SEARCH CONFIGURATION portuguese_unaccent
+    ALTER MAPPING FOR hword, hword_part, word WITH unaccent, portuguese_stem;
+
+-- Name weighs most, then categories, tags and description. Tags set to "no" are left out and
+-- "yes" only indexes the key, so "wifi" finds places that have it
+CREATE OR REPLACE FUNCTION customer_service_search_vector(TEXT, TEXT, TEXT[], JSONB)
+    RETURNS TSVECTOR
+    LANGUAGE sql
+    IMMUTABLE
+    PARALLEL SAFE
+AS
+$$
+SELECT SETWEIGHT(TO_TSVECTOR('public.portuguese_unaccent', COALESCE($1, '')), 'A') ||
+       SETWEIGHT(TO_TSVECTOR('public.portuguese_unaccent',
+                             REPLACE(ARRAY_TO_STRING($3, ' '), '-', ' ')), 'B') ||
+       SETWEIGHT(TO_TSVECTOR('public.portuguese_unaccent', COALESCE(
+               (SELECT STRING_AGG(TRANSLATE(tag.key, ':_', '  ') ||
+                                  CASE WHEN tag.value = 'yes' THEN '' ELSE ' ' || tag.value END, ' ')
+                FROM JSONB_EACH_TEXT($4) AS tag
+                WHERE tag.value <> 'no'), '')), 'C') ||
+       SETWEIGHT(TO_TSVECTOR('public.portuguese_unaccent', COALESCE($2, '')), 'D')
+$$;
+
+CREATE INDEX IF NOT EXISTS customer_services_search_idx
+    ON customer_services USING GIN (customer_service_search_vector(name, description, categories, tags))
+    WHERE deleted = FALSE;
@@ -0,0 +1,5 @@
+DELETE FROM permissions WHERE name = 'reviews:delete-any';
+ALTER TABLE customer_services
+    DROP COLUMN IF EXISTS rating_sum,
+    DROP COLUMN IF EXISTS rating_count;
+DROP TABLE IF EXISTS reviews;
@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS reviews
+(
+    id                  VARCHAR(36)   PRIMARY KEY,
+    customer_service_id VARCHAR(36)   NOT NULL REFERENCES customer_services (id),
+    author_id           VARCHAR(36)   NOT NULL REFERENCES users (id),
+    rating              SMALLINT      NOT NULL CHECK (rating BETWEEN 1 AND 5),
+    text                VARCHAR(2048) NOT NULL DEFAULT '',
+    deleted             BOOLEAN       NOT NULL DEFAULT FALSE,
+    created_at          TIMESTAMPTZ   NOT NULL,
+    updated_at          TIMESTAMPTZ   NOT NULL,
+    deleted_at          TIMESTAMPTZ   NULL
+);
+
+-- Deleted reviews are kept, their author may review the place again
+CREATE UNIQUE INDEX IF NOT EXISTS reviews_customer_service_author_unique
+    ON reviews (customer_service_id, author_id) WHERE deleted = FALSE;
+CREATE INDEX IF NOT EXISTS reviews_customer_service_created_at_idx
+    ON reviews (customer_service_id, created_at DESC) WHERE deleted = FALSE;
+
+ALTER TABLE customer_services
+    ADD COLUMN IF NOT EXISTS rating_count INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS rating_sum   INTEGER NOT NULL DEFAULT 0;
+
+INSERT INTO permissions (name, description)
+VALUES ('reviews:delete-any', 'Delete any review');
+
+INSERT INTO role_permissions (role_name, permission_name)
+VALUES ('admin', 'reviews:delete-any'),
+       ('moderator', 'reviews:delete-any');
@@ -0,0 +1,7 @@
+DELETE FROM permissions WHERE name = 'moderation:decide';
+DROP INDEX IF EXISTS reviews_author_created_at_idx;
+ALTER TABLE customer_services
+    DROP COLUMN IF EXISTS hidden;
+ALTER TABLE reviews
+    DROP COLUMN IF EXISTS hidden;
+DROP TABLE IF EXISTS moderation_flags;
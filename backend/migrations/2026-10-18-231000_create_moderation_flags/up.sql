@@ -0,0 +1,33 @@
+CREATE TABLE IF NOT EXISTS moderation_flags
+(
+    id           VARCHAR(36)   PRIMARY KEY,
+    subject_type VARCHAR(20)   NOT NULL CHECK (subject_type IN ('review', 'customer-service')),
+    subject_id   VARCHAR(36)   NOT NULL,
+    reason       VARCHAR(20)   NOT NULL,
+    reporter_id  VARCHAR(36)   NULL REFERENCES users (id),
+    note         VARCHAR(2048) NOT NULL DEFAULT '',
+    status       VARCHAR(10)   NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'rejected')),
+    moderator_id VARCHAR(36)   NULL REFERENCES users (id),
+    decided_at   TIMESTAMPTZ   NULL,
+    created_at   TIMESTAMPTZ   NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS moderation_flags_pending_idx ON moderation_flags (created_at) WHERE status = 'pending';
+CREATE INDEX IF NOT EXISTS moderation_flags_subject_idx ON moderation_flags (subject_type, subject_id);
+
+-- Rejected content is hidden from public reads, the rows stay for audit
+ALTER TABLE reviews
+    ADD COLUMN IF NOT EXISTS hidden BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE customer_services
+    ADD COLUMN IF NOT EXISTS hidden BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Review bursts are counted per author over the last hour
+CREATE INDEX IF NOT EXISTS reviews_author_created_at_idx ON reviews (author_id, created_at);
+
+INSERT INTO permissions (name, description)
+VALUES ('moderation:decide', 'Approve or reject flagged content');
+
+INSERT INTO role_permissions (role_name, permission_name)
+VALUES ('admin', 'moderation:decide'),
+       ('moderator', 'moderation:decide');
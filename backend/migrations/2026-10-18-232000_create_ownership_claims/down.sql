@@ -0,0 +1,2 @@
+DELETE FROM permissions WHERE name = 'claims:decide';
+DROP TABLE IF EXISTS ownership_claims;
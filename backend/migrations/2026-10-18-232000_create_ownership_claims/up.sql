@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS ownership_claims
+(
+    id                  VARCHAR(36) PRIMARY KEY,
+    customer_service_id VARCHAR(36) NOT NULL REFERENCES customer_services (id),
+    claimant_id         VARCHAR(36) NOT NULL REFERENCES users (id),
+    tax_id              VARCHAR(14) NOT NULL,
+    status              VARCHAR(10) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'rejected')),
+    reviewer_id         VARCHAR(36) NULL REFERENCES users (id),
+    decided_at          TIMESTAMPTZ NULL,
+    created_at          TIMESTAMPTZ NOT NULL
+);
+
+-- A claimant waits on a single claim per place, and a place has at most one owner
+CREATE UNIQUE INDEX IF NOT EXISTS ownership_claims_pending_unique
+    ON ownership_claims (customer_service_id, claimant_id) WHERE status = 'pending';
+CREATE UNIQUE INDEX IF NOT EXISTS ownership_claims_owner_unique
+    ON ownership_claims (customer_service_id) WHERE status = 'approved';
+CREATE INDEX IF NOT EXISTS ownership_claims_pending_idx ON ownership_claims (created_at) WHERE status = 'pending';
+
+INSERT INTO permissions (name, description)
+VALUES ('claims:decide', 'Approve or reject business ownership claims');
+
+INSERT INTO role_permissions (role_name, permission_name)
+VALUES ('admin', 'claims:decide');
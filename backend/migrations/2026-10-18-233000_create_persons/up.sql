@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS persons
+(
+    id           VARCHAR(36)  PRIMARY KEY,
+    user_id      VARCHAR(36)  NOT NULL REFERENCES users (id),
+    name         VARCHAR(120) NOT NULL,
+    social_name  VARCHAR(120) NULL,
+    birth_date   DATE         NOT NULL,
+    tax_id_kind  VARCHAR(20)  NOT NULL,
+    tax_id       VARCHAR(14)  NOT NULL,
+    created_at   TIMESTAMPTZ  NOT NULL,
+    updated_at   TIMESTAMPTZ  NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS persons_user_id_unique ON persons (user_id);
+-- The same digits may be a document of another kind, e.g. a Portuguese NIF and a US SSN
+CREATE UNIQUE INDEX IF NOT EXISTS persons_tax_id_unique ON persons (tax_id_kind, tax_id);
@@ -0,0 +1,8 @@
+ALTER TABLE customer_services
+    DROP CONSTRAINT customer_services_address_complete,
+    DROP COLUMN address_country,
+    DROP COLUMN address_postal_code,
+    DROP COLUMN address_neighbourhood,
+    DROP COLUMN address_complement,
+    DROP COLUMN address_number,
+    DROP COLUMN address_street;
@@ -0,0 +1,13 @@
+-- Street address of a place, city and state (UF in Brazil) stay in their own columns
+ALTER TABLE customer_services
+    ADD COLUMN address_street        VARCHAR(200),
+    ADD COLUMN address_number        VARCHAR(20),
+    ADD COLUMN address_complement    VARCHAR(100),
+    ADD COLUMN address_neighbourhood VARCHAR(120),
+    ADD COLUMN address_postal_code   VARCHAR(10),
+    ADD COLUMN address_country       VARCHAR(2),
+    ADD CONSTRAINT customer_services_address_complete CHECK (
+        (address_street IS NULL) = (address_postal_code IS NULL)
+            AND (address_street IS NULL) = (address_country IS NULL)
+            AND (address_street IS NULL OR city IS NOT NULL)
+        );
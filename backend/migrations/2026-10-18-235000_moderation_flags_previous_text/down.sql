@@ -0,0 +1,4 @@
+ALTER TABLE moderation_flags
+    DROP CONSTRAINT moderation_flags_previous_text_complete,
+    DROP COLUMN previous_description,
+    DROP COLUMN previous_name;
@@ -0,0 +1,8 @@
+-- Name and description a place had before the edit that raised the flag, rejecting restores them
+ALTER TABLE moderation_flags
+    ADD COLUMN previous_name        VARCHAR(255),
+    ADD COLUMN previous_description VARCHAR(2048),
+    ADD CONSTRAINT moderation_flags_previous_text_complete CHECK (
+        (previous_name IS NULL) = (previous_description IS NULL)
+            AND (previous_name IS NULL OR subject_type = 'customer-service')
+        );
@@ -11,7 +11,7 @@ pub struct ErrorData {
     pub code: String,
     pub message: String,
     pub args: Option<HashMap<String, String>>,
-    pub cause: Option<Arc<dyn StdError>>,
+    pub cause: Option<Arc<dyn StdError + Send + Sync>>,
 }
 
 impl ErrorData {
@@ -28,7 +28,7 @@ impl ErrorData {
         self
     }
 
-    pub fn with_cause(mut self, cause: Option<Arc<dyn StdError>>) -> Self {
+    pub fn with_cause(mut self, cause: Option<Arc<dyn StdError + Send + Sync>>) -> Self {
         self.cause = cause;
         self
     }
@@ -50,6 +50,8 @@ pub enum AppError {
     Internal(ErrorData),
     IllegalArgument(ErrorData),
     Unauthorized(ErrorData),
+    Forbidden(ErrorData),
+    Conflict(ErrorData),
     UnprocessableEntity(ErrorData),
     Database(ErrorData),
     Validation(ErrorData),
@@ -63,6 +65,8 @@ impl Display for AppError {
             AppError::Internal(d) => d.fmt(f),
             AppError::IllegalArgument(d) => d.fmt(f),
             AppError::Unauthorized(d) => d.fmt(f),
+            AppError::Forbidden(d) => d.fmt(f),
+            AppError::Conflict(d) => d.fmt(f),
             AppError::UnprocessableEntity(d) => d.fmt(f),
             AppError::Database(d) => d.fmt(f),
             AppError::Validation(d) => d.fmt(f),
@@ -74,20 +78,24 @@ impl Display for AppError {
 impl StdError for AppError {
     fn cause(&self) -> Option<&dyn StdError> {
         match self {
-            AppError::NotFound(d) => d.cause.as_ref().map(|c| c.as_ref()),
-            AppError::Internal(d) => d.cause.as_ref().map(|c| c.as_ref()),
-            AppError::IllegalArgument(d) => d.cause.as_ref().map(|c| c.as_ref()),
-            AppError::Unauthorized(d) => d.cause.as_ref().map(|c| c.as_ref()),
-            AppError::UnprocessableEntity(d) => d.cause.as_ref().map(|c| c.as_ref()),
-            AppError::Database(d) => d.cause.as_ref().map(|c| c.as_ref()),
-            AppError::Validation(d) => d.cause.as_ref().map(|c| c.as_ref()),
-            AppError::Service(d) => d.cause.as_ref().map(|c| c.as_ref()),
+            AppError::NotFound(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
+            AppError::Internal(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
+            AppError::IllegalArgument(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
+            AppError::Unauthorized(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
+            AppError::Forbidden(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
+            AppError::Conflict(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
+            AppError::UnprocessableEntity(d) => {
+                d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError)
+            }
+            AppError::Database(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
+            AppError::Validation(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
+            AppError::Service(d) => d.cause.as_ref().map(|c| c.as_ref() as &dyn StdError),
         }
     }
 }
 
-impl From<Arc<dyn StdError>> for AppError {
-    fn from(value: Arc<dyn StdError>) -> Self {
+impl From<Arc<dyn StdError + Send + Sync>> for AppError {
+    fn from(value: Arc<dyn StdError + Send + Sync>) -> Self {
         if let Some(app_error) = value.downcast_ref::<AppError>() {
             match app_error {
                 AppError::NotFound(error_data) => {
@@ -102,6 +110,12 @@ impl From<Arc<dyn StdError>> for AppError {
                 AppError::Unauthorized(error_data) => {
                     return AppError::Unauthorized(error_data.clone());
                 }
+                AppError::Forbidden(error_data) => {
+                    return AppError::Forbidden(error_data.clone());
+                }
+                AppError::Conflict(error_data) => {
+                    return AppError::Conflict(error_data.clone());
+                }
                 AppError::UnprocessableEntity(error_data) => {
                     return AppError::UnprocessableEntity(error_data.clone());
                 }
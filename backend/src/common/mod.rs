@@ -1,2 +1,4 @@
 pub mod error;
 pub mod result;
+#[cfg(test)]
+pub mod test_support;
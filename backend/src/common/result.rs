@@ -1,4 +1,4 @@
 use std::error::Error;
 use std::sync::Arc;
 
-pub type ResultApp<T> = Result<T, Arc<dyn Error>>;
+pub type ResultApp<T> = Result<T, Arc<dyn Error + Send + Sync>>;
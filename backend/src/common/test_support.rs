@@ -0,0 +1,12 @@
+use crate::common::error::AppError;
+use crate::common::result::ResultApp;
+use std::fmt::Debug;
+
+// Code of the validation error a constructor failed with
+pub fn error_code<T: Debug>(result: ResultApp<T>) -> String {
+    let error = result.unwrap_err();
+    match error.downcast_ref::<AppError>() {
+        Some(AppError::Validation(data)) => data.code.clone(),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
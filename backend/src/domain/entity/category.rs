@@ -0,0 +1,64 @@
+use crate::common::result::ResultApp;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::localized_labels::LocalizedLabels;
+use crate::domain::vo::temporal::DateTime;
+
+// Node of the category taxonomy, customer services reference it by slug
+#[derive(Debug, Clone)]
+pub struct Category {
+    pub id: Id,
+    pub slug: CustomerServiceCategory,
+    // None for a top level category
+    pub parent: Option<CustomerServiceCategory>,
+    pub labels: LocalizedLabels,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+impl Category {
+    pub fn new(
+        slug: CustomerServiceCategory,
+        parent: Option<CustomerServiceCategory>,
+        labels: LocalizedLabels,
+    ) -> ResultApp<Self> {
+        Ok(Category {
+            id: Id::new()?,
+            slug,
+            parent,
+            labels,
+            created_at: DateTime::new(),
+            updated_at: DateTime::new(),
+        })
+    }
+}
+
+// The slug is stable, only the place in the tree and the labels change
+#[derive(Debug, Clone, Default)]
+pub struct CategoryPartial {
+    pub slug: Option<CustomerServiceCategory>,
+    // Some(None) moves the category to the top level
+    pub parent: Option<Option<CustomerServiceCategory>>,
+    pub labels: Option<LocalizedLabels>,
+}
+
+impl CategoryPartial {
+    pub fn set_slug(&mut self, slug: CustomerServiceCategory) {
+        self.slug = Some(slug);
+    }
+
+    // Fields left empty keep the persisted value
+    pub fn apply_to(&self, persisted: &Category) -> Category {
+        Category {
+            id: persisted.id,
+            slug: persisted.slug.clone(),
+            parent: self
+                .parent
+                .clone()
+                .unwrap_or_else(|| persisted.parent.clone()),
+            labels: self.labels.as_ref().unwrap_or(&persisted.labels).clone(),
+            created_at: persisted.created_at.clone(),
+            updated_at: DateTime::new(),
+        }
+    }
+}
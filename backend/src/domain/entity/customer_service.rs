@@ -1,14 +1,25 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::address::Address;
+use crate::domain::vo::brazil_state::BrazilState;
 use crate::domain::vo::customer_service_category::CustomerServiceCategory;
 use crate::domain::vo::description::Description;
+use crate::domain::vo::geohash::Geohash;
 use crate::domain::vo::geopoint::GeoPoint;
+use crate::domain::vo::holiday_calendar::HolidayCalendar;
 use crate::domain::vo::id::Id;
 use crate::domain::vo::name::Name;
+use crate::domain::vo::opening_hours::{OpeningHours, OpeningStatus};
 use crate::domain::vo::phone::Phone;
 use crate::domain::vo::photo::Photo;
+use crate::domain::vo::rating::RatingSummary;
 use crate::domain::vo::tags::Tags;
 use crate::domain::vo::temporal::DateTime;
+use crate::domain::vo::timezone::Timezone;
 use crate::domain::vo::url::Url;
-use std::collections::HashSet;
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct CustomerService {
@@ -16,11 +27,398 @@ pub struct CustomerService {
     pub name: Name,
     pub description: Description,
     pub location: GeoPoint,
+    // City and state of the address are mirrored in the fields below
+    pub address: Option<Address>,
     pub phone: Phone,
     pub website: Option<Url>,
     pub photos: Vec<Photo>,
     pub tags: Tags,
     pub categories: HashSet<CustomerServiceCategory>,
+    pub opening_hours: Option<OpeningHours>,
+    pub timezone: Timezone,
+    // Where the place observes state and municipal holidays
+    pub state: Option<BrazilState>,
+    pub city: Option<Name>,
+    // Maintained by the review repository, writes of the customer service never touch it
+    pub rating: RatingSummary,
+    pub deleted: bool,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub deleted_at: Option<DateTime>,
+}
+
+impl CustomerService {
+    // Categories are unordered, the alphabetically first one stands in as the primary category
+    pub fn primary_category(&self) -> Option<&CustomerServiceCategory> {
+        self.categories
+            .iter()
+            .min_by_key(|category| category.value())
+    }
+
+    // None when the place never published its opening hours
+    pub fn opening_status(
+        &self,
+        at: chrono::DateTime<Utc>,
+        holiday_calendar: &HolidayCalendar,
+    ) -> Option<OpeningStatus> {
+        let holidays = holiday_calendar.for_place(self.state.as_ref(), self.city.as_ref());
+        self.opening_hours
+            .as_ref()
+            .map(|opening_hours| opening_hours.status_at(at, self.timezone.tz(), &holidays))
+    }
+}
+
+// A place as sent for creation, without coordinates it is located from its address
+#[derive(Debug, Clone)]
+pub struct NewCustomerService {
+    pub name: Name,
+    pub description: Description,
+    pub location: Option<GeoPoint>,
+    pub address: Option<Address>,
+    pub phone: Phone,
+    pub website: Option<Url>,
+    pub photos: Vec<Photo>,
+    pub tags: Tags,
+    pub categories: HashSet<CustomerServiceCategory>,
+    pub opening_hours: Option<OpeningHours>,
+    pub timezone: Timezone,
+    pub state: Option<BrazilState>,
+    pub city: Option<Name>,
+}
+
+impl NewCustomerService {
+    pub fn into_customer_service(self, location: GeoPoint) -> ResultApp<CustomerService> {
+        let now = DateTime::new();
+        Ok(CustomerService {
+            id: Id::new()?,
+            name: self.name,
+            description: self.description,
+            location,
+            address: self.address,
+            phone: self.phone,
+            website: self.website,
+            photos: self.photos,
+            tags: self.tags,
+            categories: self.categories,
+            opening_hours: self.opening_hours,
+            timezone: self.timezone,
+            state: self.state,
+            city: self.city,
+            rating: RatingSummary::default(),
+            deleted: false,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CustomerServiceFilter {
+    // Only places open at this instant
+    pub open_at: Option<chrono::DateTime<Utc>>,
+    // Places in any of these categories, use cases expand a parent to its descendants first
+    pub categories: Option<HashSet<CustomerServiceCategory>>,
+    // Places carrying every one of these tags with the same value
+    pub tags: Option<Tags>,
+    pub sort: CustomerServiceSort,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CustomerServiceSort {
+    #[default]
+    Newest,
+    // Average rating pulled towards the mean of all places, so a single 5 star review does not
+    // outrank dozens of good ones
+    Rating,
+}
+
+impl CustomerServiceSort {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim() {
+            "newest" => Ok(CustomerServiceSort::Newest),
+            "rating" => Ok(CustomerServiceSort::Rating),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-sort", "sort must be newest or rating")
+                    .with_args(HashMap::from([("sort".to_string(), other.to_string())])),
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CustomerServicePartial {
+    pub id: Option<Id>,
+    pub name: Option<Name>,
+    pub description: Option<Description>,
+    pub location: Option<GeoPoint>,
+    // Some(None) clears the field, here and for website and opening hours
+    pub address: Option<Option<Address>>,
+    pub phone: Option<Phone>,
+    pub website: Option<Option<Url>>,
+    pub photos: Option<Vec<Photo>>,
+    pub tags: Option<Tags>,
+    pub categories: Option<HashSet<CustomerServiceCategory>>,
+    pub opening_hours: Option<Option<OpeningHours>>,
+    pub timezone: Option<Timezone>,
+    pub state: Option<BrazilState>,
+    pub city: Option<Name>,
+}
+
+impl CustomerServicePartial {
+    pub fn set_id(&mut self, id: Id) {
+        self.id = Some(id);
+    }
+
+    // What an owner may change without the update-any permission
+    pub fn only_owner_editable(&self) -> bool {
+        self.name.is_none()
+            && self.location.is_none()
+            && self.address.is_none()
+            && self.phone.is_none()
+            && self.website.is_none()
+            && self.tags.is_none()
+            && self.categories.is_none()
+            && self.timezone.is_none()
+            && self.state.is_none()
+            && self.city.is_none()
+    }
+
+    // City and state follow the address, they may only be set on a place left without one
+    pub fn ensure_state_city_editable(&self, persisted: &CustomerService) -> ResultApp<()> {
+        let keeps_address = match &self.address {
+            Some(address) => address.is_some(),
+            None => persisted.address.is_some(),
+        };
+        if keeps_address && (self.state.is_some() || self.city.is_some()) {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "state-city-follow-address",
+                "state and city come from the address, send them in the address instead",
+            ))));
+        }
+        Ok(())
+    }
+
+    // Fields left out keep the persisted value
+    pub fn apply_to(&self, persisted: &CustomerService) -> CustomerService {
+        CustomerService {
+            id: persisted.id,
+            name: self.name.as_ref().unwrap_or(&persisted.name).clone(),
+            description: self
+                .description
+                .as_ref()
+                .unwrap_or(&persisted.description)
+                .clone(),
+            location: self.location.unwrap_or(persisted.location),
+            address: self
+                .address
+                .clone()
+                .unwrap_or_else(|| persisted.address.clone()),
+            phone: self.phone.as_ref().unwrap_or(&persisted.phone).clone(),
+            website: self
+                .website
+                .clone()
+                .unwrap_or_else(|| persisted.website.clone()),
+            photos: self.photos.as_ref().unwrap_or(&persisted.photos).clone(),
+            tags: match &self.tags {
+                Some(tags) => tags.clone().keeping_unrecognized(&persisted.tags),
+                None => persisted.tags.clone(),
+            },
+            categories: self
+                .categories
+                .as_ref()
+                .unwrap_or(&persisted.categories)
+                .clone(),
+            opening_hours: self
+                .opening_hours
+                .clone()
+                .unwrap_or_else(|| persisted.opening_hours.clone()),
+            timezone: self.timezone.unwrap_or(persisted.timezone),
+            // A new address also moves the place to its city and state, otherwise they are only
+            // sent for places without an address
+            state: match &self.address {
+                Some(Some(address)) => address.brazil_state(),
+                Some(None) | None => self.state.clone().or_else(|| persisted.state.clone()),
+            },
+            city: match &self.address {
+                Some(Some(address)) => Some(address.city()),
+                Some(None) | None => self.city.clone().or_else(|| persisted.city.clone()),
+            },
+            rating: persisted.rating,
+            deleted: persisted.deleted,
+            created_at: persisted.created_at.clone(),
+            updated_at: persisted.updated_at.clone(),
+            deleted_at: persisted.deleted_at.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomerServicePage {
+    pub customer_services: Vec<CustomerService>,
+    // Where a listing of open places resumes, None once every candidate was scanned
+    pub next: Option<ScanCursor>,
+    // The scan stopped at its cap before the page filled, a short page is not the last one
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanCursor {
+    // Candidate the next scan starts from, in the order of the listing
+    pub scan_offset: i64,
+    // Open places still to skip from there
+    pub offset: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomerServiceSearchResult {
+    pub customer_service: CustomerService,
+    // Text relevance, already discounted by distance when searching around a point
+    pub score: f64,
+    pub distance_meters: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NearbyCustomerService {
+    pub customer_service: CustomerService,
+    pub distance_meters: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CellCount {
+    pub cell: Geohash,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomerServiceCluster {
+    pub centroid: GeoPoint,
+    pub count: i64,
+    pub representative_id: Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::error_code;
+    use crate::domain::vo::address::AddressData;
+
+    fn address(city: &str, state: &str, postal_code: &str) -> Address {
+        Address::new(AddressData {
+            street: "Rua Principal".to_string(),
+            number: Some("100".to_string()),
+            complement: None,
+            neighbourhood: None,
+            city: city.to_string(),
+            state: Some(state.to_string()),
+            postal_code: postal_code.to_string(),
+            country: "BR".to_string(),
+        })
+        .unwrap()
+    }
+
+    fn persisted() -> CustomerService {
+        CustomerService {
+            id: Id::new().unwrap(),
+            name: Name::new("Padaria Central").unwrap(),
+            description: Description::new("Pães e cafés".to_string()).unwrap(),
+            location: GeoPoint::new(-23.5614, -46.6559).unwrap(),
+            address: Some(address("São Paulo", "SP", "01310-100")),
+            phone: Phone::new("+5511987654321".to_string()).unwrap(),
+            website: Some(Url::new("https://padaria.example.com").unwrap()),
+            photos: vec![],
+            tags: Tags::default(),
+            categories: HashSet::new(),
+            opening_hours: Some(OpeningHours::new("Mo-Fr 08:00-18:00").unwrap()),
+            timezone: Timezone::default(),
+            state: Some(BrazilState::new("SP").unwrap()),
+            city: Some(Name::new("São Paulo").unwrap()),
+            rating: RatingSummary::default(),
+            deleted: false,
+            created_at: DateTime::new(),
+            updated_at: DateTime::new(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn fields_left_out_keep_the_persisted_value() {
+        let persisted = persisted();
+        let updated = CustomerServicePartial::default().apply_to(&persisted);
+        assert_eq!(updated.name, persisted.name);
+        assert_eq!(updated.address, persisted.address);
+        assert_eq!(updated.website, persisted.website);
+        assert_eq!(updated.opening_hours, persisted.opening_hours);
+        assert_eq!(updated.state, persisted.state);
+        assert_eq!(updated.city, persisted.city);
+    }
+
+    #[test]
+    fn explicit_null_clears_website_opening_hours_and_address() {
+        let persisted = persisted();
+        let partial = CustomerServicePartial {
+            address: Some(None),
+            website: Some(None),
+            opening_hours: Some(None),
+            ..Default::default()
+        };
+        let updated = partial.apply_to(&persisted);
+        assert_eq!(updated.address, None);
+        assert_eq!(updated.website, None);
+        assert_eq!(updated.opening_hours, None);
+        // The place keeps where it observes holidays until told otherwise
+        assert_eq!(updated.state, persisted.state);
+        assert_eq!(updated.city, persisted.city);
+    }
+
+    #[test]
+    fn new_address_moves_state_and_city() {
+        let persisted = persisted();
+        let partial = CustomerServicePartial {
+            address: Some(Some(address("Rio de Janeiro", "RJ", "20040-002"))),
+            ..Default::default()
+        };
+        partial.ensure_state_city_editable(&persisted).unwrap();
+        let updated = partial.apply_to(&persisted);
+        assert_eq!(updated.state, Some(BrazilState::new("RJ").unwrap()));
+        assert_eq!(updated.city, Some(Name::new("Rio de Janeiro").unwrap()));
+    }
+
+    #[test]
+    fn state_and_city_are_rejected_while_the_address_is_kept() {
+        let persisted = persisted();
+        let partial = CustomerServicePartial {
+            state: Some(BrazilState::new("RJ").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            error_code(partial.ensure_state_city_editable(&persisted)),
+            "state-city-follow-address"
+        );
+
+        let partial = CustomerServicePartial {
+            address: Some(Some(address("Rio de Janeiro", "RJ", "20040-002"))),
+            city: Some(Name::new("Niterói").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            error_code(partial.ensure_state_city_editable(&persisted)),
+            "state-city-follow-address"
+        );
+    }
+
+    #[test]
+    fn state_and_city_may_be_set_once_the_address_is_cleared() {
+        let persisted = persisted();
+        let partial = CustomerServicePartial {
+            address: Some(None),
+            state: Some(BrazilState::new("RJ").unwrap()),
+            city: Some(Name::new("Niterói").unwrap()),
+            ..Default::default()
+        };
+        partial.ensure_state_city_editable(&persisted).unwrap();
+        let updated = partial.apply_to(&persisted);
+        assert_eq!(updated.state, Some(BrazilState::new("RJ").unwrap()));
+        assert_eq!(updated.city, Some(Name::new("Niterói").unwrap()));
+    }
 }
@@ -0,0 +1,88 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::temporal::DateTime;
+use chrono::{Datelike, NaiveDate};
+use std::sync::Arc;
+
+// Admin-defined regional holiday, national ones are computed by the holiday calendar
+#[derive(Debug, Clone)]
+pub struct Holiday {
+    pub id: Id,
+    pub name: Name,
+    pub date: NaiveDate,
+    // Repeats every year on the same month and day
+    pub recurring: bool,
+    pub state: Option<BrazilState>,
+    // Municipal holiday when set, requires the state
+    pub city: Option<Name>,
+    pub created_at: DateTime,
+}
+
+impl Holiday {
+    pub fn new(
+        name: Name,
+        date: NaiveDate,
+        recurring: bool,
+        state: Option<BrazilState>,
+        city: Option<Name>,
+    ) -> ResultApp<Self> {
+        if city.is_some() && state.is_none() {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "holiday-city-requires-state",
+                "a municipal holiday must also name its state",
+            ))));
+        }
+
+        Ok(Holiday {
+            id: Id::new()?,
+            name,
+            date,
+            recurring,
+            state,
+            city,
+            created_at: DateTime::new(),
+        })
+    }
+
+    // None for a one-off holiday of another year, or a recurring 29 February in a common year
+    pub fn occurrence_in(&self, year: i32) -> Option<NaiveDate> {
+        if self.recurring {
+            NaiveDate::from_ymd_opt(year, self.date.month(), self.date.day())
+        } else {
+            (self.date.year() == year).then_some(self.date)
+        }
+    }
+
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        self.occurrence_in(date.year()) == Some(date)
+    }
+
+    // Holidays without a state apply to the whole country
+    pub fn applies_to(&self, state: Option<&BrazilState>, city: Option<&Name>) -> bool {
+        let same_state = match &self.state {
+            None => return true,
+            Some(holiday_state) => Some(holiday_state) == state,
+        };
+        let same_city = match (&self.city, city) {
+            (None, _) => true,
+            (Some(holiday_city), Some(city)) => {
+                holiday_city.value().to_lowercase() == city.value().to_lowercase()
+            }
+            (Some(_), None) => false,
+        };
+        same_state && same_city
+    }
+}
+
+// One day off as listed for a year, national holidays carry no id
+#[derive(Debug, Clone)]
+pub struct ObservedHoliday {
+    pub id: Option<Id>,
+    pub name: String,
+    pub date: NaiveDate,
+    pub state: Option<BrazilState>,
+    pub city: Option<Name>,
+}
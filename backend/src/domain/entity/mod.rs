@@ -1,3 +1,10 @@
-mod customer_service;
+pub mod category;
+pub mod customer_service;
+pub mod holiday;
+pub mod moderation_flag;
+pub mod ownership_claim;
 pub mod person;
+pub mod principal;
+pub mod refresh_token;
+pub mod review;
 pub mod user;
@@ -0,0 +1,119 @@
+use crate::common::result::ResultApp;
+use crate::domain::vo::description::Description;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::moderation::{
+    FlagReason, ModerationStatus, ModerationSubject, PreviousPlaceText,
+};
+use crate::domain::vo::temporal::DateTime;
+
+// Entry of the moderation queue, flagged content stays public until a moderator rejects it
+#[derive(Debug, Clone)]
+pub struct ModerationFlag {
+    pub id: Id,
+    pub subject: ModerationSubject,
+    pub reason: FlagReason,
+    // None when an automatic rule raised the flag
+    pub reporter_id: Option<Id>,
+    pub note: Description,
+    pub status: ModerationStatus,
+    pub moderator_id: Option<Id>,
+    pub decided_at: Option<DateTime>,
+    pub created_at: DateTime,
+    // Set when an edit of a place raised the flag, rejecting restores it instead of hiding the place
+    pub previous_text: Option<PreviousPlaceText>,
+}
+
+impl ModerationFlag {
+    pub fn new(
+        subject: ModerationSubject,
+        reason: FlagReason,
+        reporter_id: Option<Id>,
+        note: Description,
+    ) -> ResultApp<Self> {
+        Ok(ModerationFlag {
+            id: Id::new()?,
+            subject,
+            reason,
+            reporter_id,
+            note,
+            status: ModerationStatus::Pending,
+            moderator_id: None,
+            decided_at: None,
+            created_at: DateTime::new(),
+            previous_text: None,
+        })
+    }
+
+    pub fn with_previous_text(mut self, previous_text: Option<PreviousPlaceText>) -> Self {
+        self.previous_text = previous_text;
+        self
+    }
+}
+
+// Text a rejection of these pending flags restores on their place, the one before the oldest
+// flagged edit. None when any of them was not raised by an edit, the listing itself is then at
+// fault and gets hidden
+pub fn text_to_restore(pending: &[ModerationFlag]) -> Option<PreviousPlaceText> {
+    if pending.iter().any(|flag| flag.previous_text.is_none()) {
+        return None;
+    }
+    pending
+        .iter()
+        .min_by_key(|flag| flag.created_at.to_chono_date_time())
+        .and_then(|flag| flag.previous_text.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::vo::name::Name;
+    use chrono::{Duration, Utc};
+
+    fn flag(minutes_ago: i64, reason: FlagReason, previous_name: Option<&str>) -> ModerationFlag {
+        let subject = ModerationSubject::CustomerService(Id::new().unwrap());
+        let mut flag = ModerationFlag::new(
+            subject,
+            reason,
+            None,
+            Description::new(String::new()).unwrap(),
+        )
+        .unwrap()
+        .with_previous_text(previous_name.map(|name| PreviousPlaceText {
+            name: Name::new(name).unwrap(),
+            description: Description::new(format!("{} description", name)).unwrap(),
+        }));
+        flag.created_at = DateTime::new_from_date_time(Utc::now() - Duration::minutes(minutes_ago));
+        flag
+    }
+
+    #[test]
+    fn a_single_edit_flag_restores_its_text() {
+        let restored = text_to_restore(&[flag(5, FlagReason::Profanity, Some("Padaria Boa"))]);
+        assert_eq!(restored.unwrap().name.value(), "Padaria Boa");
+    }
+
+    #[test]
+    fn flags_from_several_edits_restore_the_text_before_the_oldest() {
+        let restored = text_to_restore(&[
+            flag(5, FlagReason::Link, Some("Padaria Nova")),
+            flag(30, FlagReason::Profanity, Some("Padaria Boa")),
+            flag(10, FlagReason::Profanity, Some("Padaria Outra")),
+        ])
+        .unwrap();
+        assert_eq!(restored.name.value(), "Padaria Boa");
+        assert_eq!(restored.description.value(), "Padaria Boa description");
+    }
+
+    #[test]
+    fn a_flag_not_raised_by_an_edit_hides_the_place() {
+        assert!(
+            text_to_restore(&[
+                flag(30, FlagReason::Profanity, Some("Padaria Boa")),
+                flag(5, FlagReason::UserReport, None),
+            ])
+            .is_none()
+        );
+        assert!(text_to_restore(&[flag(5, FlagReason::UserReport, None)]).is_none());
+        assert!(text_to_restore(&[]).is_none());
+    }
+}
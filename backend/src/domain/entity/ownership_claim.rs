@@ -0,0 +1,74 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::tax_id::{Document, TaxId};
+use crate::domain::vo::temporal::DateTime;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl ClaimStatus {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim() {
+            "pending" => Ok(ClaimStatus::Pending),
+            "approved" => Ok(ClaimStatus::Approved),
+            "rejected" => Ok(ClaimStatus::Rejected),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-claim-status", "Invalid claim status")
+                    .with_args(HashMap::from([("status".to_string(), other.to_string())])),
+            ))),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            ClaimStatus::Pending => "pending",
+            ClaimStatus::Approved => "approved",
+            ClaimStatus::Rejected => "rejected",
+        }
+        .to_string()
+    }
+}
+
+// A user asking to be recognized as the business behind a place, an admin decides it
+#[derive(Debug, Clone)]
+pub struct OwnershipClaim {
+    pub id: Id,
+    pub customer_service_id: Id,
+    pub claimant_id: Id,
+    pub tax_id: TaxId,
+    pub status: ClaimStatus,
+    pub reviewer_id: Option<Id>,
+    pub decided_at: Option<DateTime>,
+    pub created_at: DateTime,
+}
+
+impl OwnershipClaim {
+    // Only a company can own a place, so the tax id must be a CNPJ with valid check digits
+    pub fn new(customer_service_id: Id, claimant_id: Id, tax_id: TaxId) -> ResultApp<Self> {
+        if !matches!(tax_id, TaxId::BrazilCnpj(_)) {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-brazilian-cnpj",
+                "Invalid Brazilian CNPJ",
+            ))));
+        }
+        tax_id.validate()?;
+
+        Ok(OwnershipClaim {
+            id: Id::new()?,
+            customer_service_id,
+            claimant_id,
+            tax_id,
+            status: ClaimStatus::Pending,
+            reviewer_id: None,
+            decided_at: None,
+            created_at: DateTime::new(),
+        })
+    }
+}
@@ -1,11 +1,103 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::id::Id;
 use crate::domain::vo::name::Name;
 use crate::domain::vo::tax_id::TaxId;
-use crate::domain::vo::temporal::Date;
+use crate::domain::vo::temporal::{Date, DateTime};
+use std::collections::HashMap;
+use std::sync::Arc;
 
+pub const PERSON_MINIMUM_AGE_YEARS: u32 = 18;
+
+// Personal profile a user attaches to their account, at most one per user
 #[derive(Debug, Clone)]
 pub struct Person {
-    name: String,
-    birth_date: Date,
-    social_name: Name,
-    tax_id: TaxId,
+    pub id: Id,
+    pub user_id: Id,
+    // Legal name, as printed on the tax document
+    pub name: Name,
+    pub birth_date: Date,
+    pub social_name: Option<Name>,
+    pub tax_id: TaxId,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+impl Person {
+    pub fn new(
+        user_id: Id,
+        name: Name,
+        birth_date: Date,
+        social_name: Option<Name>,
+        tax_id: TaxId,
+    ) -> ResultApp<Self> {
+        ensure_minimum_age(&birth_date, &Date::new())?;
+
+        Ok(Person {
+            id: Id::new()?,
+            user_id,
+            name,
+            birth_date,
+            social_name,
+            tax_id,
+            created_at: DateTime::new(),
+            updated_at: DateTime::new(),
+        })
+    }
+
+    // Brazilian law has the social name shown in place of the legal one whenever it is given
+    pub fn display_name(&self) -> Name {
+        self.social_name.as_ref().unwrap_or(&self.name).clone()
+    }
+}
+
+fn ensure_minimum_age(birth_date: &Date, today: &Date) -> ResultApp<()> {
+    match birth_date.years_until(today) {
+        Some(age) if age >= PERSON_MINIMUM_AGE_YEARS => Ok(()),
+        _ => Err(Arc::new(AppError::Validation(
+            ErrorData::new(
+                "person-under-minimum-age",
+                "person is under the minimum age",
+            )
+            .with_args(HashMap::from([(
+                "minimum_age".to_string(),
+                PERSON_MINIMUM_AGE_YEARS.to_string(),
+            )])),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new_from_naive_date(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+    }
+
+    #[test]
+    fn minimum_age_is_reached_on_the_birthday() {
+        let today = date(2026, 10, 18);
+        assert!(ensure_minimum_age(&date(2008, 10, 18), &today).is_ok());
+        assert!(ensure_minimum_age(&date(2008, 10, 19), &today).is_err());
+        // Born in the future
+        assert!(ensure_minimum_age(&date(2027, 1, 1), &today).is_err());
+    }
+
+    #[test]
+    fn social_name_is_displayed_when_present() {
+        let mut person = Person::new(
+            Id::new().unwrap(),
+            Name::new("João da Silva").unwrap(),
+            date(1990, 5, 1),
+            None,
+            TaxId::new("529.982.247-25").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(person.display_name().value(), "João da Silva");
+
+        person.social_name = Some(Name::new("Joana da Silva").unwrap());
+        assert_eq!(person.display_name().value(), "Joana da Silva");
+    }
 }
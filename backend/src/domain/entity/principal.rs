@@ -0,0 +1,91 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::domain::vo::role::Role;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+// The authenticated caller of a request, resolved from the bearer token
+#[derive(Debug, Clone)]
+pub struct Principal {
+    pub user_id: Id,
+    pub roles: HashSet<Role>,
+    pub permissions: HashSet<Permission>,
+}
+
+impl Principal {
+    pub fn new(user_id: Id, roles: HashSet<Role>, permissions: HashSet<Permission>) -> Self {
+        Self {
+            user_id,
+            roles,
+            permissions,
+        }
+    }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    // `owner_id` is the owner of the resource being acted on, when it has one
+    pub fn authorize(&self, policy: AccessPolicy, owner_id: Option<&Id>) -> ResultApp<()> {
+        let allowed = match policy {
+            AccessPolicy::Require(permission) => self.has_permission(permission),
+            AccessPolicy::OwnerOr(permission) => {
+                owner_id == Some(&self.user_id) || self.has_permission(permission)
+            }
+        };
+        if allowed {
+            return Ok(());
+        }
+
+        let permission = match policy {
+            AccessPolicy::Require(permission) | AccessPolicy::OwnerOr(permission) => permission,
+        };
+        Err(Arc::new(AppError::Forbidden(
+            ErrorData::new("forbidden", "not allowed to act on this resource").with_args(
+                HashMap::from([("permission".to_string(), permission.value())]),
+            ),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICY: AccessPolicy = AccessPolicy::OwnerOr(Permission::UsersUpdateAny);
+
+    #[test]
+    fn owner_or_permission_holder_is_allowed() {
+        let owner = Id::new().unwrap();
+        let other = Id::new().unwrap();
+
+        let as_owner = Principal::new(owner, HashSet::new(), HashSet::new());
+        assert!(as_owner.authorize(POLICY, Some(&owner)).is_ok());
+
+        let as_admin = Principal::new(
+            other,
+            HashSet::from([Role::Admin]),
+            HashSet::from([Permission::UsersUpdateAny]),
+        );
+        assert!(as_admin.authorize(POLICY, Some(&owner)).is_ok());
+    }
+
+    #[test]
+    fn anyone_else_is_forbidden() {
+        let owner = Id::new().unwrap();
+        let other = Principal::new(Id::new().unwrap(), HashSet::new(), HashSet::new());
+
+        let err = other.authorize(POLICY, Some(&owner)).unwrap_err();
+        match err.downcast_ref::<AppError>() {
+            Some(AppError::Forbidden(data)) => assert_eq!(data.code, "forbidden"),
+            other => panic!("expected Forbidden error, got: {:?}", other),
+        }
+        assert!(
+            other
+                .authorize(AccessPolicy::Require(Permission::RolesManage), None)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::domain::vo::id::Id;
+use crate::domain::vo::temporal::DateTime;
+
+// Only the SHA-256 hash of the opaque token is kept, the plain value is handed to the client once
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+    pub id: Id,
+    pub user_id: Id,
+    pub family_id: Id,
+    pub token_hash: String,
+    pub expires_at: DateTime,
+    pub revoked: bool,
+    pub revoked_at: Option<DateTime>,
+    pub replaced_by: Option<Id>,
+    pub created_at: DateTime,
+}
+
+impl RefreshToken {
+    pub fn new(
+        id: Id,
+        user_id: Id,
+        family_id: Id,
+        token_hash: String,
+        expires_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            family_id,
+            token_hash,
+            expires_at,
+            revoked: false,
+            revoked_at: None,
+            replaced_by: None,
+            created_at: DateTime::new(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.to_chono_date_time() <= chrono::Utc::now()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::common::result::ResultApp;
+use crate::domain::vo::description::Description;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::rating::Rating;
+use crate::domain::vo::temporal::DateTime;
+
+// A user's opinion of a customer service, at most one that is not deleted per user and place
+#[derive(Debug, Clone)]
+pub struct Review {
+    pub id: Id,
+    pub customer_service_id: Id,
+    pub author_id: Id,
+    pub rating: Rating,
+    pub text: Description,
+    pub deleted: bool,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub deleted_at: Option<DateTime>,
+}
+
+impl Review {
+    pub fn new(
+        customer_service_id: Id,
+        author_id: Id,
+        rating: Rating,
+        text: Description,
+    ) -> ResultApp<Self> {
+        let now = DateTime::new();
+        Ok(Review {
+            id: Id::new()?,
+            customer_service_id,
+            author_id,
+            rating,
+            text,
+            deleted: false,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        })
+    }
+}
+
+// The place and the author are fixed, only the rating and the text are edited
+#[derive(Debug, Clone, Default)]
+pub struct ReviewPartial {
+    pub rating: Option<Rating>,
+    pub text: Option<Description>,
+}
+
+impl ReviewPartial {
+    // Fields left empty keep the persisted value
+    pub fn apply_to(&self, persisted: &Review) -> Review {
+        Review {
+            id: persisted.id,
+            customer_service_id: persisted.customer_service_id,
+            author_id: persisted.author_id,
+            rating: self.rating.unwrap_or(persisted.rating),
+            text: self.text.as_ref().unwrap_or(&persisted.text).clone(),
+            deleted: persisted.deleted,
+            created_at: persisted.created_at.clone(),
+            updated_at: persisted.updated_at.clone(),
+            deleted_at: persisted.deleted_at.clone(),
+        }
+    }
+}
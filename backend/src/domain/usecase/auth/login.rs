@@ -1 +1,173 @@
-trait LoginUseCase {}
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::principal::Principal;
+use crate::domain::entity::refresh_token::RefreshToken;
+use crate::domain::entity::user::User;
+use crate::domain::usecase::auth::signin::SignInUseCase;
+use crate::domain::vo::email::Email;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::temporal::DateTime;
+use crate::infrastructure::token::{TokenService, generate_opaque_token, hash_opaque_token};
+use crate::repositories::refresh_token::refresh_token_repository::RefreshTokenRepository;
+use crate::repositories::user::user_repository::UserRepository;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct AuthTokens {
+    pub access_token: String,
+    pub expires_in: i64,
+    pub refresh_token: String,
+}
+
+#[async_trait::async_trait]
+pub trait LoginUseCase: Send + Sync {
+    async fn login(&self, email: &Email, plaintext_password: &str) -> ResultApp<AuthTokens>;
+    async fn refresh(&self, refresh_token: &str) -> ResultApp<AuthTokens>;
+}
+
+pub struct LoginUseCaseImpl {
+    sign_in_use_case: Arc<dyn SignInUseCase>,
+    user_repository: Arc<dyn UserRepository>,
+    refresh_token_repository: Arc<dyn RefreshTokenRepository>,
+    token_service: Arc<dyn TokenService>,
+}
+
+impl LoginUseCaseImpl {
+    pub fn new(
+        sign_in_use_case: Arc<dyn SignInUseCase>,
+        user_repository: Arc<dyn UserRepository>,
+        refresh_token_repository: Arc<dyn RefreshTokenRepository>,
+        token_service: Arc<dyn TokenService>,
+    ) -> Self {
+        Self {
+            sign_in_use_case,
+            user_repository,
+            refresh_token_repository,
+            token_service,
+        }
+    }
+
+    async fn issue_tokens(
+        &self,
+        user: &User,
+        refresh_token_id: Id,
+        family_id: Id,
+    ) -> ResultApp<AuthTokens> {
+        let principal = Principal::new(
+            user.id,
+            self.user_repository.find_roles(&user.id).await?,
+            self.user_repository.find_permissions(&user.id).await?,
+        );
+        let access_token = self.token_service.issue_access_token(&principal)?;
+
+        let refresh_token = generate_opaque_token();
+        let expires_at = DateTime::new_from_date_time(
+            chrono::Utc::now()
+                + chrono::Duration::seconds(self.token_service.refresh_token_ttl_seconds()),
+        );
+        self.refresh_token_repository
+            .save(&RefreshToken::new(
+                refresh_token_id,
+                user.id,
+                family_id,
+                hash_opaque_token(&refresh_token),
+                expires_at,
+            ))
+            .await?;
+
+        Ok(AuthTokens {
+            access_token,
+            expires_in: self.token_service.access_token_ttl_seconds(),
+            refresh_token,
+        })
+    }
+}
+
+fn invalid_refresh_token(code: &str, message: &str) -> Arc<AppError> {
+    Arc::new(AppError::Unauthorized(ErrorData::new(code, message)))
+}
+
+#[async_trait::async_trait]
+impl LoginUseCase for LoginUseCaseImpl {
+    async fn login(&self, email: &Email, plaintext_password: &str) -> ResultApp<AuthTokens> {
+        let user = self
+            .sign_in_use_case
+            .sign_in(email, plaintext_password)
+            .await?;
+
+        // Every login starts a new token family
+        self.issue_tokens(&user, Id::new()?, Id::new()?).await
+    }
+
+    async fn refresh(&self, refresh_token: &str) -> ResultApp<AuthTokens> {
+        let persisted_token = match self
+            .refresh_token_repository
+            .find_by_token_hash(&hash_opaque_token(refresh_token))
+            .await
+        {
+            Ok(Some(token)) => token,
+            Ok(None) => {
+                return Err(invalid_refresh_token(
+                    "invalid-refresh-token",
+                    "invalid refresh token",
+                ));
+            }
+            Err(error) => return Err(error),
+        };
+
+        if persisted_token.revoked {
+            // A rotated token came back, assume it leaked and kill the whole family
+            self.refresh_token_repository
+                .revoke_family(&persisted_token.family_id)
+                .await?;
+            return Err(invalid_refresh_token(
+                "refresh-token-reused",
+                "refresh token was already used",
+            ));
+        }
+
+        if persisted_token.is_expired() {
+            return Err(invalid_refresh_token(
+                "refresh-token-expired",
+                "refresh token expired",
+            ));
+        }
+
+        let user = match self
+            .user_repository
+            .find_by_id(&persisted_token.user_id)
+            .await
+        {
+            Ok(Some(user)) if !user.deleted => user,
+            Ok(_) => {
+                self.refresh_token_repository
+                    .revoke_family(&persisted_token.family_id)
+                    .await?;
+                return Err(invalid_refresh_token(
+                    "invalid-refresh-token",
+                    "invalid refresh token",
+                ));
+            }
+            Err(error) => return Err(error),
+        };
+
+        let new_token_id = Id::new()?;
+        let rotated = self
+            .refresh_token_repository
+            .revoke(&persisted_token.id, Some(&new_token_id))
+            .await?;
+        if !rotated {
+            // Lost a race against another refresh with the same token
+            self.refresh_token_repository
+                .revoke_family(&persisted_token.family_id)
+                .await?;
+            return Err(invalid_refresh_token(
+                "refresh-token-reused",
+                "refresh token was already used",
+            ));
+        }
+
+        self.issue_tokens(&user, new_token_id, persisted_token.family_id)
+            .await
+    }
+}
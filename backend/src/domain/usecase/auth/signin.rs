@@ -1 +1,71 @@
-trait SignInUseCase {}
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::user::User;
+use crate::domain::vo::email::Email;
+use crate::domain::vo::password::Password;
+use crate::repositories::user::user_repository::UserRepository;
+use std::sync::Arc;
+
+#[async_trait::async_trait]
+pub trait SignInUseCase: Send + Sync {
+    async fn sign_in(&self, email: &Email, plaintext_password: &str) -> ResultApp<User>;
+}
+
+pub struct SignInUseCaseImpl {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl SignInUseCaseImpl {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+        Self { user_repository }
+    }
+}
+
+fn invalid_credentials() -> Arc<AppError> {
+    Arc::new(AppError::Unauthorized(ErrorData::new(
+        "invalid-credentials",
+        "invalid email or password",
+    )))
+}
+
+#[async_trait::async_trait]
+impl SignInUseCase for SignInUseCaseImpl {
+    async fn sign_in(&self, email: &Email, plaintext_password: &str) -> ResultApp<User> {
+        let mut user = match self.user_repository.find_by_email(email).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                // Same Argon2 cost as a known email, the timing must not tell accounts apart
+                Password::dummy().verify(plaintext_password);
+                return Err(invalid_credentials());
+            }
+            Err(error) => return Err(error),
+        };
+
+        if !user.password.verify(plaintext_password) {
+            return Err(invalid_credentials());
+        }
+
+        if user.deleted {
+            return Err(Arc::new(AppError::Unauthorized(ErrorData::new(
+                "user-deleted",
+                "user is deleted",
+            ))));
+        }
+
+        // Upgrade legacy or outdated hashes while the plaintext is at hand
+        if user.password.needs_rehash() {
+            user.password = Password::new(plaintext_password.to_string())?;
+            match self.user_repository.update(&user).await {
+                Ok(Some(updated)) => user = updated,
+                Ok(None) => {}
+                Err(error) => log::warn!(
+                    "could not rehash password of user {}: {}",
+                    user.id.value(),
+                    error
+                ),
+            }
+        }
+
+        Ok(user)
+    }
+}
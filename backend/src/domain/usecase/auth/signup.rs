@@ -1 +1,46 @@
-trait SignUp {}
+use crate::common::result::ResultApp;
+use crate::domain::entity::user::User;
+use crate::domain::usecase::user::create_user::CreateUserUseCase;
+use crate::domain::vo::email::Email;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::password::Password;
+use crate::domain::vo::temporal::DateTime;
+use std::sync::Arc;
+
+#[async_trait::async_trait]
+pub trait SignUpUseCase: Send + Sync {
+    async fn sign_up(&self, name: Name, email: Email, password: Password) -> ResultApp<User>;
+}
+
+pub struct SignUpUseCaseImpl {
+    create_user_use_case: Arc<dyn CreateUserUseCase>,
+}
+
+impl SignUpUseCaseImpl {
+    pub fn new(create_user_use_case: Arc<dyn CreateUserUseCase>) -> Self {
+        Self {
+            create_user_use_case,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SignUpUseCase for SignUpUseCaseImpl {
+    async fn sign_up(&self, name: Name, email: Email, password: Password) -> ResultApp<User> {
+        let now = DateTime::new();
+        let user = User::new(
+            Id::new()?,
+            name,
+            email,
+            password,
+            false,
+            now.clone(),
+            now,
+            None,
+        );
+
+        // Email uniqueness is enforced by the create user use case
+        self.create_user_use_case.create_user(&user).await
+    }
+}
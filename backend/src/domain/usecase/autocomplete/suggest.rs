@@ -0,0 +1,110 @@
+use crate::common::result::ResultApp;
+use crate::domain::entity::category::Category;
+use crate::domain::entity::customer_service::{CustomerService, CustomerServiceFilter};
+use crate::domain::vo::autocomplete_index::{AutocompleteIndex, RankedSuggestion};
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::search_query::SearchQuery;
+use crate::repositories::category::category_repository::CategoryRepository;
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::{Arc, RwLock};
+
+pub const MAX_SUGGESTIONS: usize = 20;
+const REBUILD_BATCH: i64 = 500;
+
+// Suggestions come from memory, writes to customer services and categories keep the index current
+#[async_trait::async_trait]
+pub trait AutocompleteUseCase: Send + Sync {
+    fn suggest(
+        &self,
+        query: &SearchQuery,
+        near: Option<&GeoPoint>,
+        limit: usize,
+    ) -> Vec<RankedSuggestion>;
+    // Loads every customer service and category, run once before serving
+    async fn rebuild(&self) -> ResultApp<()>;
+    fn index_customer_service(&self, customer_service: &CustomerService);
+    fn remove_customer_service(&self, id: &Id);
+    fn index_category(&self, category: &Category);
+    fn remove_category(&self, slug: &CustomerServiceCategory);
+}
+
+pub struct AutocompleteUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    index: RwLock<AutocompleteIndex>,
+}
+
+impl AutocompleteUseCaseImpl {
+    pub fn new(
+        customer_service_repository: Arc<dyn CustomerServiceRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+    ) -> Self {
+        Self {
+            customer_service_repository,
+            category_repository,
+            index: RwLock::new(AutocompleteIndex::default()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AutocompleteUseCase for AutocompleteUseCaseImpl {
+    fn suggest(
+        &self,
+        query: &SearchQuery,
+        near: Option<&GeoPoint>,
+        limit: usize,
+    ) -> Vec<RankedSuggestion> {
+        self.index
+            .read()
+            .unwrap()
+            .suggest(query, near, limit.clamp(1, MAX_SUGGESTIONS))
+    }
+
+    async fn rebuild(&self) -> ResultApp<()> {
+        let mut index = AutocompleteIndex::default();
+        for category in self.category_repository.find_all().await? {
+            index.upsert_category(&category);
+        }
+
+        let filter = CustomerServiceFilter::default();
+        let mut offset = 0;
+        loop {
+            let customer_services = self
+                .customer_service_repository
+                .find_all(&filter, REBUILD_BATCH, offset)
+                .await?;
+            for customer_service in &customer_services {
+                index.upsert_customer_service(customer_service);
+            }
+            if (customer_services.len() as i64) < REBUILD_BATCH {
+                break;
+            }
+            offset += REBUILD_BATCH;
+        }
+
+        *self.index.write().unwrap() = index;
+        Ok(())
+    }
+
+    fn index_customer_service(&self, customer_service: &CustomerService) {
+        self.index
+            .write()
+            .unwrap()
+            .upsert_customer_service(customer_service);
+    }
+
+    fn remove_customer_service(&self, id: &Id) {
+        self.index.write().unwrap().remove_customer_service(id);
+    }
+
+    fn index_category(&self, category: &Category) {
+        self.index.write().unwrap().upsert_category(category);
+    }
+
+    fn remove_category(&self, slug: &CustomerServiceCategory) {
+        self.index.write().unwrap().remove_category(slug);
+    }
+}
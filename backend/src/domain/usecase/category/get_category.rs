@@ -0,0 +1,93 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::category::Category;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::repositories::category::category_repository::CategoryRepository;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[async_trait::async_trait]
+pub trait GetCategoryUseCase: Send + Sync {
+    async fn get_category(&self, slug: &CustomerServiceCategory) -> ResultApp<Category>;
+    async fn list_categories(&self) -> ResultApp<Vec<Category>>;
+    // The given categories plus all of their descendants, unknown slugs expand to nothing
+    async fn expand_categories(
+        &self,
+        slugs: &HashSet<CustomerServiceCategory>,
+    ) -> ResultApp<HashSet<CustomerServiceCategory>>;
+    // Fails with the first slug that is not part of the taxonomy
+    async fn ensure_categories_exist(
+        &self,
+        slugs: &HashSet<CustomerServiceCategory>,
+    ) -> ResultApp<()>;
+}
+
+pub struct GetCategoryUseCaseImpl {
+    category_repository: Arc<dyn CategoryRepository>,
+}
+
+impl GetCategoryUseCaseImpl {
+    pub fn new(category_repository: Arc<dyn CategoryRepository>) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GetCategoryUseCase for GetCategoryUseCaseImpl {
+    async fn get_category(&self, slug: &CustomerServiceCategory) -> ResultApp<Category> {
+        match self.category_repository.find_by_slug(slug).await {
+            Ok(Some(category)) => Ok(category),
+            Ok(None) => Err(Arc::new(AppError::NotFound(ErrorData::new(
+                "category-not-found",
+                "category not found",
+            )))),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn list_categories(&self) -> ResultApp<Vec<Category>> {
+        self.category_repository.find_all().await
+    }
+
+    async fn expand_categories(
+        &self,
+        slugs: &HashSet<CustomerServiceCategory>,
+    ) -> ResultApp<HashSet<CustomerServiceCategory>> {
+        let mut expanded = HashSet::new();
+        for slug in slugs {
+            if expanded.contains(slug) {
+                continue;
+            }
+            let subtree = self.category_repository.find_subtree(slug).await?;
+            expanded.extend(subtree.into_iter().map(|category| category.slug));
+        }
+        Ok(expanded)
+    }
+
+    async fn ensure_categories_exist(
+        &self,
+        slugs: &HashSet<CustomerServiceCategory>,
+    ) -> ResultApp<()> {
+        if slugs.is_empty() {
+            return Ok(());
+        }
+
+        let slugs: Vec<CustomerServiceCategory> = slugs.iter().cloned().collect();
+        let known: HashSet<CustomerServiceCategory> = self
+            .category_repository
+            .find_by_slugs(&slugs)
+            .await?
+            .into_iter()
+            .map(|category| category.slug)
+            .collect();
+        match slugs.iter().find(|slug| !known.contains(slug)) {
+            Some(unknown) => Err(Arc::new(AppError::Validation(
+                ErrorData::new("unknown-category", "category is not part of the taxonomy")
+                    .with_args(HashMap::from([("category".to_string(), unknown.value())])),
+            ))),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::category::{Category, CategoryPartial};
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::autocomplete::suggest::AutocompleteUseCase;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::repositories::category::category_repository::CategoryRepository;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const MANAGE_CATEGORIES_POLICY: AccessPolicy = AccessPolicy::Require(Permission::CategoriesManage);
+
+#[async_trait::async_trait]
+pub trait ManageCategoriesUseCase: Send + Sync {
+    async fn create_category(
+        &self,
+        principal: &Principal,
+        category: &Category,
+    ) -> ResultApp<Category>;
+    async fn update_category(
+        &self,
+        principal: &Principal,
+        category_partial: &CategoryPartial,
+    ) -> ResultApp<Category>;
+    // Only leaves that no customer service references can be deleted
+    async fn delete_category(
+        &self,
+        principal: &Principal,
+        slug: &CustomerServiceCategory,
+    ) -> ResultApp<Category>;
+}
+
+pub struct ManageCategoriesUseCaseImpl {
+    category_repository: Arc<dyn CategoryRepository>,
+    autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+}
+
+impl ManageCategoriesUseCaseImpl {
+    pub fn new(
+        category_repository: Arc<dyn CategoryRepository>,
+        autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+    ) -> Self {
+        Self {
+            category_repository,
+            autocomplete_use_case,
+        }
+    }
+
+    async fn ensure_parent_exists(&self, parent: &CustomerServiceCategory) -> ResultApp<()> {
+        match self.category_repository.find_by_slug(parent).await? {
+            Some(_) => Ok(()),
+            None => Err(Arc::new(AppError::Validation(
+                ErrorData::new("unknown-parent-category", "parent category does not exist")
+                    .with_args(HashMap::from([("parent".to_string(), parent.value())])),
+            ))),
+        }
+    }
+}
+
+fn category_not_found() -> Arc<AppError> {
+    Arc::new(AppError::NotFound(ErrorData::new(
+        "category-not-found",
+        "category not found",
+    )))
+}
+
+#[async_trait::async_trait]
+impl ManageCategoriesUseCase for ManageCategoriesUseCaseImpl {
+    async fn create_category(
+        &self,
+        principal: &Principal,
+        category: &Category,
+    ) -> ResultApp<Category> {
+        principal.authorize(MANAGE_CATEGORIES_POLICY, None)?;
+
+        if let Some(parent) = &category.parent {
+            self.ensure_parent_exists(parent).await?;
+        }
+        let category = self.category_repository.save(category).await?;
+        self.autocomplete_use_case.index_category(&category);
+        Ok(category)
+    }
+
+    async fn update_category(
+        &self,
+        principal: &Principal,
+        category_partial: &CategoryPartial,
+    ) -> ResultApp<Category> {
+        principal.authorize(MANAGE_CATEGORIES_POLICY, None)?;
+
+        let Some(slug) = &category_partial.slug else {
+            return Err(category_not_found());
+        };
+        let persisted_category = match self.category_repository.find_by_slug(slug).await {
+            Ok(Some(category)) => category,
+            Ok(None) => return Err(category_not_found()),
+            Err(error) => return Err(error),
+        };
+
+        let category = category_partial.apply_to(&persisted_category);
+        if let Some(parent) = &category.parent
+            && category.parent != persisted_category.parent
+        {
+            self.ensure_parent_exists(parent).await?;
+            // Moving a category below itself or one of its descendants would create a cycle
+            let subtree = self.category_repository.find_subtree(slug).await?;
+            if subtree.iter().any(|descendant| &descendant.slug == parent) {
+                return Err(Arc::new(AppError::Validation(
+                    ErrorData::new(
+                        "category-cycle",
+                        "a category cannot be moved below itself or its descendants",
+                    )
+                    .with_args(HashMap::from([("parent".to_string(), parent.value())])),
+                )));
+            }
+        }
+
+        match self.category_repository.update(&category).await {
+            Ok(Some(category)) => {
+                self.autocomplete_use_case.index_category(&category);
+                Ok(category)
+            }
+            Ok(None) => Err(category_not_found()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn delete_category(
+        &self,
+        principal: &Principal,
+        slug: &CustomerServiceCategory,
+    ) -> ResultApp<Category> {
+        principal.authorize(MANAGE_CATEGORIES_POLICY, None)?;
+
+        let subtree = self.category_repository.find_subtree(slug).await?;
+        if subtree.is_empty() {
+            return Err(category_not_found());
+        }
+        if subtree.len() > 1 {
+            return Err(Arc::new(AppError::Conflict(ErrorData::new(
+                "category-has-children",
+                "move or delete the child categories first",
+            ))));
+        }
+        if self.category_repository.is_in_use(slug).await? {
+            return Err(Arc::new(AppError::Conflict(ErrorData::new(
+                "category-in-use",
+                "category is still assigned to customer services",
+            ))));
+        }
+
+        match self.category_repository.delete(slug).await {
+            Ok(Some(category)) => {
+                self.autocomplete_use_case.remove_category(&category.slug);
+                Ok(category)
+            }
+            Ok(None) => Err(category_not_found()),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_category;
+pub mod manage_categories;
@@ -0,0 +1,163 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::ownership_claim::{ClaimStatus, OwnershipClaim};
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::customer_service::get_customer_service::{
+    GetCustomerServiceUseCase, MAX_PAGE_SIZE,
+};
+use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::domain::vo::role::Role;
+use crate::repositories::claim::ownership_claim_repository::OwnershipClaimRepository;
+use crate::repositories::user::user_repository::UserRepository;
+use std::sync::Arc;
+
+const DECIDE_CLAIM_POLICY: AccessPolicy = AccessPolicy::Require(Permission::ClaimsDecide);
+
+#[async_trait::async_trait]
+pub trait ManageClaimsUseCase: Send + Sync {
+    // The claim is filed in the name of the principal, whatever claimant it carries
+    async fn submit_claim(
+        &self,
+        principal: &Principal,
+        claim: &OwnershipClaim,
+    ) -> ResultApp<OwnershipClaim>;
+    async fn list_pending(
+        &self,
+        principal: &Principal,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<OwnershipClaim>>;
+    // Approving makes the claimant the owner of the place and a business owner
+    async fn decide(
+        &self,
+        principal: &Principal,
+        id: &Id,
+        decision: ClaimStatus,
+    ) -> ResultApp<OwnershipClaim>;
+}
+
+pub struct ManageClaimsUseCaseImpl {
+    ownership_claim_repository: Arc<dyn OwnershipClaimRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase>,
+}
+
+impl ManageClaimsUseCaseImpl {
+    pub fn new(
+        ownership_claim_repository: Arc<dyn OwnershipClaimRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase>,
+    ) -> Self {
+        Self {
+            ownership_claim_repository,
+            user_repository,
+            get_customer_service_use_case,
+        }
+    }
+}
+
+fn claim_not_pending() -> Arc<AppError> {
+    Arc::new(AppError::Conflict(ErrorData::new(
+        "claim-already-decided",
+        "ownership claim was already decided",
+    )))
+}
+
+fn customer_service_already_owned() -> Arc<AppError> {
+    Arc::new(AppError::Conflict(ErrorData::new(
+        "customer-service-already-owned",
+        "the customer service already has an owner",
+    )))
+}
+
+#[async_trait::async_trait]
+impl ManageClaimsUseCase for ManageClaimsUseCaseImpl {
+    async fn submit_claim(
+        &self,
+        principal: &Principal,
+        claim: &OwnershipClaim,
+    ) -> ResultApp<OwnershipClaim> {
+        self.get_customer_service_use_case
+            .get_customer_service(&claim.customer_service_id)
+            .await?;
+        if self
+            .ownership_claim_repository
+            .find_owner(&claim.customer_service_id)
+            .await?
+            .is_some()
+        {
+            return Err(customer_service_already_owned());
+        }
+
+        let mut claim = claim.clone();
+        claim.claimant_id = principal.user_id;
+        self.ownership_claim_repository.save(&claim).await
+    }
+
+    async fn list_pending(
+        &self,
+        principal: &Principal,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<OwnershipClaim>> {
+        principal.authorize(DECIDE_CLAIM_POLICY, None)?;
+        self.ownership_claim_repository
+            .find_pending(limit.clamp(1, MAX_PAGE_SIZE), offset.max(0))
+            .await
+    }
+
+    async fn decide(
+        &self,
+        principal: &Principal,
+        id: &Id,
+        decision: ClaimStatus,
+    ) -> ResultApp<OwnershipClaim> {
+        principal.authorize(DECIDE_CLAIM_POLICY, None)?;
+        if decision == ClaimStatus::Pending {
+            return Err(Arc::new(AppError::IllegalArgument(ErrorData::new(
+                "invalid-claim-decision",
+                "a claim can only be approved or rejected",
+            ))));
+        }
+
+        match self.ownership_claim_repository.find_by_id(id).await {
+            Ok(Some(claim)) if claim.status != ClaimStatus::Pending => {
+                return Err(claim_not_pending());
+            }
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                return Err(Arc::new(AppError::NotFound(ErrorData::new(
+                    "claim-not-found",
+                    "ownership claim not found",
+                ))));
+            }
+            Err(error) => return Err(error),
+        }
+
+        let claim = match self
+            .ownership_claim_repository
+            .decide(id, decision, &principal.user_id)
+            .await
+        {
+            Ok(Some(claim)) => claim,
+            Ok(None) => return Err(claim_not_pending()),
+            Err(error) => return Err(error),
+        };
+        // Ownership already follows from the approved claim, the role only widens what the
+        // claimant may do elsewhere
+        if claim.status == ClaimStatus::Approved
+            && let Err(error) = self
+                .user_repository
+                .add_role(&claim.claimant_id, Role::BusinessOwner)
+                .await
+        {
+            log::warn!(
+                "could not grant business owner role to {}: {}",
+                claim.claimant_id.value(),
+                error
+            );
+        }
+        Ok(claim)
+    }
+}
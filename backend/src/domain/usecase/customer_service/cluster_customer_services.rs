@@ -0,0 +1,68 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::CustomerServiceCluster;
+use crate::domain::vo::geohash::Geohash;
+use crate::domain::vo::geopoint::BoundingBox;
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::Arc;
+
+pub const MAX_ZOOM: u8 = 22;
+// Past this zoom every place comes back as its own point, like supercluster's maxZoom
+pub const MAX_CLUSTER_ZOOM: u8 = 16;
+pub const MAX_UNCLUSTERED_POINTS: i64 = 2_000;
+
+#[async_trait::async_trait]
+pub trait ClusterCustomerServicesUseCase: Send + Sync {
+    async fn cluster(
+        &self,
+        bounding_box: &BoundingBox,
+        zoom: u8,
+    ) -> ResultApp<Vec<CustomerServiceCluster>>;
+}
+
+pub struct ClusterCustomerServicesUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+}
+
+impl ClusterCustomerServicesUseCaseImpl {
+    pub fn new(customer_service_repository: Arc<dyn CustomerServiceRepository>) -> Self {
+        Self {
+            customer_service_repository,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ClusterCustomerServicesUseCase for ClusterCustomerServicesUseCaseImpl {
+    async fn cluster(
+        &self,
+        bounding_box: &BoundingBox,
+        zoom: u8,
+    ) -> ResultApp<Vec<CustomerServiceCluster>> {
+        if zoom > MAX_ZOOM {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-zoom",
+                "zoom must be between 0 and 22",
+            ))));
+        }
+
+        if zoom > MAX_CLUSTER_ZOOM {
+            let customer_services = self
+                .customer_service_repository
+                .find_in_bounding_box(bounding_box, MAX_UNCLUSTERED_POINTS)
+                .await?;
+            return Ok(customer_services
+                .into_iter()
+                .map(|customer_service| CustomerServiceCluster {
+                    centroid: customer_service.location,
+                    count: 1,
+                    representative_id: customer_service.id,
+                })
+                .collect());
+        }
+
+        self.customer_service_repository
+            .find_clusters(bounding_box, Geohash::precision_for_zoom(zoom))
+            .await
+    }
+}
@@ -0,0 +1,113 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::{CustomerService, NewCustomerService};
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::autocomplete::suggest::AutocompleteUseCase;
+use crate::domain::usecase::category::get_category::GetCategoryUseCase;
+use crate::domain::usecase::moderation::flag_content::FlagContentUseCase;
+use crate::domain::vo::address::Address;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::infrastructure::geocoder::Geocoder;
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const CREATE_CUSTOMER_SERVICE_POLICY: AccessPolicy =
+    AccessPolicy::Require(Permission::CustomerServicesCreate);
+
+#[async_trait::async_trait]
+pub trait CreateCustomerServiceUseCase: Send + Sync {
+    async fn create_customer_service(
+        &self,
+        principal: &Principal,
+        customer_service: &NewCustomerService,
+    ) -> ResultApp<CustomerService>;
+}
+
+pub struct CreateCustomerServiceUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+    get_category_use_case: Arc<dyn GetCategoryUseCase>,
+    autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+    flag_content_use_case: Arc<dyn FlagContentUseCase>,
+    geocoder: Arc<dyn Geocoder>,
+}
+
+impl CreateCustomerServiceUseCaseImpl {
+    pub fn new(
+        customer_service_repository: Arc<dyn CustomerServiceRepository>,
+        get_category_use_case: Arc<dyn GetCategoryUseCase>,
+        autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+        flag_content_use_case: Arc<dyn FlagContentUseCase>,
+        geocoder: Arc<dyn Geocoder>,
+    ) -> Self {
+        Self {
+            customer_service_repository,
+            get_category_use_case,
+            autocomplete_use_case,
+            flag_content_use_case,
+            geocoder,
+        }
+    }
+
+    // Coordinates for a place sent with its address only
+    fn locate(&self, address: Option<&Address>) -> ResultApp<GeoPoint> {
+        let Some(address) = address else {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "missing-location",
+                "send latitude and longitude or an address",
+            ))));
+        };
+        match self.geocoder.geocode_address(address) {
+            Some(entry) => Ok(entry.location),
+            None => Err(Arc::new(AppError::Validation(
+                ErrorData::new(
+                    "address-not-geocoded",
+                    "the address could not be located, send latitude and longitude",
+                )
+                .with_args(HashMap::from([(
+                    "address".to_string(),
+                    address.single_line(),
+                )])),
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateCustomerServiceUseCase for CreateCustomerServiceUseCaseImpl {
+    async fn create_customer_service(
+        &self,
+        principal: &Principal,
+        customer_service: &NewCustomerService,
+    ) -> ResultApp<CustomerService> {
+        principal.authorize(CREATE_CUSTOMER_SERVICE_POLICY, None)?;
+        self.get_category_use_case
+            .ensure_categories_exist(&customer_service.categories)
+            .await?;
+
+        let location = match customer_service.location {
+            Some(location) => location,
+            None => self.locate(customer_service.address.as_ref())?,
+        };
+        let customer_service = customer_service.clone().into_customer_service(location)?;
+        let customer_service = self
+            .customer_service_repository
+            .save(&customer_service)
+            .await?;
+        self.autocomplete_use_case
+            .index_customer_service(&customer_service);
+        if let Err(error) = self
+            .flag_content_use_case
+            .screen_customer_service(&customer_service, None)
+            .await
+        {
+            log::warn!(
+                "could not screen customer service {}: {}",
+                customer_service.id.value(),
+                error
+            );
+        }
+        Ok(customer_service)
+    }
+}
@@ -0,0 +1,62 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::CustomerService;
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::autocomplete::suggest::AutocompleteUseCase;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::Arc;
+
+const DELETE_CUSTOMER_SERVICE_POLICY: AccessPolicy =
+    AccessPolicy::Require(Permission::CustomerServicesDeleteAny);
+
+#[async_trait::async_trait]
+pub trait DeleteCustomerServiceUseCase: Send + Sync {
+    async fn delete_customer_service(
+        &self,
+        principal: &Principal,
+        id: &Id,
+    ) -> ResultApp<CustomerService>;
+}
+
+pub struct DeleteCustomerServiceUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+    autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+}
+
+impl DeleteCustomerServiceUseCaseImpl {
+    pub fn new(
+        customer_service_repository: Arc<dyn CustomerServiceRepository>,
+        autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+    ) -> Self {
+        Self {
+            customer_service_repository,
+            autocomplete_use_case,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DeleteCustomerServiceUseCase for DeleteCustomerServiceUseCaseImpl {
+    async fn delete_customer_service(
+        &self,
+        principal: &Principal,
+        id: &Id,
+    ) -> ResultApp<CustomerService> {
+        principal.authorize(DELETE_CUSTOMER_SERVICE_POLICY, None)?;
+
+        match self.customer_service_repository.delete(id).await {
+            Ok(Some(customer_service)) => {
+                self.autocomplete_use_case
+                    .remove_customer_service(&customer_service.id);
+                Ok(customer_service)
+            }
+            Ok(None) => Err(Arc::new(AppError::NotFound(ErrorData::new(
+                "customer-service-not-found",
+                "customer service not found",
+            )))),
+            Err(error) => Err(error),
+        }
+    }
+}
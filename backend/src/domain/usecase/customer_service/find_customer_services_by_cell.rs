@@ -0,0 +1,83 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::{CellCount, CustomerService};
+use crate::domain::vo::geohash::Geohash;
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::Arc;
+
+// Finest resolution stored on customer_services
+pub const MAX_CELL_PRECISION: usize = 8;
+pub const MAX_CELL_LIMIT: i64 = 100;
+
+#[async_trait::async_trait]
+pub trait FindCustomerServicesByCellUseCase: Send + Sync {
+    async fn find_in_cell(
+        &self,
+        cell: &Geohash,
+        include_neighbours: bool,
+        limit: i64,
+    ) -> ResultApp<Vec<CustomerService>>;
+    async fn count_per_cell(&self, within: &Geohash, precision: usize)
+    -> ResultApp<Vec<CellCount>>;
+}
+
+pub struct FindCustomerServicesByCellUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+}
+
+impl FindCustomerServicesByCellUseCaseImpl {
+    pub fn new(customer_service_repository: Arc<dyn CustomerServiceRepository>) -> Self {
+        Self {
+            customer_service_repository,
+        }
+    }
+}
+
+fn validate_cell_precision(precision: usize) -> ResultApp<()> {
+    if precision > MAX_CELL_PRECISION {
+        return Err(Arc::new(AppError::Validation(ErrorData::new(
+            "invalid-cell-precision",
+            "cell precision must be at most 8 characters",
+        ))));
+    }
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl FindCustomerServicesByCellUseCase for FindCustomerServicesByCellUseCaseImpl {
+    async fn find_in_cell(
+        &self,
+        cell: &Geohash,
+        include_neighbours: bool,
+        limit: i64,
+    ) -> ResultApp<Vec<CustomerService>> {
+        validate_cell_precision(cell.precision())?;
+
+        let mut cells = vec![cell.clone()];
+        if include_neighbours {
+            cells.extend(cell.neighbours());
+        }
+
+        self.customer_service_repository
+            .find_in_cells(&cells, limit.clamp(1, MAX_CELL_LIMIT))
+            .await
+    }
+
+    async fn count_per_cell(
+        &self,
+        within: &Geohash,
+        precision: usize,
+    ) -> ResultApp<Vec<CellCount>> {
+        validate_cell_precision(precision)?;
+        if precision < within.precision() {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-cell-precision",
+                "cell precision must not be coarser than the enclosing cell",
+            ))));
+        }
+
+        self.customer_service_repository
+            .count_by_cell(std::slice::from_ref(within), precision)
+            .await
+    }
+}
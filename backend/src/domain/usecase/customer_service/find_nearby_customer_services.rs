@@ -0,0 +1,52 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::NearbyCustomerService;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::Arc;
+
+pub const MAX_NEARBY_RADIUS_METERS: f64 = 50_000.0;
+pub const MAX_NEARBY_LIMIT: i64 = 100;
+
+#[async_trait::async_trait]
+pub trait FindNearbyCustomerServicesUseCase: Send + Sync {
+    async fn find_nearby(
+        &self,
+        center: &GeoPoint,
+        radius_meters: f64,
+        limit: i64,
+    ) -> ResultApp<Vec<NearbyCustomerService>>;
+}
+
+pub struct FindNearbyCustomerServicesUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+}
+
+impl FindNearbyCustomerServicesUseCaseImpl {
+    pub fn new(customer_service_repository: Arc<dyn CustomerServiceRepository>) -> Self {
+        Self {
+            customer_service_repository,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FindNearbyCustomerServicesUseCase for FindNearbyCustomerServicesUseCaseImpl {
+    async fn find_nearby(
+        &self,
+        center: &GeoPoint,
+        radius_meters: f64,
+        limit: i64,
+    ) -> ResultApp<Vec<NearbyCustomerService>> {
+        if !(radius_meters > 0.0 && radius_meters <= MAX_NEARBY_RADIUS_METERS) {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-radius",
+                "radius must be greater than 0 and at most 50000 meters",
+            ))));
+        }
+
+        self.customer_service_repository
+            .find_nearby(center, radius_meters, limit.clamp(1, MAX_NEARBY_LIMIT))
+            .await
+    }
+}
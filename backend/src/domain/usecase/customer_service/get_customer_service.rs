@@ -0,0 +1,156 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::{
+    CustomerService, CustomerServiceFilter, CustomerServicePage, ScanCursor,
+};
+use crate::domain::usecase::category::get_category::GetCategoryUseCase;
+use crate::domain::usecase::holiday::holiday_calendar::HolidayCalendarUseCase;
+use crate::domain::vo::id::Id;
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::Arc;
+
+pub const MAX_PAGE_SIZE: i64 = 100;
+// Opening hours are not evaluated in SQL, candidates are scanned in batches until a page fills
+const OPEN_AT_SCAN_BATCH: i64 = 200;
+// Past this many candidates the page is returned truncated with a cursor to resume from, rather
+// than reading the whole table for a deep offset or a rarely open category
+const OPEN_AT_MAX_SCAN_BATCHES: i64 = 10;
+
+#[async_trait::async_trait]
+pub trait GetCustomerServiceUseCase: Send + Sync {
+    async fn get_customer_service(&self, id: &Id) -> ResultApp<CustomerService>;
+    async fn list_customer_services(
+        &self,
+        filter: &CustomerServiceFilter,
+        limit: i64,
+        offset: i64,
+        scan_offset: i64,
+    ) -> ResultApp<CustomerServicePage>;
+}
+
+pub struct GetCustomerServiceUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+    holiday_calendar_use_case: Arc<dyn HolidayCalendarUseCase>,
+    get_category_use_case: Arc<dyn GetCategoryUseCase>,
+}
+
+impl GetCustomerServiceUseCaseImpl {
+    pub fn new(
+        customer_service_repository: Arc<dyn CustomerServiceRepository>,
+        holiday_calendar_use_case: Arc<dyn HolidayCalendarUseCase>,
+        get_category_use_case: Arc<dyn GetCategoryUseCase>,
+    ) -> Self {
+        Self {
+            customer_service_repository,
+            holiday_calendar_use_case,
+            get_category_use_case,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GetCustomerServiceUseCase for GetCustomerServiceUseCaseImpl {
+    async fn get_customer_service(&self, id: &Id) -> ResultApp<CustomerService> {
+        match self.customer_service_repository.find_by_id(id).await {
+            Ok(Some(customer_service)) => Ok(customer_service),
+            Ok(None) => Err(Arc::new(AppError::NotFound(ErrorData::new(
+                "customer-service-not-found",
+                "customer service not found",
+            )))),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn list_customer_services(
+        &self,
+        filter: &CustomerServiceFilter,
+        limit: i64,
+        offset: i64,
+        scan_offset: i64,
+    ) -> ResultApp<CustomerServicePage> {
+        let limit = limit.clamp(1, MAX_PAGE_SIZE);
+        let offset = offset.max(0);
+        let mut filter = filter.clone();
+        if let Some(categories) = &filter.categories {
+            filter.categories = Some(
+                self.get_category_use_case
+                    .expand_categories(categories)
+                    .await?,
+            );
+        }
+        let Some(open_at) = filter.open_at else {
+            let customer_services = self
+                .customer_service_repository
+                .find_all(&filter, limit, offset)
+                .await?;
+            return Ok(CustomerServicePage {
+                customer_services,
+                next: None,
+                truncated: false,
+            });
+        };
+
+        let holiday_calendar = self
+            .holiday_calendar_use_case
+            .calendar_around(open_at)
+            .await?;
+        let scan_start = scan_offset.max(0);
+        let mut page = vec![];
+        let mut skipped = 0;
+        for batch in 0..OPEN_AT_MAX_SCAN_BATCHES {
+            let batch_offset = scan_start + batch * OPEN_AT_SCAN_BATCH;
+            let candidates = self
+                .customer_service_repository
+                .find_all(&filter, OPEN_AT_SCAN_BATCH, batch_offset)
+                .await?;
+            let scanned = candidates.len() as i64;
+
+            for (position, customer_service) in (batch_offset..).zip(candidates) {
+                let open = customer_service
+                    .opening_status(open_at, &holiday_calendar)
+                    .is_some_and(|status| status.open);
+                if !open {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                page.push(customer_service);
+                if page.len() as i64 == limit {
+                    return Ok(CustomerServicePage {
+                        customer_services: page,
+                        next: Some(ScanCursor {
+                            scan_offset: position + 1,
+                            offset: 0,
+                        }),
+                        truncated: false,
+                    });
+                }
+            }
+
+            if scanned < OPEN_AT_SCAN_BATCH {
+                return Ok(CustomerServicePage {
+                    customer_services: page,
+                    next: None,
+                    truncated: false,
+                });
+            }
+        }
+        log::warn!(
+            "open_at scan stopped after {} places from {} with {} of {} results",
+            OPEN_AT_MAX_SCAN_BATCHES * OPEN_AT_SCAN_BATCH,
+            scan_start,
+            page.len(),
+            limit
+        );
+        Ok(CustomerServicePage {
+            customer_services: page,
+            next: Some(ScanCursor {
+                scan_offset: scan_start + OPEN_AT_MAX_SCAN_BATCHES * OPEN_AT_SCAN_BATCH,
+                offset: offset - skipped,
+            }),
+            truncated: true,
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use crate::common::result::ResultApp;
+use crate::domain::vo::tile::Tile;
+use crate::infrastructure::mvt::{DEFAULT_EXTENT, PointFeature, encode_point_layer};
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::Arc;
+
+pub const CUSTOMER_SERVICES_LAYER: &str = "customer_services";
+pub const MAX_TILE_FEATURES: i64 = 5_000;
+// 64 of the 4096 tile units, enough for a marker drawn across the tile edge
+const TILE_BUFFER: f64 = 64.0 / DEFAULT_EXTENT as f64;
+
+#[async_trait::async_trait]
+pub trait GetCustomerServiceTileUseCase: Send + Sync {
+    // Mapbox Vector Tile bytes with one point feature per customer service
+    async fn get_tile(&self, tile: &Tile) -> ResultApp<Vec<u8>>;
+}
+
+pub struct GetCustomerServiceTileUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+}
+
+impl GetCustomerServiceTileUseCaseImpl {
+    pub fn new(customer_service_repository: Arc<dyn CustomerServiceRepository>) -> Self {
+        Self {
+            customer_service_repository,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GetCustomerServiceTileUseCase for GetCustomerServiceTileUseCaseImpl {
+    async fn get_tile(&self, tile: &Tile) -> ResultApp<Vec<u8>> {
+        let customer_services = self
+            .customer_service_repository
+            .find_in_bounding_box(&tile.bounding_box(TILE_BUFFER), MAX_TILE_FEATURES)
+            .await?;
+
+        let features: Vec<PointFeature> = customer_services
+            .iter()
+            .map(|customer_service| {
+                let (x, y) = tile.project(&customer_service.location, DEFAULT_EXTENT);
+                let mut properties = vec![
+                    ("id".to_string(), customer_service.id.value()),
+                    ("name".to_string(), customer_service.name.value()),
+                ];
+                if let Some(category) = customer_service.primary_category() {
+                    properties.push(("category".to_string(), category.value()));
+                }
+                PointFeature { x, y, properties }
+            })
+            .collect();
+
+        Ok(encode_point_layer(
+            CUSTOMER_SERVICES_LAYER,
+            DEFAULT_EXTENT,
+            &features,
+        ))
+    }
+}
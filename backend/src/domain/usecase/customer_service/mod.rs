@@ -0,0 +1,9 @@
+pub mod cluster_customer_services;
+pub mod create_customer_service;
+pub mod delete_customer_service;
+pub mod find_customer_services_by_cell;
+pub mod find_nearby_customer_services;
+pub mod get_customer_service;
+pub mod get_customer_service_tile;
+pub mod search_customer_services;
+pub mod update_customer_service;
@@ -0,0 +1,65 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::CustomerServiceSearchResult;
+use crate::domain::usecase::customer_service::find_nearby_customer_services::MAX_NEARBY_RADIUS_METERS;
+use crate::domain::usecase::customer_service::get_customer_service::MAX_PAGE_SIZE;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::domain::vo::search_query::SearchQuery;
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::Arc;
+
+pub const DEFAULT_SEARCH_RADIUS_METERS: f64 = 10_000.0;
+
+#[async_trait::async_trait]
+pub trait SearchCustomerServicesUseCase: Send + Sync {
+    // Around `near` only places inside the radius are returned
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        near: Option<(GeoPoint, f64)>,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<CustomerServiceSearchResult>>;
+}
+
+pub struct SearchCustomerServicesUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+}
+
+impl SearchCustomerServicesUseCaseImpl {
+    pub fn new(customer_service_repository: Arc<dyn CustomerServiceRepository>) -> Self {
+        Self {
+            customer_service_repository,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchCustomerServicesUseCase for SearchCustomerServicesUseCaseImpl {
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        near: Option<(GeoPoint, f64)>,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<CustomerServiceSearchResult>> {
+        if let Some((_, radius_meters)) = near
+            && !(radius_meters > 0.0 && radius_meters <= MAX_NEARBY_RADIUS_METERS)
+        {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-radius",
+                "radius must be greater than 0 and at most 50000 meters",
+            ))));
+        }
+
+        self.customer_service_repository
+            .search(
+                query,
+                near.as_ref()
+                    .map(|(center, radius_meters)| (center, *radius_meters)),
+                limit.clamp(1, MAX_PAGE_SIZE),
+                offset.max(0),
+            )
+            .await
+    }
+}
@@ -0,0 +1,133 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::{CustomerService, CustomerServicePartial};
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::autocomplete::suggest::AutocompleteUseCase;
+use crate::domain::usecase::category::get_category::GetCategoryUseCase;
+use crate::domain::usecase::moderation::flag_content::FlagContentUseCase;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::repositories::claim::ownership_claim_repository::OwnershipClaimRepository;
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use std::sync::Arc;
+
+// The owner is whoever had an ownership claim approved for the place
+const UPDATE_CUSTOMER_SERVICE_POLICY: AccessPolicy =
+    AccessPolicy::OwnerOr(Permission::CustomerServicesUpdateAny);
+
+#[async_trait::async_trait]
+pub trait UpdateCustomerServiceUseCase: Send + Sync {
+    // Owners without the update-any permission may only change hours, photos and description
+    async fn update_customer_service(
+        &self,
+        principal: &Principal,
+        customer_service_partial: &CustomerServicePartial,
+    ) -> ResultApp<CustomerService>;
+}
+
+pub struct UpdateCustomerServiceUseCaseImpl {
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+    ownership_claim_repository: Arc<dyn OwnershipClaimRepository>,
+    get_category_use_case: Arc<dyn GetCategoryUseCase>,
+    autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+    flag_content_use_case: Arc<dyn FlagContentUseCase>,
+}
+
+impl UpdateCustomerServiceUseCaseImpl {
+    pub fn new(
+        customer_service_repository: Arc<dyn CustomerServiceRepository>,
+        ownership_claim_repository: Arc<dyn OwnershipClaimRepository>,
+        get_category_use_case: Arc<dyn GetCategoryUseCase>,
+        autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+        flag_content_use_case: Arc<dyn FlagContentUseCase>,
+    ) -> Self {
+        Self {
+            customer_service_repository,
+            ownership_claim_repository,
+            get_category_use_case,
+            autocomplete_use_case,
+            flag_content_use_case,
+        }
+    }
+}
+
+fn customer_service_not_found() -> Arc<AppError> {
+    Arc::new(AppError::NotFound(ErrorData::new(
+        "customer-service-not-found",
+        "customer service not found",
+    )))
+}
+
+#[async_trait::async_trait]
+impl UpdateCustomerServiceUseCase for UpdateCustomerServiceUseCaseImpl {
+    async fn update_customer_service(
+        &self,
+        principal: &Principal,
+        customer_service_partial: &CustomerServicePartial,
+    ) -> ResultApp<CustomerService> {
+        let customer_service_id = match customer_service_partial.id {
+            Some(id) => id,
+            None => return Err(customer_service_not_found()),
+        };
+        let owner_id = self
+            .ownership_claim_repository
+            .find_owner(&customer_service_id)
+            .await?;
+        principal.authorize(UPDATE_CUSTOMER_SERVICE_POLICY, owner_id.as_ref())?;
+        if !principal.has_permission(Permission::CustomerServicesUpdateAny)
+            && !customer_service_partial.only_owner_editable()
+        {
+            return Err(Arc::new(AppError::Forbidden(ErrorData::new(
+                "forbidden",
+                "owners may only edit opening hours, photos and description",
+            ))));
+        }
+        if let Some(categories) = &customer_service_partial.categories {
+            self.get_category_use_case
+                .ensure_categories_exist(categories)
+                .await?;
+        }
+
+        let persisted_customer_service = match self
+            .customer_service_repository
+            .find_by_id(&customer_service_id)
+            .await
+        {
+            Ok(Some(customer_service)) => customer_service,
+            Ok(None) => return Err(customer_service_not_found()),
+            Err(error) => return Err(error),
+        };
+
+        customer_service_partial.ensure_state_city_editable(&persisted_customer_service)?;
+        let customer_service = customer_service_partial.apply_to(&persisted_customer_service);
+        match self
+            .customer_service_repository
+            .update(&customer_service)
+            .await
+        {
+            Ok(Some(customer_service)) => {
+                self.autocomplete_use_case
+                    .index_customer_service(&customer_service);
+                let text_changed = customer_service_partial.name.is_some()
+                    || customer_service_partial.description.is_some();
+                if text_changed
+                    && let Err(error) = self
+                        .flag_content_use_case
+                        .screen_customer_service(
+                            &customer_service,
+                            Some(&persisted_customer_service),
+                        )
+                        .await
+                {
+                    log::warn!(
+                        "could not screen customer service {}: {}",
+                        customer_service.id.value(),
+                        error
+                    );
+                }
+                Ok(customer_service)
+            }
+            Ok(None) => Err(customer_service_not_found()),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::gazetteer::{GazetteerEntry, NearestLocality};
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::infrastructure::geocoder::Geocoder;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub trait GeocodeUseCase: Send + Sync {
+    fn geocode(&self, query: &str) -> ResultApp<GazetteerEntry>;
+    fn reverse_geocode(&self, point: &GeoPoint) -> ResultApp<NearestLocality>;
+}
+
+pub struct GeocodeUseCaseImpl {
+    geocoder: Arc<dyn Geocoder>,
+}
+
+impl GeocodeUseCaseImpl {
+    pub fn new(geocoder: Arc<dyn Geocoder>) -> Self {
+        Self { geocoder }
+    }
+}
+
+impl GeocodeUseCase for GeocodeUseCaseImpl {
+    fn geocode(&self, query: &str) -> ResultApp<GazetteerEntry> {
+        self.geocoder.geocode(query).ok_or_else(|| {
+            Arc::new(AppError::NotFound(
+                ErrorData::new("place-not-found", "no place matches the query")
+                    .with_args(HashMap::from([("q".to_string(), query.to_string())])),
+            )) as _
+        })
+    }
+
+    fn reverse_geocode(&self, point: &GeoPoint) -> ResultApp<NearestLocality> {
+        self.geocoder.reverse_geocode(point).ok_or_else(|| {
+            Arc::new(AppError::NotFound(ErrorData::new(
+                "locality-not-found",
+                "no locality near the point",
+            ))) as _
+        })
+    }
+}
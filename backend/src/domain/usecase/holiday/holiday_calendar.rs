@@ -0,0 +1,123 @@
+use crate::common::result::ResultApp;
+use crate::domain::entity::holiday::ObservedHoliday;
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::holiday_calendar::{HolidayCalendar, brazil_national_holidays};
+use crate::domain::vo::name::Name;
+use crate::repositories::holiday::holiday_repository::HolidayRepository;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+#[async_trait::async_trait]
+pub trait HolidayCalendarUseCase: Send + Sync {
+    // National holidays of the year plus the regional ones of the given state and city
+    async fn list_holidays(
+        &self,
+        year: i32,
+        state: Option<&BrazilState>,
+        city: Option<&Name>,
+    ) -> ResultApp<Vec<ObservedHoliday>>;
+    // Covers the year before and after `at`, enough for opening hours lookahead across New Year
+    // Calendars are kept per day, every read of opening hours would otherwise query the holidays
+    async fn calendar_around(&self, at: DateTime<Utc>) -> ResultApp<HolidayCalendar>;
+    // Drops the kept calendars once regional holidays change
+    fn forget_calendars(&self);
+}
+
+pub struct HolidayCalendarUseCaseImpl {
+    holiday_repository: Arc<dyn HolidayRepository>,
+    calendars: RwLock<HashMap<NaiveDate, HolidayCalendar>>,
+    // Bumped by every forget, a calendar loaded across one is stale and not kept
+    generation: AtomicU64,
+}
+
+impl HolidayCalendarUseCaseImpl {
+    pub fn new(holiday_repository: Arc<dyn HolidayRepository>) -> Self {
+        Self {
+            holiday_repository,
+            calendars: RwLock::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+        }
+    }
+}
+
+fn first_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, 1, 1).unwrap()
+}
+
+fn last_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, 12, 31).unwrap()
+}
+
+#[async_trait::async_trait]
+impl HolidayCalendarUseCase for HolidayCalendarUseCaseImpl {
+    async fn list_holidays(
+        &self,
+        year: i32,
+        state: Option<&BrazilState>,
+        city: Option<&Name>,
+    ) -> ResultApp<Vec<ObservedHoliday>> {
+        let regional = self
+            .holiday_repository
+            .find_between(first_day(year), last_day(year))
+            .await?;
+
+        let mut holidays: Vec<ObservedHoliday> = brazil_national_holidays(year)
+            .into_iter()
+            .map(|(date, name)| ObservedHoliday {
+                id: None,
+                name: name.to_string(),
+                date,
+                state: None,
+                city: None,
+            })
+            .collect();
+        holidays.extend(
+            regional
+                .into_iter()
+                .filter(|holiday| holiday.applies_to(state, city))
+                .filter_map(|holiday| {
+                    holiday.occurrence_in(year).map(|date| ObservedHoliday {
+                        id: Some(holiday.id),
+                        name: holiday.name.value(),
+                        date,
+                        state: holiday.state,
+                        city: holiday.city,
+                    })
+                }),
+        );
+        holidays.sort_by_key(|holiday| holiday.date);
+        Ok(holidays)
+    }
+
+    async fn calendar_around(&self, at: DateTime<Utc>) -> ResultApp<HolidayCalendar> {
+        let day = at.date_naive();
+        if let Some(calendar) = self.calendars.read().unwrap().get(&day) {
+            return Ok(calendar.clone());
+        }
+
+        let generation = self.generation.load(Ordering::SeqCst);
+        let years = at.year() - 1..=at.year() + 1;
+        let regional = self
+            .holiday_repository
+            .find_between(first_day(*years.start()), last_day(*years.end()))
+            .await?;
+        let calendar = HolidayCalendar::brazil(years, regional);
+        let mut calendars = self.calendars.write().unwrap();
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return Ok(calendar);
+        }
+        // Only today and the odd open_at filter are asked for, older days are not needed again
+        calendars.retain(|kept_day, _| *kept_day >= Utc::now().date_naive());
+        calendars.insert(day, calendar.clone());
+        Ok(calendar)
+    }
+
+    fn forget_calendars(&self) {
+        // Bumped under the lock, a load then either sees the new generation or is cleared here
+        let mut calendars = self.calendars.write().unwrap();
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        calendars.clear();
+    }
+}
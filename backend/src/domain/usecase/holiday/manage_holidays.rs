@@ -0,0 +1,61 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::holiday::Holiday;
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::holiday::holiday_calendar::HolidayCalendarUseCase;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::repositories::holiday::holiday_repository::HolidayRepository;
+use std::sync::Arc;
+
+const MANAGE_HOLIDAYS_POLICY: AccessPolicy = AccessPolicy::Require(Permission::HolidaysManage);
+
+#[async_trait::async_trait]
+pub trait ManageHolidaysUseCase: Send + Sync {
+    async fn create_holiday(&self, principal: &Principal, holiday: &Holiday) -> ResultApp<Holiday>;
+    async fn delete_holiday(&self, principal: &Principal, id: &Id) -> ResultApp<Holiday>;
+}
+
+pub struct ManageHolidaysUseCaseImpl {
+    holiday_repository: Arc<dyn HolidayRepository>,
+    holiday_calendar_use_case: Arc<dyn HolidayCalendarUseCase>,
+}
+
+impl ManageHolidaysUseCaseImpl {
+    pub fn new(
+        holiday_repository: Arc<dyn HolidayRepository>,
+        holiday_calendar_use_case: Arc<dyn HolidayCalendarUseCase>,
+    ) -> Self {
+        Self {
+            holiday_repository,
+            holiday_calendar_use_case,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ManageHolidaysUseCase for ManageHolidaysUseCaseImpl {
+    async fn create_holiday(&self, principal: &Principal, holiday: &Holiday) -> ResultApp<Holiday> {
+        principal.authorize(MANAGE_HOLIDAYS_POLICY, None)?;
+
+        let holiday = self.holiday_repository.save(holiday).await?;
+        self.holiday_calendar_use_case.forget_calendars();
+        Ok(holiday)
+    }
+
+    async fn delete_holiday(&self, principal: &Principal, id: &Id) -> ResultApp<Holiday> {
+        principal.authorize(MANAGE_HOLIDAYS_POLICY, None)?;
+
+        match self.holiday_repository.delete(id).await {
+            Ok(Some(holiday)) => {
+                self.holiday_calendar_use_case.forget_calendars();
+                Ok(holiday)
+            }
+            Ok(None) => Err(Arc::new(AppError::NotFound(ErrorData::new(
+                "holiday-not-found",
+                "holiday not found",
+            )))),
+            Err(error) => Err(error),
+        }
+    }
+}
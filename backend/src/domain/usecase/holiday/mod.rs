@@ -0,0 +1,2 @@
+pub mod holiday_calendar;
+pub mod manage_holidays;
@@ -1,2 +1,11 @@
-mod auth;
+pub(crate) mod auth;
+pub(crate) mod autocomplete;
+pub(crate) mod category;
+pub(crate) mod claim;
+pub(crate) mod customer_service;
+pub(crate) mod geocoding;
+pub(crate) mod holiday;
+pub(crate) mod moderation;
+pub(crate) mod person;
+pub(crate) mod review;
 pub(crate) mod user;
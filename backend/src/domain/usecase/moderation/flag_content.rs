@@ -0,0 +1,163 @@
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::CustomerService;
+use crate::domain::entity::moderation_flag::ModerationFlag;
+use crate::domain::entity::principal::Principal;
+use crate::domain::entity::review::Review;
+use crate::domain::usecase::customer_service::get_customer_service::GetCustomerServiceUseCase;
+use crate::domain::usecase::review::get_review::GetReviewUseCase;
+use crate::domain::vo::content_screen::{
+    REVIEW_BURST_WINDOW_MINUTES, is_review_burst, screen_text,
+};
+use crate::domain::vo::description::Description;
+use crate::domain::vo::moderation::{FlagReason, ModerationSubject, PreviousPlaceText};
+use crate::repositories::moderation::moderation_flag_repository::ModerationFlagRepository;
+use crate::repositories::review::review_repository::ReviewRepository;
+use crate::repositories::user::user_repository::UserRepository;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+#[async_trait::async_trait]
+pub trait FlagContentUseCase: Send + Sync {
+    // Any signed in user may report content that is publicly visible
+    async fn report(
+        &self,
+        principal: &Principal,
+        subject: &ModerationSubject,
+        note: &Description,
+    ) -> ResultApp<ModerationFlag>;
+    // Runs the automatic rules on a review that was just written, the burst rule included
+    async fn screen_new_review(&self, review: &Review) -> ResultApp<Vec<ModerationFlag>>;
+    async fn screen_edited_review(&self, review: &Review) -> ResultApp<Vec<ModerationFlag>>;
+    // `previous` is the place before an edit, flags raised on an edit keep its name and description
+    async fn screen_customer_service(
+        &self,
+        customer_service: &CustomerService,
+        previous: Option<&CustomerService>,
+    ) -> ResultApp<Vec<ModerationFlag>>;
+}
+
+pub struct FlagContentUseCaseImpl {
+    moderation_flag_repository: Arc<dyn ModerationFlagRepository>,
+    review_repository: Arc<dyn ReviewRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    get_review_use_case: Arc<dyn GetReviewUseCase>,
+    get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase>,
+}
+
+impl FlagContentUseCaseImpl {
+    pub fn new(
+        moderation_flag_repository: Arc<dyn ModerationFlagRepository>,
+        review_repository: Arc<dyn ReviewRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        get_review_use_case: Arc<dyn GetReviewUseCase>,
+        get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase>,
+    ) -> Self {
+        Self {
+            moderation_flag_repository,
+            review_repository,
+            user_repository,
+            get_review_use_case,
+            get_customer_service_use_case,
+        }
+    }
+
+    async fn save_automatic_flags(
+        &self,
+        subject: ModerationSubject,
+        reasons: Vec<FlagReason>,
+        previous_text: Option<PreviousPlaceText>,
+    ) -> ResultApp<Vec<ModerationFlag>> {
+        let flags = reasons
+            .into_iter()
+            .map(|reason| {
+                Ok(
+                    ModerationFlag::new(subject, reason, None, Description::new(String::new())?)?
+                        .with_previous_text(previous_text.clone()),
+                )
+            })
+            .collect::<ResultApp<Vec<_>>>()?;
+        self.moderation_flag_repository.save_all(&flags).await
+    }
+}
+
+#[async_trait::async_trait]
+impl FlagContentUseCase for FlagContentUseCaseImpl {
+    async fn report(
+        &self,
+        principal: &Principal,
+        subject: &ModerationSubject,
+        note: &Description,
+    ) -> ResultApp<ModerationFlag> {
+        match subject {
+            ModerationSubject::Review(id) => {
+                self.get_review_use_case.get_review(id).await?;
+            }
+            ModerationSubject::CustomerService(id) => {
+                self.get_customer_service_use_case
+                    .get_customer_service(id)
+                    .await?;
+            }
+        }
+
+        let flag = ModerationFlag::new(
+            *subject,
+            FlagReason::UserReport,
+            Some(principal.user_id),
+            note.clone(),
+        )?;
+        let mut saved = self.moderation_flag_repository.save_all(&[flag]).await?;
+        Ok(saved.remove(0))
+    }
+
+    async fn screen_new_review(&self, review: &Review) -> ResultApp<Vec<ModerationFlag>> {
+        let mut reasons = screen_text(&review.text.value());
+
+        if let Some(author) = self.user_repository.find_by_id(&review.author_id).await? {
+            let now = Utc::now();
+            let recent_reviews = self
+                .review_repository
+                .count_by_author_since(
+                    &review.author_id,
+                    now - Duration::minutes(REVIEW_BURST_WINDOW_MINUTES),
+                )
+                .await?;
+            let account_age = now - author.created_at.to_chono_date_time();
+            if is_review_burst(account_age, recent_reviews) {
+                reasons.push(FlagReason::ReviewBurst);
+            }
+        }
+
+        self.save_automatic_flags(ModerationSubject::Review(review.id), reasons, None)
+            .await
+    }
+
+    async fn screen_edited_review(&self, review: &Review) -> ResultApp<Vec<ModerationFlag>> {
+        self.save_automatic_flags(
+            ModerationSubject::Review(review.id),
+            screen_text(&review.text.value()),
+            None,
+        )
+        .await
+    }
+
+    async fn screen_customer_service(
+        &self,
+        customer_service: &CustomerService,
+        previous: Option<&CustomerService>,
+    ) -> ResultApp<Vec<ModerationFlag>> {
+        let text = format!(
+            "{}\n{}",
+            customer_service.name.value(),
+            customer_service.description.value()
+        );
+        self.save_automatic_flags(
+            ModerationSubject::CustomerService(customer_service.id),
+            screen_text(&text),
+            previous.map(|previous| PreviousPlaceText {
+                name: previous.name.clone(),
+                description: previous.description.clone(),
+            }),
+        )
+        .await
+    }
+}
@@ -0,0 +1,2 @@
+pub mod flag_content;
+pub mod moderate_content;
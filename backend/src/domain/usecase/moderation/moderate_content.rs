@@ -0,0 +1,158 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::moderation_flag::{ModerationFlag, text_to_restore};
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::autocomplete::suggest::AutocompleteUseCase;
+use crate::domain::usecase::customer_service::get_customer_service::MAX_PAGE_SIZE;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::moderation::{ModerationStatus, ModerationSubject};
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::repositories::customer_service::customer_service_repository::CustomerServiceRepository;
+use crate::repositories::moderation::moderation_flag_repository::ModerationFlagRepository;
+use crate::repositories::review::review_repository::ReviewRepository;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const MODERATE_CONTENT_POLICY: AccessPolicy = AccessPolicy::Require(Permission::ModerationDecide);
+
+#[async_trait::async_trait]
+pub trait ModerateContentUseCase: Send + Sync {
+    async fn list_queue(
+        &self,
+        principal: &Principal,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<ModerationFlag>>;
+    // Decides the flag together with every other pending flag of the same subject, rejecting
+    // hides the subject, or reverts a place to its text before the flagged edits
+    async fn decide(
+        &self,
+        principal: &Principal,
+        flag_id: &Id,
+        decision: ModerationStatus,
+    ) -> ResultApp<Vec<ModerationFlag>>;
+}
+
+pub struct ModerateContentUseCaseImpl {
+    moderation_flag_repository: Arc<dyn ModerationFlagRepository>,
+    review_repository: Arc<dyn ReviewRepository>,
+    customer_service_repository: Arc<dyn CustomerServiceRepository>,
+    autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+}
+
+impl ModerateContentUseCaseImpl {
+    pub fn new(
+        moderation_flag_repository: Arc<dyn ModerationFlagRepository>,
+        review_repository: Arc<dyn ReviewRepository>,
+        customer_service_repository: Arc<dyn CustomerServiceRepository>,
+        autocomplete_use_case: Arc<dyn AutocompleteUseCase>,
+    ) -> Self {
+        Self {
+            moderation_flag_repository,
+            review_repository,
+            customer_service_repository,
+            autocomplete_use_case,
+        }
+    }
+
+    // Places get their text from before the flagged edits back, or are hidden when the listing
+    // itself was flagged
+    async fn reject(&self, subject: &ModerationSubject) -> ResultApp<()> {
+        let ModerationSubject::CustomerService(customer_service_id) = subject else {
+            return self.hide(subject).await;
+        };
+        let pending = self
+            .moderation_flag_repository
+            .find_pending_by_subject(subject)
+            .await?;
+        let Some(previous_text) = text_to_restore(&pending) else {
+            return self.hide(subject).await;
+        };
+
+        let Some(mut customer_service) = self
+            .customer_service_repository
+            .find_by_id(customer_service_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        customer_service.name = previous_text.name;
+        customer_service.description = previous_text.description;
+        if let Some(customer_service) = self
+            .customer_service_repository
+            .update(&customer_service)
+            .await?
+        {
+            self.autocomplete_use_case
+                .index_customer_service(&customer_service);
+        }
+        Ok(())
+    }
+
+    async fn hide(&self, subject: &ModerationSubject) -> ResultApp<()> {
+        match subject {
+            ModerationSubject::Review(id) => {
+                self.review_repository.hide(id).await?;
+            }
+            ModerationSubject::CustomerService(id) => {
+                if let Some(customer_service) = self.customer_service_repository.hide(id).await? {
+                    self.autocomplete_use_case
+                        .remove_customer_service(&customer_service.id);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ModerateContentUseCase for ModerateContentUseCaseImpl {
+    async fn list_queue(
+        &self,
+        principal: &Principal,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<ModerationFlag>> {
+        principal.authorize(MODERATE_CONTENT_POLICY, None)?;
+        self.moderation_flag_repository
+            .find_pending(limit.clamp(1, MAX_PAGE_SIZE), offset.max(0))
+            .await
+    }
+
+    async fn decide(
+        &self,
+        principal: &Principal,
+        flag_id: &Id,
+        decision: ModerationStatus,
+    ) -> ResultApp<Vec<ModerationFlag>> {
+        principal.authorize(MODERATE_CONTENT_POLICY, None)?;
+
+        let flag = match self.moderation_flag_repository.find_by_id(flag_id).await {
+            Ok(Some(flag)) => flag,
+            Ok(None) => {
+                return Err(Arc::new(AppError::NotFound(ErrorData::new(
+                    "moderation-flag-not-found",
+                    "moderation flag not found",
+                ))));
+            }
+            Err(error) => return Err(error),
+        };
+        if flag.status != ModerationStatus::Pending {
+            return Err(Arc::new(AppError::Conflict(
+                ErrorData::new(
+                    "moderation-flag-already-decided",
+                    "moderation flag was already decided",
+                )
+                .with_args(HashMap::from([("status".to_string(), flag.status.value())])),
+            )));
+        }
+
+        // Rejected first, a failure then leaves the flags pending for another attempt
+        if decision == ModerationStatus::Rejected {
+            self.reject(&flag.subject).await?;
+        }
+        self.moderation_flag_repository
+            .decide(&flag.subject, decision, &principal.user_id)
+            .await
+    }
+}
@@ -0,0 +1,89 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::person::Person;
+use crate::domain::entity::principal::Principal;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::repositories::person::person_repository::PersonRepository;
+use crate::repositories::user::user_repository::UserRepository;
+use std::sync::Arc;
+
+// The profile holds personal data, only its user or a user manager may see it
+const MANAGE_PERSON_POLICY: AccessPolicy = AccessPolicy::OwnerOr(Permission::UsersUpdateAny);
+
+#[async_trait::async_trait]
+pub trait ManagePersonUseCase: Send + Sync {
+    async fn get_person(&self, principal: &Principal, user_id: &Id) -> ResultApp<Person>;
+    // Attaches the profile to its user, replacing the one already attached
+    async fn save_person(&self, principal: &Principal, person: &Person) -> ResultApp<Person>;
+}
+
+pub struct ManagePersonUseCaseImpl {
+    person_repository: Arc<dyn PersonRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl ManagePersonUseCaseImpl {
+    pub fn new(
+        person_repository: Arc<dyn PersonRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            person_repository,
+            user_repository,
+        }
+    }
+}
+
+fn person_not_found() -> Arc<AppError> {
+    Arc::new(AppError::NotFound(ErrorData::new(
+        "person-not-found",
+        "person not found",
+    )))
+}
+
+#[async_trait::async_trait]
+impl ManagePersonUseCase for ManagePersonUseCaseImpl {
+    async fn get_person(&self, principal: &Principal, user_id: &Id) -> ResultApp<Person> {
+        principal.authorize(MANAGE_PERSON_POLICY, Some(user_id))?;
+
+        match self.person_repository.find_by_user_id(user_id).await {
+            Ok(Some(person)) => Ok(person),
+            Ok(None) => Err(person_not_found()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn save_person(&self, principal: &Principal, person: &Person) -> ResultApp<Person> {
+        principal.authorize(MANAGE_PERSON_POLICY, Some(&person.user_id))?;
+        if self
+            .user_repository
+            .find_by_id(&person.user_id)
+            .await?
+            .is_none()
+        {
+            return Err(Arc::new(AppError::NotFound(ErrorData::new(
+                "user-not-found",
+                "user not found",
+            ))));
+        }
+
+        let persisted = self
+            .person_repository
+            .find_by_user_id(&person.user_id)
+            .await?;
+        match persisted {
+            Some(persisted) => {
+                let mut person = person.clone();
+                person.id = persisted.id;
+                person.created_at = persisted.created_at;
+                match self.person_repository.update(&person).await {
+                    Ok(Some(person)) => Ok(person),
+                    Ok(None) => Err(person_not_found()),
+                    Err(error) => Err(error),
+                }
+            }
+            None => self.person_repository.save(person).await,
+        }
+    }
+}
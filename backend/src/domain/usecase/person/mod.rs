@@ -0,0 +1 @@
+pub mod manage_person;
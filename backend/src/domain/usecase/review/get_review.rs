@@ -0,0 +1,71 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::review::Review;
+use crate::domain::usecase::customer_service::get_customer_service::{
+    GetCustomerServiceUseCase, MAX_PAGE_SIZE,
+};
+use crate::domain::vo::id::Id;
+use crate::repositories::review::review_repository::ReviewRepository;
+use std::sync::Arc;
+
+#[async_trait::async_trait]
+pub trait GetReviewUseCase: Send + Sync {
+    async fn get_review(&self, id: &Id) -> ResultApp<Review>;
+    async fn list_reviews(
+        &self,
+        customer_service_id: &Id,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<Review>>;
+}
+
+pub struct GetReviewUseCaseImpl {
+    review_repository: Arc<dyn ReviewRepository>,
+    get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase>,
+}
+
+impl GetReviewUseCaseImpl {
+    pub fn new(
+        review_repository: Arc<dyn ReviewRepository>,
+        get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase>,
+    ) -> Self {
+        Self {
+            review_repository,
+            get_customer_service_use_case,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GetReviewUseCase for GetReviewUseCaseImpl {
+    async fn get_review(&self, id: &Id) -> ResultApp<Review> {
+        match self.review_repository.find_by_id(id).await {
+            Ok(Some(review)) => Ok(review),
+            Ok(None) => Err(Arc::new(AppError::NotFound(ErrorData::new(
+                "review-not-found",
+                "review not found",
+            )))),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn list_reviews(
+        &self,
+        customer_service_id: &Id,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<Review>> {
+        // An unknown place is reported as such instead of as a place without reviews
+        self.get_customer_service_use_case
+            .get_customer_service(customer_service_id)
+            .await?;
+
+        self.review_repository
+            .find_by_customer_service(
+                customer_service_id,
+                limit.clamp(1, MAX_PAGE_SIZE),
+                offset.max(0),
+            )
+            .await
+    }
+}
@@ -0,0 +1,121 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::principal::Principal;
+use crate::domain::entity::review::{Review, ReviewPartial};
+use crate::domain::usecase::customer_service::get_customer_service::GetCustomerServiceUseCase;
+use crate::domain::usecase::moderation::flag_content::FlagContentUseCase;
+use crate::domain::usecase::review::get_review::GetReviewUseCase;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::repositories::review::review_repository::ReviewRepository;
+use std::sync::Arc;
+
+const DELETE_REVIEW_POLICY: AccessPolicy = AccessPolicy::OwnerOr(Permission::ReviewsDeleteAny);
+
+#[async_trait::async_trait]
+pub trait ManageReviewsUseCase: Send + Sync {
+    // The review is written in the name of the principal, whatever author it carries
+    async fn create_review(&self, principal: &Principal, review: &Review) -> ResultApp<Review>;
+    // Only the author may change what a review says
+    async fn update_review(
+        &self,
+        principal: &Principal,
+        id: &Id,
+        review_partial: &ReviewPartial,
+    ) -> ResultApp<Review>;
+    async fn delete_review(&self, principal: &Principal, id: &Id) -> ResultApp<Review>;
+}
+
+pub struct ManageReviewsUseCaseImpl {
+    review_repository: Arc<dyn ReviewRepository>,
+    get_review_use_case: Arc<dyn GetReviewUseCase>,
+    get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase>,
+    flag_content_use_case: Arc<dyn FlagContentUseCase>,
+}
+
+impl ManageReviewsUseCaseImpl {
+    pub fn new(
+        review_repository: Arc<dyn ReviewRepository>,
+        get_review_use_case: Arc<dyn GetReviewUseCase>,
+        get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase>,
+        flag_content_use_case: Arc<dyn FlagContentUseCase>,
+    ) -> Self {
+        Self {
+            review_repository,
+            get_review_use_case,
+            get_customer_service_use_case,
+            flag_content_use_case,
+        }
+    }
+}
+
+fn review_not_found() -> Arc<AppError> {
+    Arc::new(AppError::NotFound(ErrorData::new(
+        "review-not-found",
+        "review not found",
+    )))
+}
+
+#[async_trait::async_trait]
+impl ManageReviewsUseCase for ManageReviewsUseCaseImpl {
+    async fn create_review(&self, principal: &Principal, review: &Review) -> ResultApp<Review> {
+        self.get_customer_service_use_case
+            .get_customer_service(&review.customer_service_id)
+            .await?;
+
+        let mut review = review.clone();
+        review.author_id = principal.user_id;
+        let review = self.review_repository.save(&review).await?;
+        // The review is already written, a failing screen must not report it as lost
+        if let Err(error) = self.flag_content_use_case.screen_new_review(&review).await {
+            log::warn!("could not screen review {}: {}", review.id.value(), error);
+        }
+        Ok(review)
+    }
+
+    async fn update_review(
+        &self,
+        principal: &Principal,
+        id: &Id,
+        review_partial: &ReviewPartial,
+    ) -> ResultApp<Review> {
+        let persisted = self.get_review_use_case.get_review(id).await?;
+        if persisted.author_id != principal.user_id {
+            return Err(Arc::new(AppError::Forbidden(ErrorData::new(
+                "forbidden",
+                "only the author may edit a review",
+            ))));
+        }
+
+        match self
+            .review_repository
+            .update(&review_partial.apply_to(&persisted))
+            .await
+        {
+            Ok(Some(review)) => {
+                if review_partial.text.is_some()
+                    && let Err(error) = self
+                        .flag_content_use_case
+                        .screen_edited_review(&review)
+                        .await
+                {
+                    log::warn!("could not screen review {}: {}", review.id.value(), error);
+                }
+                Ok(review)
+            }
+            Ok(None) => Err(review_not_found()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn delete_review(&self, principal: &Principal, id: &Id) -> ResultApp<Review> {
+        let persisted = self.get_review_use_case.get_review(id).await?;
+        principal.authorize(DELETE_REVIEW_POLICY, Some(&persisted.author_id))?;
+
+        match self.review_repository.delete(id).await {
+            Ok(Some(review)) => Ok(review),
+            Ok(None) => Err(review_not_found()),
+            Err(error) => Err(error),
+        }
+    }
+}
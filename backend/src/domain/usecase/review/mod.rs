@@ -0,0 +1,2 @@
+pub mod get_review;
+pub mod manage_reviews;
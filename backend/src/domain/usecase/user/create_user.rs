@@ -1,6 +1,9 @@
+use crate::common::error::{AppError, ErrorData};
 use crate::common::result::ResultApp;
 use crate::domain::entity::user::User;
+use crate::domain::vo::role::Role;
 use crate::repositories::user::user_repository::UserRepository;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[async_trait::async_trait]
@@ -21,6 +24,20 @@ impl CreateUserUseCaseImpl {
 #[async_trait::async_trait]
 impl CreateUserUseCase for CreateUserUseCaseImpl {
     async fn create_user(&self, user: &User) -> ResultApp<User> {
-        self.user_repository.save(user).await
+        // The unique index on users.email still guards against concurrent signups
+        match self.user_repository.find_by_email(&user.email).await {
+            Ok(Some(_)) => {
+                return Err(Arc::new(AppError::Conflict(
+                    ErrorData::new("email-already-registered", "email already registered")
+                        .with_args(HashMap::from([("email".to_string(), user.email.value())])),
+                )));
+            }
+            Ok(None) => {}
+            Err(error) => return Err(error),
+        }
+
+        self.user_repository
+            .save_with_role(user, Role::RegularUser)
+            .await
     }
 }
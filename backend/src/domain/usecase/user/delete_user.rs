@@ -1,14 +1,18 @@
 use crate::common::error::{AppError, ErrorData};
 use crate::common::result::ResultApp;
+use crate::domain::entity::principal::Principal;
 use crate::domain::entity::user::User;
 use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
 use crate::repositories::user::user_repository::UserRepository;
 use std::error::Error;
 use std::sync::Arc;
 
+const DELETE_USER_POLICY: AccessPolicy = AccessPolicy::OwnerOr(Permission::UsersDeleteAny);
+
 #[async_trait::async_trait]
 pub trait DeleteUserUseCase: Send + Sync {
-    async fn delete_user(&self, user_id: &Id) -> ResultApp<User>;
+    async fn delete_user(&self, principal: &Principal, user_id: &Id) -> ResultApp<User>;
 }
 
 pub struct DeleteUserUseCaseImpl {
@@ -23,7 +27,9 @@ impl DeleteUserUseCaseImpl {
 
 #[async_trait::async_trait]
 impl DeleteUserUseCase for DeleteUserUseCaseImpl {
-    async fn delete_user(&self, user_id: &Id) -> ResultApp<User> {
+    async fn delete_user(&self, principal: &Principal, user_id: &Id) -> ResultApp<User> {
+        principal.authorize(DELETE_USER_POLICY, Some(user_id))?;
+
         match self.user_repository.delete(user_id).await {
             Ok(user) => {
                 match user {
@@ -32,7 +38,7 @@ impl DeleteUserUseCase for DeleteUserUseCaseImpl {
                         // Create a simple error as the cause
                         let custom_err =
                             std::io::Error::new(std::io::ErrorKind::NotFound, "User not found");
-                        let err_arc: Arc<dyn Error> = Arc::new(custom_err);
+                        let err_arc: Arc<dyn Error + Send + Sync> = Arc::new(custom_err);
                         Err(Arc::new(AppError::NotFound(
                             ErrorData::new("user-not-found", "user not found")
                                 .with_cause(Some(err_arc)),
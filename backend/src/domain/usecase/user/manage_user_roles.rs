@@ -0,0 +1,77 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::principal::Principal;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::{AccessPolicy, Permission};
+use crate::domain::vo::role::Role;
+use crate::repositories::user::user_repository::UserRepository;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const MANAGE_USER_ROLES_POLICY: AccessPolicy = AccessPolicy::Require(Permission::RolesManage);
+
+#[async_trait::async_trait]
+pub trait ManageUserRolesUseCase: Send + Sync {
+    async fn grant_role(
+        &self,
+        principal: &Principal,
+        user_id: &Id,
+        role: Role,
+    ) -> ResultApp<HashSet<Role>>;
+    async fn revoke_role(
+        &self,
+        principal: &Principal,
+        user_id: &Id,
+        role: Role,
+    ) -> ResultApp<HashSet<Role>>;
+}
+
+pub struct ManageUserRolesUseCaseImpl {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl ManageUserRolesUseCaseImpl {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+        Self { user_repository }
+    }
+
+    async fn ensure_user_exists(&self, user_id: &Id) -> ResultApp<()> {
+        match self.user_repository.find_by_id(user_id).await {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(Arc::new(AppError::NotFound(ErrorData::new(
+                "user-not-found",
+                "user not found",
+            )))),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ManageUserRolesUseCase for ManageUserRolesUseCaseImpl {
+    async fn grant_role(
+        &self,
+        principal: &Principal,
+        user_id: &Id,
+        role: Role,
+    ) -> ResultApp<HashSet<Role>> {
+        principal.authorize(MANAGE_USER_ROLES_POLICY, None)?;
+        self.ensure_user_exists(user_id).await?;
+
+        self.user_repository.add_role(user_id, role).await?;
+        self.user_repository.find_roles(user_id).await
+    }
+
+    async fn revoke_role(
+        &self,
+        principal: &Principal,
+        user_id: &Id,
+        role: Role,
+    ) -> ResultApp<HashSet<Role>> {
+        principal.authorize(MANAGE_USER_ROLES_POLICY, None)?;
+        self.ensure_user_exists(user_id).await?;
+
+        self.user_repository.remove_role(user_id, role).await?;
+        self.user_repository.find_roles(user_id).await
+    }
+}
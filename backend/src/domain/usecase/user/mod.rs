@@ -1,3 +1,4 @@
 pub mod create_user;
 pub mod delete_user;
+pub mod manage_user_roles;
 pub mod update_user;
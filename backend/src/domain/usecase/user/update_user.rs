@@ -1,12 +1,21 @@
 use crate::common::error::{AppError, ErrorData};
 use crate::common::result::ResultApp;
+use crate::domain::entity::principal::Principal;
 use crate::domain::entity::user::{User, UserPartial};
+use crate::domain::vo::permission::{AccessPolicy, Permission};
 use crate::repositories::user::user_repository::UserRepository;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+const UPDATE_USER_POLICY: AccessPolicy = AccessPolicy::OwnerOr(Permission::UsersUpdateAny);
+
 #[async_trait::async_trait]
 pub trait UpdateUserUseCase: Send + Sync {
-    async fn update_user(&self, user_partial: &UserPartial) -> ResultApp<User>;
+    async fn update_user(
+        &self,
+        principal: &Principal,
+        user_partial: &UserPartial,
+    ) -> ResultApp<User>;
 }
 
 pub struct UpdateUserUseCaseImpl {
@@ -21,7 +30,13 @@ impl UpdateUserUseCaseImpl {
 
 #[async_trait::async_trait]
 impl UpdateUserUseCase for UpdateUserUseCaseImpl {
-    async fn update_user(&self, user_partial: &UserPartial) -> ResultApp<User> {
+    async fn update_user(
+        &self,
+        principal: &Principal,
+        user_partial: &UserPartial,
+    ) -> ResultApp<User> {
+        principal.authorize(UPDATE_USER_POLICY, user_partial.id.as_ref())?;
+
         let persisted_user = match self
             .user_repository
             .find_by_id(&user_partial.id.unwrap())
@@ -39,6 +54,19 @@ impl UpdateUserUseCase for UpdateUserUseCaseImpl {
             Err(error) => return Err(error),
         };
 
+        if let Some(new_email) = &user_partial.email {
+            match self.user_repository.find_by_email(new_email).await {
+                Ok(Some(other)) if other.id != persisted_user.id => {
+                    return Err(Arc::new(AppError::Conflict(
+                        ErrorData::new("email-already-registered", "email already registered")
+                            .with_args(HashMap::from([("email".to_string(), new_email.value())])),
+                    )));
+                }
+                Ok(_) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
         let user = User::new(
             user_partial.id.unwrap(),
             user_partial
@@ -62,8 +90,6 @@ impl UpdateUserUseCase for UpdateUserUseCaseImpl {
             persisted_user.deleted_at,
         );
 
-        // TODO Match with the session
-
         match self.user_repository.update(&user).await {
             Ok(user) => match user {
                 Some(user) => Ok(user),
@@ -0,0 +1,479 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::name::Name;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// Postal code ranges (first five CEP digits) of each federative unit, as assigned by Correios
+const BRAZIL_CEP_RANGES: [(u32, u32, &str); 30] = [
+    (1000, 19999, "SP"),
+    (20000, 28999, "RJ"),
+    (29000, 29999, "ES"),
+    (30000, 39999, "MG"),
+    (40000, 48999, "BA"),
+    (49000, 49999, "SE"),
+    (50000, 56999, "PE"),
+    (57000, 57999, "AL"),
+    (58000, 58999, "PB"),
+    (59000, 59999, "RN"),
+    (60000, 63999, "CE"),
+    (64000, 64999, "PI"),
+    (65000, 65999, "MA"),
+    (66000, 68899, "PA"),
+    (68900, 68999, "AP"),
+    (69000, 69299, "AM"),
+    (69300, 69399, "RR"),
+    (69400, 69899, "AM"),
+    (69900, 69999, "AC"),
+    (70000, 72799, "DF"),
+    (72800, 72999, "GO"),
+    (73000, 73699, "DF"),
+    (73700, 76799, "GO"),
+    (76800, 76999, "RO"),
+    (77000, 77999, "TO"),
+    (78000, 78899, "MT"),
+    (79000, 79999, "MS"),
+    (80000, 87999, "PR"),
+    (88000, 89999, "SC"),
+    (90000, 99999, "RS"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressCountry {
+    BR,
+    AR,
+    PT,
+    US,
+}
+
+impl AddressCountry {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim().to_uppercase().as_str() {
+            "BR" => Ok(AddressCountry::BR),
+            "AR" => Ok(AddressCountry::AR),
+            "PT" => Ok(AddressCountry::PT),
+            "US" => Ok(AddressCountry::US),
+            other => Err(invalid(
+                "invalid-address-country",
+                "Unsupported address country",
+                "country",
+                other,
+            )),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            AddressCountry::BR => "BR",
+            AddressCountry::AR => "AR",
+            AddressCountry::PT => "PT",
+            AddressCountry::US => "US",
+        }
+        .to_string()
+    }
+}
+
+// Raw components as they come from a request or a row, checked by Address::new
+#[derive(Debug, Clone, Default)]
+pub struct AddressData {
+    pub street: String,
+    pub number: Option<String>,
+    pub complement: Option<String>,
+    pub neighbourhood: Option<String>,
+    pub city: String,
+    pub state: Option<String>,
+    pub postal_code: String,
+    pub country: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Address {
+    street: String,
+    // None for places without a number, "s/n" in Brazil
+    number: Option<String>,
+    complement: Option<String>,
+    neighbourhood: Option<String>,
+    city: Name,
+    // UF in Brazil, the state or province code elsewhere
+    state: Option<String>,
+    // Digits and letters only, separators are added back by formatted_postal_code
+    postal_code: String,
+    country: AddressCountry,
+}
+
+impl Address {
+    pub fn new(data: AddressData) -> ResultApp<Self> {
+        let country = AddressCountry::new(&data.country)?;
+        let street = required_text("street", &data.street, 200)?;
+        let number = optional_text("number", data.number, 20)?;
+        let complement = optional_text("complement", data.complement, 100)?;
+        let neighbourhood = optional_text("neighbourhood", data.neighbourhood, 120)?;
+        let city = Name::new(&data.city)?;
+        let postal_code = normalize_postal_code(country, &data.postal_code)?;
+        let state = normalize_state(country, data.state)?;
+
+        if country == AddressCountry::BR {
+            ensure_cep_matches_state(&postal_code, state.as_deref().unwrap_or_default())?;
+        }
+
+        Ok(Address {
+            street,
+            number,
+            complement,
+            neighbourhood,
+            city,
+            state,
+            postal_code,
+            country,
+        })
+    }
+
+    pub fn street(&self) -> String {
+        self.street.clone()
+    }
+
+    pub fn number(&self) -> Option<String> {
+        self.number.clone()
+    }
+
+    pub fn complement(&self) -> Option<String> {
+        self.complement.clone()
+    }
+
+    pub fn neighbourhood(&self) -> Option<String> {
+        self.neighbourhood.clone()
+    }
+
+    pub fn city(&self) -> Name {
+        self.city.clone()
+    }
+
+    pub fn state(&self) -> Option<String> {
+        self.state.clone()
+    }
+
+    pub fn postal_code(&self) -> String {
+        self.postal_code.clone()
+    }
+
+    pub fn country(&self) -> AddressCountry {
+        self.country
+    }
+
+    // The UF holidays are matched against, None outside Brazil
+    pub fn brazil_state(&self) -> Option<BrazilState> {
+        match self.country {
+            AddressCountry::BR => self.state.as_ref().and_then(|s| BrazilState::new(s).ok()),
+            _ => None,
+        }
+    }
+
+    // Postal code with the separators customary in its country, 01310-100 for a CEP
+    pub fn formatted_postal_code(&self) -> String {
+        let code = &self.postal_code;
+        match self.country {
+            AddressCountry::BR => format!("{}-{}", &code[..5], &code[5..]),
+            AddressCountry::PT => format!("{}-{}", &code[..4], &code[4..]),
+            AddressCountry::US if code.len() == 9 => format!("{}-{}", &code[..5], &code[5..]),
+            AddressCountry::US | AddressCountry::AR => code.clone(),
+        }
+    }
+
+    // One line in the order the postal service of the country prints it
+    pub fn single_line(&self) -> String {
+        let city = self.city.value();
+        let postal_code = self.formatted_postal_code();
+        let complement = self
+            .complement
+            .as_ref()
+            .map(|complement| format!(", {}", complement))
+            .unwrap_or_default();
+
+        match self.country {
+            // Av. Paulista, 1578, Conj. 12 - Bela Vista, São Paulo - SP, 01310-200
+            AddressCountry::BR => {
+                let number = self.number.as_deref().unwrap_or("s/n");
+                let neighbourhood = self
+                    .neighbourhood
+                    .as_ref()
+                    .map(|neighbourhood| format!(" - {}", neighbourhood))
+                    .unwrap_or_default();
+                let state = self.state.as_deref().unwrap_or_default();
+                format!(
+                    "{}, {}{}{}, {} - {}, {}",
+                    self.street, number, complement, neighbourhood, city, state, postal_code
+                )
+            }
+            // 1600 Pennsylvania Ave NW, Suite 100, Washington, DC 20500
+            AddressCountry::US => {
+                let street = match &self.number {
+                    Some(number) => format!("{} {}", number, self.street),
+                    None => self.street.clone(),
+                };
+                let state = self.state.as_deref().unwrap_or_default();
+                format!(
+                    "{}{}, {}, {} {}",
+                    street, complement, city, state, postal_code
+                )
+            }
+            // Rua Augusta 100, 1100-053 Lisboa and Av. Corrientes 1234, C1043AAZ Buenos Aires
+            AddressCountry::PT | AddressCountry::AR => {
+                let street = match &self.number {
+                    Some(number) => format!("{} {}", self.street, number),
+                    None => self.street.clone(),
+                };
+                format!("{}{}, {} {}", street, complement, postal_code, city)
+            }
+        }
+    }
+}
+
+fn invalid(code: &str, message: &str, key: &str, value: &str) -> Arc<AppError> {
+    Arc::new(AppError::Validation(
+        ErrorData::new(code, message)
+            .with_args(HashMap::from([(key.to_string(), value.to_string())])),
+    ))
+}
+
+fn required_text(field: &str, value: &str, max: usize) -> ResultApp<String> {
+    let value = value.trim();
+    if value.is_empty() || value.chars().count() > max {
+        return Err(invalid(
+            "invalid-address",
+            "address component is empty or too long",
+            "field",
+            field,
+        ));
+    }
+    Ok(value.to_string())
+}
+
+// Blank optional components are dropped rather than rejected
+fn optional_text(field: &str, value: Option<String>, max: usize) -> ResultApp<Option<String>> {
+    match value {
+        Some(value) if !value.trim().is_empty() => required_text(field, &value, max).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn normalize_postal_code(country: AddressCountry, value: &str) -> ResultApp<String> {
+    let code: String = value
+        .chars()
+        .filter(|c| !matches!(c, '-' | '.' | ' '))
+        .collect::<String>()
+        .to_uppercase();
+    let pattern = match country {
+        AddressCountry::BR => r"^\d{8}$",
+        AddressCountry::PT => r"^\d{7}$",
+        AddressCountry::US => r"^\d{5}(?:\d{4})?$",
+        // CPA such as C1043AAZ, or the four digit code it replaced
+        AddressCountry::AR => r"^(?:[A-Z]\d{4}[A-Z]{3}|\d{4})$",
+    };
+    if !Regex::new(pattern).unwrap().is_match(&code) {
+        return Err(Arc::new(AppError::Validation(
+            ErrorData::new("invalid-postal-code", "Invalid postal code").with_args(HashMap::from(
+                [
+                    ("postal_code".to_string(), value.to_string()),
+                    ("country".to_string(), country.value()),
+                ],
+            )),
+        )));
+    }
+    Ok(code)
+}
+
+// A UF in Brazil and a two letter state in the US are mandatory, elsewhere the code is optional
+fn normalize_state(country: AddressCountry, value: Option<String>) -> ResultApp<Option<String>> {
+    let state = value
+        .map(|state| state.trim().to_uppercase())
+        .filter(|state| !state.is_empty());
+    match (country, state) {
+        (AddressCountry::BR, Some(state)) => Ok(Some(BrazilState::new(state)?.value())),
+        (AddressCountry::US, Some(state))
+            if Regex::new(r"^[A-Z]{2}$").unwrap().is_match(&state) =>
+        {
+            Ok(Some(state))
+        }
+        (AddressCountry::AR | AddressCountry::PT, Some(state))
+            if Regex::new(r"^[A-Z0-9]{1,2}$").unwrap().is_match(&state) =>
+        {
+            Ok(Some(state))
+        }
+        (AddressCountry::AR | AddressCountry::PT, None) => Ok(None),
+        (_, state) => Err(invalid(
+            "invalid-address-state",
+            "Missing or invalid state for the address country",
+            "state",
+            &state.unwrap_or_default(),
+        )),
+    }
+}
+
+fn brazil_state_for_cep(cep: &str) -> Option<&'static str> {
+    let prefix: u32 = cep[..5].parse().ok()?;
+    BRAZIL_CEP_RANGES
+        .iter()
+        .find(|(start, end, _)| (*start..=*end).contains(&prefix))
+        .map(|(_, _, state)| *state)
+}
+
+fn ensure_cep_matches_state(cep: &str, state: &str) -> ResultApp<()> {
+    match brazil_state_for_cep(cep) {
+        Some(expected) if expected == state => Ok(()),
+        expected => Err(Arc::new(AppError::Validation(
+            ErrorData::new(
+                "postal-code-state-mismatch",
+                "CEP does not belong to the state",
+            )
+            .with_args(HashMap::from([
+                ("postal_code".to_string(), cep.to_string()),
+                ("state".to_string(), state.to_string()),
+                (
+                    "expected_state".to_string(),
+                    expected.unwrap_or_default().to_string(),
+                ),
+            ])),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::error_code;
+
+    fn brazilian(postal_code: &str, state: &str) -> AddressData {
+        AddressData {
+            street: "Av. Paulista".to_string(),
+            number: Some("1578".to_string()),
+            complement: None,
+            neighbourhood: Some("Bela Vista".to_string()),
+            city: "São Paulo".to_string(),
+            state: Some(state.to_string()),
+            postal_code: postal_code.to_string(),
+            country: "br".to_string(),
+        }
+    }
+
+    #[test]
+    fn brazilian_address_is_normalized_and_rendered() {
+        let mut data = brazilian("01310.100", "sp");
+        data.complement = Some(" Conj. 12 ".to_string());
+        let address = Address::new(data).unwrap();
+        assert_eq!(address.postal_code(), "01310100");
+        assert_eq!(address.formatted_postal_code(), "01310-100");
+        assert_eq!(address.brazil_state().unwrap().value(), "SP");
+        assert_eq!(
+            address.single_line(),
+            "Av. Paulista, 1578, Conj. 12 - Bela Vista, São Paulo - SP, 01310-100"
+        );
+
+        let mut data = brazilian("70040-010", "DF");
+        data.number = Some("  ".to_string());
+        data.neighbourhood = None;
+        data.city = "Brasília".to_string();
+        assert_eq!(
+            Address::new(data).unwrap().single_line(),
+            "Av. Paulista, s/n, Brasília - DF, 70040-010"
+        );
+    }
+
+    #[test]
+    fn cep_must_belong_to_the_state() {
+        assert_eq!(
+            error_code(Address::new(brazilian("01310-100", "RJ"))),
+            "postal-code-state-mismatch"
+        );
+        assert!(Address::new(brazilian("69301-000", "RR")).is_ok());
+        assert!(Address::new(brazilian("69400-000", "AM")).is_ok());
+        // Reserved, no state owns it
+        assert_eq!(
+            error_code(Address::new(brazilian("00999-000", "SP"))),
+            "postal-code-state-mismatch"
+        );
+    }
+
+    #[test]
+    fn brazilian_components_are_checked() {
+        assert_eq!(
+            error_code(Address::new(brazilian("1310-100", "SP"))),
+            "invalid-postal-code"
+        );
+        assert_eq!(
+            error_code(Address::new(brazilian("01310-100", "XX"))),
+            "invalid-state"
+        );
+        let mut data = brazilian("01310-100", "SP");
+        data.state = None;
+        assert_eq!(error_code(Address::new(data)), "invalid-address-state");
+        let mut data = brazilian("01310-100", "SP");
+        data.street = " ".to_string();
+        assert_eq!(error_code(Address::new(data)), "invalid-address");
+    }
+
+    #[test]
+    fn other_countries_follow_their_conventions() {
+        let us = Address::new(AddressData {
+            street: "Pennsylvania Ave NW".to_string(),
+            number: Some("1600".to_string()),
+            city: "Washington".to_string(),
+            state: Some("dc".to_string()),
+            postal_code: "205000003".to_string(),
+            country: "US".to_string(),
+            ..AddressData::default()
+        })
+        .unwrap();
+        assert_eq!(
+            us.single_line(),
+            "1600 Pennsylvania Ave NW, Washington, DC 20500-0003"
+        );
+        assert_eq!(us.brazil_state(), None);
+
+        let pt = Address::new(AddressData {
+            street: "Rua Augusta".to_string(),
+            number: Some("100".to_string()),
+            city: "Lisboa".to_string(),
+            postal_code: "1100-053".to_string(),
+            country: "PT".to_string(),
+            ..AddressData::default()
+        })
+        .unwrap();
+        assert_eq!(pt.single_line(), "Rua Augusta 100, 1100-053 Lisboa");
+
+        let ar = Address::new(AddressData {
+            street: "Av. Corrientes".to_string(),
+            number: Some("1234".to_string()),
+            complement: Some("Piso 3".to_string()),
+            city: "Buenos Aires".to_string(),
+            state: Some("C".to_string()),
+            postal_code: "c1043aaz".to_string(),
+            country: "AR".to_string(),
+            ..AddressData::default()
+        })
+        .unwrap();
+        assert_eq!(
+            ar.single_line(),
+            "Av. Corrientes 1234, Piso 3, C1043AAZ Buenos Aires"
+        );
+
+        assert_eq!(
+            error_code(Address::new(AddressData {
+                street: "Main St".to_string(),
+                city: "Springfield".to_string(),
+                postal_code: "62701".to_string(),
+                country: "US".to_string(),
+                ..AddressData::default()
+            })),
+            "invalid-address-state"
+        );
+        assert_eq!(
+            error_code(Address::new(AddressData {
+                country: "MX".to_string(),
+                ..AddressData::default()
+            })),
+            "invalid-address-country"
+        );
+    }
+}
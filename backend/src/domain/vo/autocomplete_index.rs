@@ -0,0 +1,438 @@
+use crate::domain::entity::category::Category;
+use crate::domain::entity::customer_service::CustomerService;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::search_query::SearchQuery;
+use crate::domain::vo::tags::{TAG_SCHEMAS, TagValueType, tag_schema};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+// Distance at which a place keeps half of its score
+const DISTANCE_HALF_SCORE_METERS: f64 = 2_000.0;
+// Suggestions whose text starts with the query rank above ones matching a later word
+const LEADING_MATCH_BONUS: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuggestionKind {
+    CustomerService,
+    Category,
+    Tag,
+}
+
+impl SuggestionKind {
+    fn weight(&self) -> f64 {
+        match self {
+            SuggestionKind::Category => 1.2,
+            SuggestionKind::CustomerService => 1.0,
+            SuggestionKind::Tag => 0.8,
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            SuggestionKind::CustomerService => "customer_service",
+            SuggestionKind::Category => "category",
+            SuggestionKind::Tag => "tag",
+        }
+        .to_string()
+    }
+}
+
+// `value` is what the client searches with next: an id, a category slug or a `key=value` tag
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub kind: SuggestionKind,
+    pub text: String,
+    pub value: String,
+    pub location: Option<GeoPoint>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedSuggestion {
+    pub suggestion: Suggestion,
+    pub distance_meters: Option<f64>,
+}
+
+type EntryKey = (SuggestionKind, String, String);
+
+#[derive(Debug, Clone)]
+struct Entry {
+    suggestion: Suggestion,
+    // Free text tag values are shared by every place carrying them
+    references: usize,
+}
+
+// Lowercase without diacritics, so "São João" is found by "sao jo"
+pub fn fold_accents(s: &str) -> String {
+    s.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            other => other,
+        })
+        .collect()
+}
+
+fn words_of(text: &str) -> HashSet<String> {
+    fold_accents(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn tag_text(key: &str) -> String {
+    key.replace([':', '_'], " ")
+}
+
+// Sorted word map, every word of a suggestion points at it and a prefix is a range scan
+#[derive(Debug, Clone)]
+pub struct AutocompleteIndex {
+    words: BTreeMap<String, HashSet<EntryKey>>,
+    entries: HashMap<EntryKey, Entry>,
+    by_customer_service: HashMap<Id, Vec<EntryKey>>,
+    by_category: HashMap<CustomerServiceCategory, Vec<EntryKey>>,
+}
+
+impl Default for AutocompleteIndex {
+    // Starts with the yes/no and enumerated tags of the schema, they never change at runtime
+    fn default() -> Self {
+        let mut index = AutocompleteIndex {
+            words: BTreeMap::new(),
+            entries: HashMap::new(),
+            by_customer_service: HashMap::new(),
+            by_category: HashMap::new(),
+        };
+        for schema in TAG_SCHEMAS {
+            match schema.value_type {
+                TagValueType::Bool => {
+                    index.retain(Suggestion {
+                        kind: SuggestionKind::Tag,
+                        text: tag_text(schema.key),
+                        value: format!("{}=yes", schema.key),
+                        location: None,
+                    });
+                }
+                TagValueType::Enum(options) => {
+                    for option in options.iter().filter(|option| **option != "no") {
+                        index.retain(Suggestion {
+                            kind: SuggestionKind::Tag,
+                            text: format!("{} {}", tag_text(schema.key), option),
+                            value: format!("{}={}", schema.key, option),
+                            location: None,
+                        });
+                    }
+                }
+                TagValueType::Integer { .. } | TagValueType::Text { .. } => {}
+            }
+        }
+        index
+    }
+}
+
+impl AutocompleteIndex {
+    fn retain(&mut self, suggestion: Suggestion) -> EntryKey {
+        let key = (
+            suggestion.kind,
+            suggestion.value.clone(),
+            suggestion.text.clone(),
+        );
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.references += 1;
+            return key;
+        }
+
+        for word in words_of(&suggestion.text) {
+            self.words.entry(word).or_default().insert(key.clone());
+        }
+        self.entries.insert(
+            key.clone(),
+            Entry {
+                suggestion,
+                references: 1,
+            },
+        );
+        key
+    }
+
+    fn release(&mut self, key: &EntryKey) {
+        let Some(entry) = self.entries.get_mut(key) else {
+            return;
+        };
+        entry.references -= 1;
+        if entry.references > 0 {
+            return;
+        }
+
+        let entry = self.entries.remove(key).unwrap();
+        for word in words_of(&entry.suggestion.text) {
+            if let Some(keys) = self.words.get_mut(&word) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.words.remove(&word);
+                }
+            }
+        }
+    }
+
+    // Replaces whatever was indexed for the place, deleted places are only removed
+    pub fn upsert_customer_service(&mut self, customer_service: &CustomerService) {
+        self.remove_customer_service(&customer_service.id);
+        if customer_service.deleted {
+            return;
+        }
+
+        let mut keys = vec![self.retain(Suggestion {
+            kind: SuggestionKind::CustomerService,
+            text: customer_service.name.value(),
+            value: customer_service.id.value(),
+            location: Some(customer_service.location),
+        })];
+        for (key, value) in customer_service.tags.value() {
+            let free_text = tag_schema(&key)
+                .is_some_and(|schema| matches!(schema.value_type, TagValueType::Text { .. }));
+            if free_text {
+                keys.push(self.retain(Suggestion {
+                    kind: SuggestionKind::Tag,
+                    text: value.clone(),
+                    value: format!("{}={}", key, value),
+                    location: None,
+                }));
+            }
+        }
+        self.by_customer_service.insert(customer_service.id, keys);
+    }
+
+    pub fn remove_customer_service(&mut self, id: &Id) {
+        for key in self.by_customer_service.remove(id).unwrap_or_default() {
+            self.release(&key);
+        }
+    }
+
+    // Every label is indexed so the category is found in any locale
+    pub fn upsert_category(&mut self, category: &Category) {
+        self.remove_category(&category.slug);
+
+        let keys = category
+            .labels
+            .value()
+            .into_values()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|label| {
+                self.retain(Suggestion {
+                    kind: SuggestionKind::Category,
+                    text: label,
+                    value: category.slug.value(),
+                    location: None,
+                })
+            })
+            .collect();
+        self.by_category.insert(category.slug.clone(), keys);
+    }
+
+    pub fn remove_category(&mut self, slug: &CustomerServiceCategory) {
+        for key in self.by_category.remove(slug).unwrap_or_default() {
+            self.release(&key);
+        }
+    }
+
+    fn matching_prefix(&self, prefix: &str) -> HashSet<&EntryKey> {
+        self.words
+            .range(prefix.to_string()..)
+            .take_while(|(word, _)| word.starts_with(prefix))
+            .flat_map(|(_, keys)| keys.iter())
+            .collect()
+    }
+
+    // Every query word must prefix some word of the suggestion, places near `near` rank higher
+    pub fn suggest(
+        &self,
+        query: &SearchQuery,
+        near: Option<&GeoPoint>,
+        limit: usize,
+    ) -> Vec<RankedSuggestion> {
+        let terms: Vec<String> = query
+            .terms()
+            .iter()
+            .map(|term| fold_accents(term))
+            .collect();
+        let Some(mut candidates) = terms
+            .iter()
+            .map(|term| self.matching_prefix(term))
+            .reduce(|found, next| found.intersection(&next).copied().collect())
+        else {
+            return vec![];
+        };
+
+        let leading = terms.join(" ");
+        let mut ranked: Vec<(f64, RankedSuggestion)> = candidates
+            .drain()
+            .map(|key| {
+                let suggestion = &self.entries[key].suggestion;
+                let distance_meters = near
+                    .zip(suggestion.location.as_ref())
+                    .map(|(near, location)| near.distance_to(location));
+                let mut score = suggestion.kind.weight();
+                if fold_accents(&suggestion.text).starts_with(&leading) {
+                    score += LEADING_MATCH_BONUS;
+                }
+                if let Some(distance_meters) = distance_meters {
+                    score /= 1.0 + distance_meters / DISTANCE_HALF_SCORE_METERS;
+                }
+                (
+                    score,
+                    RankedSuggestion {
+                        suggestion: suggestion.clone(),
+                        distance_meters,
+                    },
+                )
+            })
+            .collect();
+        ranked.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| a.suggestion.text.cmp(&b.suggestion.text))
+                .then_with(|| a.suggestion.value.cmp(&b.suggestion.value))
+        });
+
+        // A category labeled alike in several locales is suggested once
+        let mut seen = HashSet::new();
+        ranked
+            .into_iter()
+            .map(|(_, ranked)| ranked)
+            .filter(|ranked| seen.insert((ranked.suggestion.kind, ranked.suggestion.value.clone())))
+            .take(limit)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::vo::description::Description;
+    use crate::domain::vo::localized_labels::LocalizedLabels;
+    use crate::domain::vo::name::Name;
+    use crate::domain::vo::phone::Phone;
+    use crate::domain::vo::rating::RatingSummary;
+    use crate::domain::vo::tags::Tags;
+    use crate::domain::vo::temporal::DateTime;
+    use crate::domain::vo::timezone::Timezone;
+
+    fn customer_service(name: &str, lat: f64, lon: f64, brand: Option<&str>) -> CustomerService {
+        CustomerService {
+            id: Id::new().unwrap(),
+            name: Name::new(name).unwrap(),
+            description: Description::new(String::new()).unwrap(),
+            location: GeoPoint::new(lat, lon).unwrap(),
+            address: None,
+            phone: Phone::new("+5511999999999".to_string()).unwrap(),
+            website: None,
+            photos: vec![],
+            tags: Tags::new(
+                brand
+                    .map(|brand| HashMap::from([("brand".to_string(), brand.to_string())]))
+                    .unwrap_or_default(),
+            )
+            .unwrap(),
+            categories: HashSet::new(),
+            opening_hours: None,
+            timezone: Timezone::default(),
+            state: None,
+            city: None,
+            rating: RatingSummary::default(),
+            deleted: false,
+            created_at: DateTime::new(),
+            updated_at: DateTime::new(),
+            deleted_at: None,
+        }
+    }
+
+    fn texts(suggestions: &[RankedSuggestion]) -> Vec<&str> {
+        suggestions
+            .iter()
+            .map(|ranked| ranked.suggestion.text.as_str())
+            .collect()
+    }
+
+    fn query(q: &str) -> SearchQuery {
+        SearchQuery::new(q).unwrap()
+    }
+
+    #[test]
+    fn matches_word_prefixes_without_accents() {
+        let mut index = AutocompleteIndex::default();
+        index.upsert_customer_service(&customer_service("Padaria São João", -23.55, -46.63, None));
+        index.upsert_customer_service(&customer_service("Pão de Açúcar", -23.56, -46.64, None));
+
+        assert_eq!(
+            texts(&index.suggest(&query("sao jo"), None, 10)),
+            ["Padaria São João"]
+        );
+        assert_eq!(
+            texts(&index.suggest(&query("acu"), None, 10)),
+            ["Pão de Açúcar"]
+        );
+        assert!(index.suggest(&query("mercado"), None, 10).is_empty());
+    }
+
+    #[test]
+    fn nearby_places_rank_first() {
+        let mut index = AutocompleteIndex::default();
+        index.upsert_customer_service(&customer_service("Farmácia Centro", -23.55, -46.63, None));
+        index.upsert_customer_service(&customer_service("Farmácia Rio", -22.90, -43.17, None));
+
+        let rio = GeoPoint::new(-22.91, -43.18).unwrap();
+        assert_eq!(
+            texts(&index.suggest(&query("farm"), Some(&rio), 10)),
+            ["Farmácia Rio", "Farmácia Centro"]
+        );
+    }
+
+    #[test]
+    fn mixes_categories_and_tags() {
+        let mut index = AutocompleteIndex::default();
+        let category = Category::new(
+            CustomerServiceCategory::new("pharmacy").unwrap(),
+            None,
+            LocalizedLabels::new(HashMap::from([
+                ("pt-BR".to_string(), "Farmácia".to_string()),
+                ("en".to_string(), "Pharmacy".to_string()),
+            ]))
+            .unwrap(),
+        )
+        .unwrap();
+        index.upsert_category(&category);
+        let drugstore = customer_service("Drogaria Sul", -23.55, -46.63, Some("Drogasil"));
+        index.upsert_customer_service(&drugstore);
+
+        let kinds: Vec<SuggestionKind> = index
+            .suggest(&query("dro"), None, 10)
+            .into_iter()
+            .map(|ranked| ranked.suggestion.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [SuggestionKind::CustomerService, SuggestionKind::Tag]
+        );
+        assert_eq!(
+            texts(&index.suggest(&query("farm"), None, 10)),
+            ["Farmácia"]
+        );
+        assert_eq!(
+            index.suggest(&query("wifi"), None, 10)[0].suggestion.value,
+            "wifi=yes"
+        );
+
+        index.remove_customer_service(&drugstore.id);
+        index.remove_category(&category.slug);
+        assert!(index.suggest(&query("dro"), None, 10).is_empty());
+        assert!(index.suggest(&query("pharm"), None, 10).is_empty());
+    }
+}
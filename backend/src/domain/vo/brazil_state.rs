@@ -0,0 +1,43 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// Federative units (UF) including the Federal District
+const BRAZIL_STATES: [&str; 27] = [
+    "AC", "AL", "AM", "AP", "BA", "CE", "DF", "ES", "GO", "MA", "MG", "MS", "MT", "PA", "PB", "PE",
+    "PI", "PR", "RJ", "RN", "RO", "RR", "RS", "SC", "SE", "SP", "TO",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BrazilState(String);
+
+impl BrazilState {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        let s = s.as_ref().trim().to_uppercase();
+        if !BRAZIL_STATES.contains(&s.as_str()) {
+            return Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-state", "Invalid Brazilian state (UF)")
+                    .with_args(HashMap::from([("state".to_string(), s.clone())])),
+            )));
+        }
+        Ok(BrazilState(s))
+    }
+
+    pub fn value(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_uf_codes_in_any_case() {
+        assert_eq!(BrazilState::new(" sp ").unwrap().value(), "SP");
+        assert!(BrazilState::new("DF").is_ok());
+        assert!(BrazilState::new("XX").is_err());
+        assert!(BrazilState::new("São Paulo").is_err());
+    }
+}
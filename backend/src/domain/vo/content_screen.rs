@@ -0,0 +1,115 @@
+use crate::domain::vo::autocomplete_index::fold_accents;
+use crate::domain::vo::moderation::FlagReason;
+use chrono::Duration;
+use regex::Regex;
+
+// Compared with whole accent folded words, so "Caralho" matches but "computador" does not
+const PROFANITY: &[&str] = &[
+    // pt-BR
+    "arrombado",
+    "babaca",
+    "buceta",
+    "cacete",
+    "caralho",
+    "corno",
+    "cuzao",
+    "desgracado",
+    "fdp",
+    "merda",
+    "otario",
+    "porra",
+    "pqp",
+    "puta",
+    "vagabunda",
+    "vsf",
+    // en
+    "asshole",
+    "bastard",
+    "bitch",
+    "bullshit",
+    "cunt",
+    "dick",
+    "fuck",
+    "fucking",
+    "motherfucker",
+    "shit",
+];
+
+// Accounts younger than this are watched for review bursts
+pub const NEW_ACCOUNT_AGE_DAYS: i64 = 7;
+pub const REVIEW_BURST_WINDOW_MINUTES: i64 = 60;
+// Reviews inside the window, the one being screened included, that make a burst
+pub const REVIEW_BURST_SIZE: i64 = 3;
+
+pub fn contains_profanity(text: &str) -> bool {
+    fold_accents(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| PROFANITY.contains(&word))
+}
+
+// Schemes, "www." and bare domains under common top level domains
+pub fn contains_link(text: &str) -> bool {
+    let re = Regex::new(
+        r"(?i)(https?://|www\.)\S|\b[a-z0-9-]+\.(com|net|org|info|biz|io|app|site|online|xyz|br)\b",
+    )
+    .unwrap();
+    re.is_match(text)
+}
+
+// Reasons the automatic rules find to queue the text, empty when it is clean
+pub fn screen_text(text: &str) -> Vec<FlagReason> {
+    let mut reasons = vec![];
+    if contains_profanity(text) {
+        reasons.push(FlagReason::Profanity);
+    }
+    if contains_link(text) {
+        reasons.push(FlagReason::Link);
+    }
+    reasons
+}
+
+pub fn is_review_burst(account_age: Duration, recent_reviews: i64) -> bool {
+    account_age < Duration::days(NEW_ACCOUNT_AGE_DAYS) && recent_reviews >= REVIEW_BURST_SIZE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profanity_matches_whole_words_regardless_of_case_and_accents() {
+        assert!(contains_profanity("Atendimento uma PORRA"));
+        assert!(contains_profanity("que desgraçado"));
+        assert!(contains_profanity("this is shit."));
+        assert!(!contains_profanity("Computador novo, ótimo atendimento"));
+        assert!(!contains_profanity("Shitake fresco"));
+    }
+
+    #[test]
+    fn links_are_found_with_or_without_a_scheme() {
+        assert!(contains_link("veja https://example.org/promo"));
+        assert!(contains_link("acesse www.promo.com"));
+        assert!(contains_link("compre em lojabarata.com.br"));
+        assert!(!contains_link("Abre às 8h. Fecha às 18h."));
+        assert!(!contains_link("Ótimo lugar, recomendo"));
+    }
+
+    #[test]
+    fn screen_text_collects_every_reason() {
+        assert_eq!(
+            screen_text("porra, compre em loja.com"),
+            vec![FlagReason::Profanity, FlagReason::Link]
+        );
+        assert!(screen_text("Ótimo atendimento").is_empty());
+    }
+
+    #[test]
+    fn only_new_accounts_can_burst() {
+        assert!(is_review_burst(Duration::hours(2), REVIEW_BURST_SIZE));
+        assert!(!is_review_burst(Duration::hours(2), REVIEW_BURST_SIZE - 1));
+        assert!(!is_review_burst(
+            Duration::days(NEW_ACCOUNT_AGE_DAYS),
+            REVIEW_BURST_SIZE + 5
+        ));
+    }
+}
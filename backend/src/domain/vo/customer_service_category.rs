@@ -1,2 +1,54 @@
-#[derive(Debug, Clone)]
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// Stable slug of a node of the category taxonomy, for example "orthodontics"
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CustomerServiceCategory(String);
+
+impl CustomerServiceCategory {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        let s = s.as_ref().trim().to_lowercase();
+        let valid = !s.is_empty()
+            && s.len() <= 50
+            && s.chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && !s.starts_with('-')
+            && !s.ends_with('-')
+            && !s.contains("--");
+        if !valid {
+            return Err(Arc::new(AppError::Validation(
+                ErrorData::new(
+                    "invalid-category",
+                    "category must be a slug of 1..=50 lowercase letters, digits and hyphens",
+                )
+                .with_args(HashMap::from([("category".to_string(), s.clone())])),
+            )));
+        }
+        Ok(CustomerServiceCategory(s))
+    }
+    pub fn value(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_slugs_only() {
+        assert_eq!(
+            CustomerServiceCategory::new(" Dentistry ").unwrap().value(),
+            "dentistry"
+        );
+        assert!(CustomerServiceCategory::new("pet-shop-24h").is_ok());
+        assert!(CustomerServiceCategory::new("").is_err());
+        assert!(CustomerServiceCategory::new("pet shop").is_err());
+        assert!(CustomerServiceCategory::new("saúde").is_err());
+        assert!(CustomerServiceCategory::new("-health").is_err());
+        assert!(CustomerServiceCategory::new("pet--shop").is_err());
+        assert!(CustomerServiceCategory::new("a".repeat(51)).is_err());
+    }
+}
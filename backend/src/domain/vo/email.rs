@@ -9,7 +9,12 @@ pub struct Email(String);
 impl Email {
     pub fn new(email: String) -> ResultApp<Self> {
         Self::validate(email.clone())?;
-        Ok(Self(email))
+        Ok(Self(Self::normalize(&email)))
+    }
+
+    // Emails are compared case-insensitively, so they are always stored lowercased
+    pub fn normalize(value: &str) -> String {
+        value.trim().to_lowercase()
     }
 
     pub fn validate(value: String) -> ResultApp<()> {
@@ -39,6 +44,12 @@ mod tests {
         assert_eq!(e.unwrap().value(), "user@example.com".to_string());
     }
 
+    #[test]
+    fn email_is_normalized() {
+        let e = Email::new("  User.Name@Example.COM ".to_string()).unwrap();
+        assert_eq!(e.value(), "user.name@example.com".to_string());
+    }
+
     #[test]
     fn invalid_email_is_rejected_with_validation_code() {
         let err = Email::new("bad@".to_string()).unwrap_err();
@@ -0,0 +1,401 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::address::Address;
+use crate::domain::vo::autocomplete_index::fold_accents;
+use crate::domain::vo::geohash::Geohash;
+use crate::domain::vo::geopoint::GeoPoint;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const HEADER: [&str; 7] = [
+    "kind",
+    "name",
+    "latitude",
+    "longitude",
+    "city",
+    "state",
+    "country",
+];
+
+// Cells of about 39 x 20 km, a cell and its neighbours cover any locality within the limit below
+const CELL_PRECISION: usize = 4;
+pub const MAX_LOCALITY_DISTANCE_METERS: f64 = 15_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GazetteerKind {
+    Street,
+    Neighbourhood,
+    City,
+}
+
+impl GazetteerKind {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim() {
+            "street" => Ok(GazetteerKind::Street),
+            "neighbourhood" => Ok(GazetteerKind::Neighbourhood),
+            "city" => Ok(GazetteerKind::City),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new(
+                    "invalid-gazetteer-kind",
+                    "kind must be street, neighbourhood or city",
+                )
+                .with_args(HashMap::from([("kind".to_string(), other.to_string())])),
+            ))),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            GazetteerKind::Street => "street",
+            GazetteerKind::Neighbourhood => "neighbourhood",
+            GazetteerKind::City => "city",
+        }
+        .to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GazetteerEntry {
+    pub kind: GazetteerKind,
+    pub name: String,
+    // Centroid of the street, neighbourhood or city
+    pub location: GeoPoint,
+    // Municipality the entry lies in, empty for cities themselves
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub country: String,
+}
+
+impl GazetteerEntry {
+    pub fn city_name(&self) -> &str {
+        match self.kind {
+            GazetteerKind::City => &self.name,
+            _ => self.city.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearestLocality {
+    pub entry: GazetteerEntry,
+    pub distance_meters: f64,
+}
+
+// Streets, neighbourhoods and cities loaded from a CSV with the columns in HEADER, looked up by
+// name for geocoding and by geohash cell for reverse geocoding
+#[derive(Debug, Clone, Default)]
+pub struct Gazetteer {
+    entries: Vec<GazetteerEntry>,
+    by_name: HashMap<String, Vec<usize>>,
+    // Neighbourhoods and cities only, streets are never returned as a locality
+    localities_by_cell: HashMap<Geohash, Vec<usize>>,
+}
+
+impl Gazetteer {
+    pub fn parse(csv: &str) -> ResultApp<Self> {
+        let mut gazetteer = Gazetteer::default();
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'));
+
+        match lines.next() {
+            Some((_, header)) if split_row(header).iter().map(String::as_str).eq(HEADER) => {}
+            _ => return invalid_row(1, format!("header must be {}", HEADER.join(","))),
+        }
+
+        for (index, line) in lines {
+            match parse_entry(&split_row(line)) {
+                Ok(entry) => gazetteer.insert(entry),
+                Err(reason) => return invalid_row(index + 1, reason),
+            }
+        }
+        Ok(gazetteer)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn insert(&mut self, entry: GazetteerEntry) {
+        let position = self.entries.len();
+        self.by_name
+            .entry(fold_accents(&entry.name))
+            .or_default()
+            .push(position);
+        if entry.kind != GazetteerKind::Street {
+            self.localities_by_cell
+                .entry(Geohash::encode(&entry.location, CELL_PRECISION))
+                .or_default()
+                .push(position);
+        }
+        self.entries.push(entry);
+    }
+
+    fn named(&self, name: &str) -> impl Iterator<Item = &GazetteerEntry> {
+        self.by_name
+            .get(&fold_accents(name.trim()))
+            .into_iter()
+            .flatten()
+            .map(|position| &self.entries[*position])
+    }
+
+    // The most precise entry the address resolves to, its street, then its neighbourhood, then
+    // its city
+    pub fn locate_address(&self, address: &Address) -> Option<&GazetteerEntry> {
+        let city = fold_accents(&address.city().value());
+        let country = address.country().value();
+        let state = address.state();
+        let in_place = |entry: &&GazetteerEntry| {
+            fold_accents(entry.city_name()) == city
+                && entry.country == country
+                && (state.is_none() || entry.state.is_none() || entry.state == state)
+        };
+
+        let street = self
+            .named(&address.street())
+            .filter(|entry| entry.kind == GazetteerKind::Street)
+            .find(in_place);
+        let neighbourhood = || {
+            address.neighbourhood().and_then(|neighbourhood| {
+                self.named(&neighbourhood)
+                    .filter(|entry| entry.kind == GazetteerKind::Neighbourhood)
+                    .find(in_place)
+            })
+        };
+        let city = || {
+            self.named(&address.city().value())
+                .filter(|entry| entry.kind == GazetteerKind::City)
+                .find(in_place)
+        };
+        street.or_else(neighbourhood).or_else(city)
+    }
+
+    // Free text such as "Pinheiros, São Paulo, SP", every part after the first narrows the
+    // match by city, state or country. Cities win over neighbourhoods and streets of the same name
+    pub fn locate(&self, text: &str) -> Option<&GazetteerEntry> {
+        let mut parts = text
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty());
+        let name = parts.next()?;
+        let context: Vec<String> = parts.map(fold_accents).collect();
+
+        self.named(name)
+            .filter(|entry| {
+                context.iter().all(|part| {
+                    *part == fold_accents(entry.city_name())
+                        || entry.state.as_deref().map(fold_accents).as_ref() == Some(part)
+                        || *part == fold_accents(&entry.country)
+                })
+            })
+            .min_by_key(|entry| match entry.kind {
+                GazetteerKind::City => 0,
+                GazetteerKind::Neighbourhood => 1,
+                GazetteerKind::Street => 2,
+            })
+    }
+
+    // Closest neighbourhood or city, None past MAX_LOCALITY_DISTANCE_METERS
+    pub fn nearest_locality(&self, point: &GeoPoint) -> Option<NearestLocality> {
+        let cell = Geohash::encode(point, CELL_PRECISION);
+        let mut cells = cell.neighbours();
+        cells.push(cell);
+
+        cells
+            .iter()
+            .filter_map(|cell| self.localities_by_cell.get(cell))
+            .flatten()
+            .map(|position| {
+                let entry = &self.entries[*position];
+                (entry, point.distance_to(&entry.location))
+            })
+            .filter(|(_, distance_meters)| *distance_meters <= MAX_LOCALITY_DISTANCE_METERS)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(entry, distance_meters)| NearestLocality {
+                entry: entry.clone(),
+                distance_meters,
+            })
+    }
+}
+
+fn invalid_row<T>(line: usize, reason: String) -> ResultApp<T> {
+    Err(Arc::new(AppError::Validation(
+        ErrorData::new("invalid-gazetteer", "Invalid gazetteer row").with_args(HashMap::from([
+            ("line".to_string(), line.to_string()),
+            ("reason".to_string(), reason),
+        ])),
+    )))
+}
+
+fn parse_entry(fields: &[String]) -> Result<GazetteerEntry, String> {
+    let [kind, name, latitude, longitude, city, state, country] = fields else {
+        return Err(format!("expected {} columns", HEADER.len()));
+    };
+    let kind = GazetteerKind::new(kind).map_err(|error| error.to_string())?;
+    let coordinate = |value: &str| {
+        value
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| format!("invalid coordinate {}", value))
+    };
+    let location =
+        GeoPoint::new(coordinate(latitude)?, coordinate(longitude)?).map_err(|e| e.to_string())?;
+    let optional = |value: &str| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+    let name = optional(name).ok_or("name is empty")?;
+    let country = optional(country)
+        .map(|country| country.to_uppercase())
+        .ok_or("country is empty")?;
+    let city = optional(city);
+    if kind != GazetteerKind::City && city.is_none() {
+        return Err("streets and neighbourhoods need a city".to_string());
+    }
+
+    Ok(GazetteerEntry {
+        kind,
+        name,
+        location,
+        city,
+        state: optional(state).map(|state| state.to_uppercase()),
+        country,
+    })
+}
+
+// Comma separated fields, double quotes around a field allow commas and "" inside it
+fn split_row(line: &str) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            other => field.push(other),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::error_code;
+    use crate::domain::vo::address::AddressData;
+
+    const CSV: &str = "\
+kind,name,latitude,longitude,city,state,country
+# São Paulo
+city,São Paulo,-23.5505,-46.6333,,SP,BR
+neighbourhood,Pinheiros,-23.5667,-46.7019,São Paulo,SP,BR
+neighbourhood,Bela Vista,-23.5614,-46.6500,São Paulo,SP,BR
+street,Avenida Paulista,-23.5614,-46.6559,São Paulo,SP,BR
+street,\"Rua Teodoro Sampaio, trecho norte\",-23.5580,-46.6750,São Paulo,SP,BR
+city,Pinheiros,-18.4142,-40.2175,,ES,BR
+city,Campinas,-22.9056,-47.0608,,SP,BR
+";
+
+    fn gazetteer() -> Gazetteer {
+        Gazetteer::parse(CSV).unwrap()
+    }
+
+    fn address(street: &str, neighbourhood: Option<&str>) -> Address {
+        Address::new(AddressData {
+            street: street.to_string(),
+            neighbourhood: neighbourhood.map(str::to_string),
+            city: "Sao Paulo".to_string(),
+            state: Some("SP".to_string()),
+            postal_code: "01310-100".to_string(),
+            country: "BR".to_string(),
+            ..AddressData::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn rows_are_parsed_with_quoted_fields() {
+        let gazetteer = gazetteer();
+        assert_eq!(gazetteer.len(), 7);
+        assert_eq!(
+            gazetteer.entries[4].name,
+            "Rua Teodoro Sampaio, trecho norte"
+        );
+        assert_eq!(gazetteer.entries[4].city.as_deref(), Some("São Paulo"));
+        assert_eq!(gazetteer.entries[0].city_name(), "São Paulo");
+    }
+
+    #[test]
+    fn malformed_rows_are_reported_with_their_line() {
+        assert_eq!(
+            error_code(Gazetteer::parse("kind,name\ncity,X")),
+            "invalid-gazetteer"
+        );
+        let csv = format!("{}street,Rua Sem Cidade,-23.5,-46.6,,SP,BR\n", CSV);
+        assert!(Gazetteer::parse(&csv).is_err());
+        let csv = format!("{}city,Longe,-123.5,-46.6,,SP,BR\n", CSV);
+        assert!(Gazetteer::parse(&csv).is_err());
+    }
+
+    #[test]
+    fn address_resolves_to_the_most_precise_entry() {
+        let gazetteer = gazetteer();
+        assert_eq!(
+            gazetteer
+                .locate_address(&address("Avenida Paulista", Some("Bela Vista")))
+                .unwrap()
+                .name,
+            "Avenida Paulista"
+        );
+        assert_eq!(
+            gazetteer
+                .locate_address(&address("Rua Desconhecida", Some("Pinheiros")))
+                .unwrap()
+                .name,
+            "Pinheiros"
+        );
+        let city = gazetteer
+            .locate_address(&address("Rua Desconhecida", None))
+            .unwrap();
+        assert_eq!(city.kind, GazetteerKind::City);
+        assert_eq!(city.name, "São Paulo");
+    }
+
+    #[test]
+    fn free_text_prefers_cities_unless_narrowed() {
+        let gazetteer = gazetteer();
+        let city = gazetteer.locate("pinheiros").unwrap();
+        assert_eq!(city.kind, GazetteerKind::City);
+        assert_eq!(city.state.as_deref(), Some("ES"));
+
+        let neighbourhood = gazetteer.locate("Pinheiros, sao paulo, sp").unwrap();
+        assert_eq!(neighbourhood.kind, GazetteerKind::Neighbourhood);
+        assert!(gazetteer.locate("Pinheiros, RJ").is_none());
+        assert!(gazetteer.locate(" , ").is_none());
+    }
+
+    #[test]
+    fn nearest_locality_skips_streets_and_far_points() {
+        let gazetteer = gazetteer();
+        // A few hundred meters from the Avenida Paulista centroid
+        let nearest = gazetteer
+            .nearest_locality(&GeoPoint::new(-23.5630, -46.6540).unwrap())
+            .unwrap();
+        assert_eq!(nearest.entry.name, "Bela Vista");
+        assert!(nearest.distance_meters < 500.0);
+
+        assert!(
+            gazetteer
+                .nearest_locality(&GeoPoint::new(-10.0, -55.0).unwrap())
+                .is_none()
+        );
+    }
+}
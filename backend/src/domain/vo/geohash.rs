@@ -0,0 +1,226 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::geopoint::{BoundingBox, GeoPoint};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+pub const MAX_PRECISION: usize = 12;
+
+// Hierarchical cell id, every prefix of a geohash is the parent cell containing it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Geohash(String);
+
+impl Geohash {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        let s = s.as_ref().trim().to_lowercase();
+        if s.is_empty() || s.len() > MAX_PRECISION || !s.bytes().all(|b| BASE32.contains(&b)) {
+            return Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-geohash", "Invalid geohash")
+                    .with_args(HashMap::from([("geohash".to_string(), s.clone())])),
+            )));
+        }
+        Ok(Geohash(s))
+    }
+
+    pub fn encode(point: &GeoPoint, precision: usize) -> Self {
+        let precision = precision.clamp(1, MAX_PRECISION);
+        let (mut min_lat, mut max_lat) = (-90.0, 90.0);
+        let (mut min_lon, mut max_lon) = (-180.0, 180.0);
+        let mut hash = String::with_capacity(precision);
+        let mut bits = 0usize;
+        let mut bit_count = 0;
+        let mut even_bit = true;
+
+        while hash.len() < precision {
+            if even_bit {
+                let mid = (min_lon + max_lon) / 2.0;
+                if point.lon >= mid {
+                    bits = bits * 2 + 1;
+                    min_lon = mid;
+                } else {
+                    bits *= 2;
+                    max_lon = mid;
+                }
+            } else {
+                let mid = (min_lat + max_lat) / 2.0;
+                if point.lat >= mid {
+                    bits = bits * 2 + 1;
+                    min_lat = mid;
+                } else {
+                    bits *= 2;
+                    max_lat = mid;
+                }
+            }
+            even_bit = !even_bit;
+            bit_count += 1;
+            if bit_count == 5 {
+                hash.push(BASE32[bits] as char);
+                bits = 0;
+                bit_count = 0;
+            }
+        }
+
+        Geohash(hash)
+    }
+
+    // Coarsest precision whose cells are at most a quarter of a 256px map tile wide at `zoom`
+    pub fn precision_for_zoom(zoom: u8) -> usize {
+        let longitude_bits = zoom as usize + 2;
+        (1..=MAX_PRECISION)
+            .find(|precision| (5 * precision).div_ceil(2) >= longitude_bits)
+            .unwrap_or(MAX_PRECISION)
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        let (mut min_lat, mut max_lat) = (-90.0, 90.0);
+        let (mut min_lon, mut max_lon) = (-180.0, 180.0);
+        let mut even_bit = true;
+
+        for byte in self.0.bytes() {
+            let index = BASE32.iter().position(|b| *b == byte).unwrap_or(0);
+            for shift in (0..5).rev() {
+                let bit = (index >> shift) & 1;
+                if even_bit {
+                    let mid = (min_lon + max_lon) / 2.0;
+                    if bit == 1 {
+                        min_lon = mid;
+                    } else {
+                        max_lon = mid;
+                    }
+                } else {
+                    let mid = (min_lat + max_lat) / 2.0;
+                    if bit == 1 {
+                        min_lat = mid;
+                    } else {
+                        max_lat = mid;
+                    }
+                }
+                even_bit = !even_bit;
+            }
+        }
+
+        BoundingBox {
+            min_lat,
+            min_lon,
+            max_lat,
+            max_lon,
+        }
+    }
+
+    pub fn center(&self) -> GeoPoint {
+        let bbox = self.bounding_box();
+        GeoPoint {
+            lat: (bbox.min_lat + bbox.max_lat) / 2.0,
+            lon: (bbox.min_lon + bbox.max_lon) / 2.0,
+        }
+    }
+
+    pub fn precision(&self) -> usize {
+        self.0.len()
+    }
+
+    // The up to 8 cells of the same precision around this one, none past the poles
+    pub fn neighbours(&self) -> Vec<Geohash> {
+        let bbox = self.bounding_box();
+        let center = self.center();
+        let lat_step = bbox.max_lat - bbox.min_lat;
+        let lon_step = bbox.max_lon - bbox.min_lon;
+
+        let mut neighbours = Vec::with_capacity(8);
+        for d_lat in [-1.0, 0.0, 1.0] {
+            for d_lon in [-1.0, 0.0, 1.0] {
+                if d_lat == 0.0 && d_lon == 0.0 {
+                    continue;
+                }
+                let lat = center.lat + d_lat * lat_step;
+                if !(-90.0..=90.0).contains(&lat) {
+                    continue;
+                }
+                let mut lon = center.lon + d_lon * lon_step;
+                if lon > 180.0 {
+                    lon -= 360.0;
+                } else if lon < -180.0 {
+                    lon += 360.0;
+                }
+                let neighbour = Geohash::encode(&GeoPoint { lat, lon }, self.precision());
+                if neighbour != *self && !neighbours.contains(&neighbour) {
+                    neighbours.push(neighbour);
+                }
+            }
+        }
+        neighbours
+    }
+
+    pub fn value(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl Display for Geohash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_known_points() {
+        let point = GeoPoint::new(57.64911, 10.40744).unwrap();
+        assert_eq!(Geohash::encode(&point, 11).value(), "u4pruydqqvj");
+
+        let sao_paulo = GeoPoint::new(-23.5505, -46.6333).unwrap();
+        assert_eq!(Geohash::encode(&sao_paulo, 6).value(), "6gyf4b");
+    }
+
+    #[test]
+    fn decoded_box_contains_the_encoded_point() {
+        let point = GeoPoint::new(-23.5505, -46.6333).unwrap();
+        let bbox = Geohash::encode(&point, 8).bounding_box();
+        assert!(bbox.min_lat <= point.lat && point.lat <= bbox.max_lat);
+        assert!(bbox.min_lon <= point.lon && point.lon <= bbox.max_lon);
+    }
+
+    #[test]
+    fn coarser_cell_is_a_prefix() {
+        let point = GeoPoint::new(-23.5505, -46.6333).unwrap();
+        let fine = Geohash::encode(&point, 8).value();
+        assert!(fine.starts_with(&Geohash::encode(&point, 4).value()));
+    }
+
+    #[test]
+    fn has_eight_neighbours_away_from_the_poles() {
+        let hash = Geohash::new("6gyf4b").unwrap();
+        let neighbours = hash.neighbours();
+        assert_eq!(neighbours.len(), 8);
+        assert!(neighbours.iter().all(|n| n.precision() == 6 && *n != hash));
+        assert!(neighbours.contains(&Geohash::new("6gyf4c").unwrap()));
+    }
+
+    #[test]
+    fn neighbours_wrap_around_the_antimeridian() {
+        let hash = Geohash::encode(&GeoPoint::new(0.0, 179.99).unwrap(), 4);
+        let neighbours = hash.neighbours();
+        assert!(neighbours.iter().any(|n| n.center().lon < 0.0));
+    }
+
+    #[test]
+    fn precision_grows_with_zoom() {
+        assert_eq!(Geohash::precision_for_zoom(0), 1);
+        assert_eq!(Geohash::precision_for_zoom(4), 3);
+        assert_eq!(Geohash::precision_for_zoom(10), 5);
+        assert_eq!(Geohash::precision_for_zoom(16), 7);
+        assert_eq!(Geohash::precision_for_zoom(40), MAX_PRECISION);
+    }
+
+    #[test]
+    fn invalid_characters_are_rejected() {
+        assert!(Geohash::new("6gyfa").is_err());
+        assert!(Geohash::new("").is_err());
+    }
+}
@@ -3,6 +3,10 @@ use crate::common::result::ResultApp;
 use std::sync::Arc;
 
 type Degrees = f64;
+type Meters = f64;
+
+// Mean Earth radius (IUGG), good enough for great-circle distances on a sphere
+pub const EARTH_RADIUS_METERS: Meters = 6_371_008.8;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GeoPoint {
@@ -10,6 +14,69 @@ pub struct GeoPoint {
     pub lon: Degrees, // degrees
 }
 
+// Latitude/longitude rectangle, `min_lon > max_lon` when it crosses the antimeridian
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_lat: Degrees,
+    pub min_lon: Degrees,
+    pub max_lat: Degrees,
+    pub max_lon: Degrees,
+}
+
+impl BoundingBox {
+    pub fn new(
+        min_lat: Degrees,
+        min_lon: Degrees,
+        max_lat: Degrees,
+        max_lon: Degrees,
+    ) -> ResultApp<Self> {
+        GeoPoint::new(min_lat, min_lon)?;
+        GeoPoint::new(max_lat, max_lon)?;
+        if min_lat > max_lat {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-bounding-box",
+                "Invalid bounding box",
+            ))));
+        }
+        Ok(BoundingBox {
+            min_lat,
+            min_lon,
+            max_lat,
+            max_lon,
+        })
+    }
+
+    // Parses the `min_lon,min_lat,max_lon,max_lat` form used by map clients
+    pub fn parse(value: &str) -> ResultApp<Self> {
+        let parts: Vec<Option<Degrees>> = value
+            .split(',')
+            .map(|part| part.trim().parse().ok())
+            .collect();
+        match parts.as_slice() {
+            [Some(min_lon), Some(min_lat), Some(max_lon), Some(max_lat)] => {
+                Self::new(*min_lat, *min_lon, *max_lat, *max_lon)
+            }
+            _ => Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-bounding-box",
+                "Invalid bounding box",
+            )))),
+        }
+    }
+
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.min_lon > self.max_lon
+    }
+
+    // Splits into at most two longitude ranges that never wrap
+    pub fn longitude_ranges(&self) -> [(Degrees, Degrees); 2] {
+        if self.crosses_antimeridian() {
+            [(self.min_lon, 180.0), (-180.0, self.max_lon)]
+        } else {
+            [(self.min_lon, self.max_lon), (self.min_lon, self.max_lon)]
+        }
+    }
+}
+
 impl GeoPoint {
     pub fn new(lat: Degrees, lon: Degrees) -> ResultApp<Self> {
         if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
@@ -24,4 +91,115 @@ impl GeoPoint {
     pub fn value(&self) -> (Degrees, Degrees) {
         (self.lat, self.lon)
     }
+
+    // Great-circle distance using the haversine formula
+    pub fn distance_to(&self, other: &GeoPoint) -> Meters {
+        let lat1 = self.lat.to_radians();
+        let lat2 = other.lat.to_radians();
+        let d_lat = (other.lat - self.lat).to_radians();
+        let d_lon = (other.lon - self.lon).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().min(1.0).asin()
+    }
+
+    // Smallest lat/lon rectangle containing every point within `radius` of this one
+    pub fn bounding_box(&self, radius: Meters) -> BoundingBox {
+        let angular_radius = radius / EARTH_RADIUS_METERS;
+        let lat = self.lat.to_radians();
+        let lon = self.lon.to_radians();
+
+        let mut min_lat = lat - angular_radius;
+        let mut max_lat = lat + angular_radius;
+        let (min_lon, max_lon);
+
+        let half_pi = std::f64::consts::FRAC_PI_2;
+        if min_lat > -half_pi && max_lat < half_pi {
+            let delta_lon = (angular_radius.sin() / lat.cos()).asin();
+            let mut lo = lon - delta_lon;
+            let mut hi = lon + delta_lon;
+            if lo < -std::f64::consts::PI {
+                lo += 2.0 * std::f64::consts::PI;
+            }
+            if hi > std::f64::consts::PI {
+                hi -= 2.0 * std::f64::consts::PI;
+            }
+            min_lon = lo;
+            max_lon = hi;
+        } else {
+            // A pole is inside the circle, every longitude qualifies
+            min_lat = min_lat.max(-half_pi);
+            max_lat = max_lat.min(half_pi);
+            min_lon = -std::f64::consts::PI;
+            max_lon = std::f64::consts::PI;
+        }
+
+        BoundingBox {
+            min_lat: min_lat.to_degrees(),
+            min_lon: min_lon.to_degrees(),
+            max_lat: max_lat.to_degrees(),
+            max_lon: max_lon.to_degrees(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_between_sao_paulo_and_rio_is_about_360_km() {
+        let sao_paulo = GeoPoint::new(-23.5505, -46.6333).unwrap();
+        let rio = GeoPoint::new(-22.9068, -43.1729).unwrap();
+        let d = sao_paulo.distance_to(&rio);
+        assert!((355_000.0..365_000.0).contains(&d), "got {}", d);
+        assert_eq!(sao_paulo.distance_to(&sao_paulo), 0.0);
+    }
+
+    #[test]
+    fn bounding_box_contains_points_at_the_radius() {
+        let center = GeoPoint::new(-23.5505, -46.6333).unwrap();
+        let bbox = center.bounding_box(1_000.0);
+        assert!(bbox.min_lat < center.lat && center.lat < bbox.max_lat);
+        assert!(bbox.min_lon < center.lon && center.lon < bbox.max_lon);
+
+        let north = GeoPoint::new(bbox.max_lat, center.lon).unwrap();
+        assert!((center.distance_to(&north) - 1_000.0).abs() < 1.0);
+        assert!(!bbox.crosses_antimeridian());
+    }
+
+    #[test]
+    fn bounding_box_wraps_around_the_antimeridian() {
+        let fiji = GeoPoint::new(-17.7, 179.99).unwrap();
+        let bbox = fiji.bounding_box(10_000.0);
+        assert!(bbox.crosses_antimeridian());
+        assert_eq!(bbox.longitude_ranges()[0].1, 180.0);
+        assert_eq!(bbox.longitude_ranges()[1].0, -180.0);
+    }
+
+    #[test]
+    fn bounding_box_near_a_pole_spans_all_longitudes() {
+        let north_pole = GeoPoint::new(89.99, 0.0).unwrap();
+        let bbox = north_pole.bounding_box(5_000.0);
+        assert_eq!(bbox.max_lat, 90.0);
+        assert_eq!(bbox.min_lon, -180.0);
+        assert_eq!(bbox.max_lon, 180.0);
+    }
+
+    #[test]
+    fn bounding_box_is_parsed_in_lon_lat_order() {
+        let bbox = BoundingBox::parse("-46.8,-23.7,-46.4,-23.4").unwrap();
+        assert_eq!(bbox.min_lat, -23.7);
+        assert_eq!(bbox.min_lon, -46.8);
+        assert_eq!(bbox.max_lat, -23.4);
+        assert_eq!(bbox.max_lon, -46.4);
+
+        assert!(
+            BoundingBox::parse("170,-20,-170,-10")
+                .unwrap()
+                .crosses_antimeridian()
+        );
+        assert!(BoundingBox::parse("-46.8,-23.4,-46.4,-23.7").is_err());
+        assert!(BoundingBox::parse("-46.8,-23.7,-46.4").is_err());
+    }
 }
@@ -0,0 +1,181 @@
+use crate::domain::entity::holiday::Holiday;
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::opening_hours::PublicHolidays;
+use chrono::{Duration, NaiveDate};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+// Dia Nacional de Zumbi e da Consciência Negra became a national holiday by Lei 14.759/2023
+const BLACK_CONSCIOUSNESS_DAY_SINCE: i32 = 2024;
+
+// Gregorian Easter Sunday (anonymous Meeus/Jones/Butcher algorithm)
+pub fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
+
+// Fixed national holidays plus the ones that move with Easter
+pub fn brazil_national_holidays(year: i32) -> Vec<(NaiveDate, &'static str)> {
+    let fixed = |month: u32, day: u32| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+    let easter = easter_sunday(year);
+
+    let mut holidays = vec![
+        (fixed(1, 1), "Confraternização Universal"),
+        (easter - Duration::days(48), "Carnaval"),
+        (easter - Duration::days(47), "Carnaval"),
+        (easter - Duration::days(2), "Sexta-feira Santa"),
+        (fixed(4, 21), "Tiradentes"),
+        (fixed(5, 1), "Dia do Trabalho"),
+        (easter + Duration::days(60), "Corpus Christi"),
+        (fixed(9, 7), "Independência do Brasil"),
+        (fixed(10, 12), "Nossa Senhora Aparecida"),
+        (fixed(11, 2), "Finados"),
+        (fixed(11, 15), "Proclamação da República"),
+        (fixed(12, 25), "Natal"),
+    ];
+    if year >= BLACK_CONSCIOUSNESS_DAY_SINCE {
+        holidays.push((
+            fixed(11, 20),
+            "Dia Nacional de Zumbi e da Consciência Negra",
+        ));
+    }
+    holidays.sort();
+    holidays
+}
+
+// National holidays of a range of years plus the regional ones loaded from storage
+#[derive(Debug, Clone, Default)]
+pub struct HolidayCalendar {
+    national: HashSet<NaiveDate>,
+    regional: Vec<Holiday>,
+}
+
+impl HolidayCalendar {
+    pub fn brazil(years: RangeInclusive<i32>, regional: Vec<Holiday>) -> Self {
+        HolidayCalendar {
+            national: years
+                .flat_map(brazil_national_holidays)
+                .map(|(date, _)| date)
+                .collect(),
+            regional,
+        }
+    }
+
+    // Holidays observed by a place in the given state and city
+    pub fn for_place<'a>(
+        &'a self,
+        state: Option<&'a BrazilState>,
+        city: Option<&'a Name>,
+    ) -> PlaceHolidays<'a> {
+        PlaceHolidays {
+            calendar: self,
+            state,
+            city,
+        }
+    }
+}
+
+pub struct PlaceHolidays<'a> {
+    calendar: &'a HolidayCalendar,
+    state: Option<&'a BrazilState>,
+    city: Option<&'a Name>,
+}
+
+impl PublicHolidays for PlaceHolidays<'_> {
+    fn is_public_holiday(&self, date: NaiveDate) -> bool {
+        self.calendar.national.contains(&date)
+            || self
+                .calendar
+                .regional
+                .iter()
+                .any(|holiday| holiday.applies_to(self.state, self.city) && holiday.occurs_on(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn computes_easter_sunday() {
+        assert_eq!(easter_sunday(2024), date(2024, 3, 31));
+        assert_eq!(easter_sunday(2025), date(2025, 4, 20));
+        assert_eq!(easter_sunday(2026), date(2026, 4, 5));
+        assert_eq!(easter_sunday(2038), date(2038, 4, 25));
+    }
+
+    #[test]
+    fn moveable_holidays_follow_easter() {
+        let holidays: Vec<NaiveDate> = brazil_national_holidays(2026)
+            .into_iter()
+            .map(|(d, _)| d)
+            .collect();
+        assert!(holidays.contains(&date(2026, 2, 16)));
+        assert!(holidays.contains(&date(2026, 2, 17)));
+        assert!(holidays.contains(&date(2026, 4, 3)));
+        assert!(holidays.contains(&date(2026, 6, 4)));
+        assert!(holidays.contains(&date(2026, 11, 20)));
+        assert!(
+            !brazil_national_holidays(2023)
+                .iter()
+                .any(|(d, _)| *d == date(2023, 11, 20))
+        );
+    }
+
+    #[test]
+    fn regional_holidays_only_apply_to_their_region() {
+        let sp = BrazilState::new("SP").unwrap();
+        let rj = BrazilState::new("RJ").unwrap();
+        let sao_paulo = Name::new("São Paulo").unwrap();
+        let revolution = Holiday::new(
+            Name::new("Revolução Constitucionalista").unwrap(),
+            date(1932, 7, 9),
+            true,
+            Some(sp.clone()),
+            None,
+        )
+        .unwrap();
+        let anniversary = Holiday::new(
+            Name::new("Aniversário de São Paulo").unwrap(),
+            date(1554, 1, 25),
+            true,
+            Some(sp.clone()),
+            Some(sao_paulo.clone()),
+        )
+        .unwrap();
+        let calendar = HolidayCalendar::brazil(2026..=2026, vec![revolution, anniversary]);
+
+        let in_sao_paulo = calendar.for_place(Some(&sp), Some(&sao_paulo));
+        assert!(in_sao_paulo.is_public_holiday(date(2026, 7, 9)));
+        assert!(in_sao_paulo.is_public_holiday(date(2026, 1, 25)));
+        assert!(in_sao_paulo.is_public_holiday(date(2026, 12, 25)));
+
+        let in_campinas = calendar.for_place(Some(&sp), None);
+        assert!(in_campinas.is_public_holiday(date(2026, 7, 9)));
+        assert!(!in_campinas.is_public_holiday(date(2026, 1, 25)));
+
+        assert!(
+            !calendar
+                .for_place(Some(&rj), None)
+                .is_public_holiday(date(2026, 7, 9))
+        );
+    }
+}
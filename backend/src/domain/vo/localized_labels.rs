@@ -0,0 +1,137 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+pub const DEFAULT_LOCALE: &str = "pt-BR";
+
+// Display label per locale, keyed by a language tag such as "pt-BR" or "en"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedLabels(BTreeMap<String, String>);
+
+fn invalid(code: &str, message: &str, key: &str, value: &str) -> ResultApp<LocalizedLabels> {
+    Err(Arc::new(AppError::Validation(
+        ErrorData::new(code.to_string(), message.to_string())
+            .with_args(HashMap::from([(key.to_string(), value.to_string())])),
+    )))
+}
+
+// Language in lowercase and optional region in uppercase, "pt-br" becomes "pt-BR"
+fn normalize_locale(locale: &str) -> Option<String> {
+    let mut parts = locale.trim().split(['-', '_']);
+    let language = parts.next()?.to_lowercase();
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+    match (parts.next(), parts.next()) {
+        (None, _) => Some(language),
+        (Some(region), None)
+            if region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()) =>
+        {
+            Some(format!("{}-{}", language, region.to_uppercase()))
+        }
+        _ => None,
+    }
+}
+
+fn language_of(locale: &str) -> &str {
+    locale.split('-').next().unwrap_or(locale)
+}
+
+impl LocalizedLabels {
+    pub fn new(labels: HashMap<String, String>) -> ResultApp<Self> {
+        if labels.is_empty() {
+            return invalid(
+                "invalid-labels",
+                "at least one label is required",
+                "locale",
+                DEFAULT_LOCALE,
+            );
+        }
+
+        let mut normalized = BTreeMap::new();
+        for (locale, label) in labels {
+            let Some(normalized_locale) = normalize_locale(&locale) else {
+                return invalid("invalid-locale", "invalid language tag", "locale", &locale);
+            };
+            let label = label.trim();
+            if label.is_empty() || label.chars().count() > 120 {
+                return invalid(
+                    "invalid-label",
+                    "label must be 1..=120 characters",
+                    "locale",
+                    &locale,
+                );
+            }
+            normalized.insert(normalized_locale, label.to_string());
+        }
+        Ok(LocalizedLabels(normalized))
+    }
+
+    // Falls back to another region of the same language, then the default locale, then any
+    pub fn label(&self, locale: &str) -> &str {
+        let requested = normalize_locale(locale).unwrap_or_else(|| DEFAULT_LOCALE.to_string());
+        self.0
+            .get(&requested)
+            .or_else(|| {
+                self.0
+                    .iter()
+                    .find(|(locale, _)| language_of(locale) == language_of(&requested))
+                    .map(|(_, label)| label)
+            })
+            .or_else(|| self.0.get(DEFAULT_LOCALE))
+            .or_else(|| self.0.values().next())
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    pub fn value(&self) -> BTreeMap<String, String> {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(entries: &[(&str, &str)]) -> ResultApp<LocalizedLabels> {
+        LocalizedLabels::new(
+            entries
+                .iter()
+                .map(|(locale, label)| (locale.to_string(), label.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn normalizes_language_tags() {
+        let labels = labels(&[("pt_br", " Ortodontia "), ("EN", "Orthodontics")]).unwrap();
+        assert_eq!(
+            labels.value().into_iter().collect::<Vec<_>>(),
+            vec![
+                ("en".to_string(), "Orthodontics".to_string()),
+                ("pt-BR".to_string(), "Ortodontia".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_labels() {
+        assert!(labels(&[]).is_err());
+        assert!(labels(&[("portuguese", "Saúde")]).is_err());
+        assert!(labels(&[("pt-BR-x", "Saúde")]).is_err());
+        assert!(labels(&[("pt-BR", " ")]).is_err());
+    }
+
+    #[test]
+    fn falls_back_to_language_then_default_locale() {
+        let labels = labels(&[("pt-BR", "Saúde"), ("en-US", "Health")]).unwrap();
+        assert_eq!(labels.label("en-US"), "Health");
+        assert_eq!(labels.label("en-GB"), "Health");
+        assert_eq!(labels.label("es"), "Saúde");
+        assert_eq!(labels.label("not a locale"), "Saúde");
+
+        let english_only = self::labels(&[("en", "Health")]).unwrap();
+        assert_eq!(english_only.label("pt-BR"), "Health");
+    }
+}
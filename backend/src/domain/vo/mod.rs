@@ -1,13 +1,30 @@
+pub mod address;
+pub mod autocomplete_index;
+pub mod brazil_state;
+pub mod content_screen;
 pub mod customer_service_category;
 pub mod description;
 pub mod email;
+pub mod gazetteer;
+pub mod geohash;
 pub mod geopoint;
+pub mod holiday_calendar;
 pub mod id;
+pub mod localized_labels;
+pub mod moderation;
 pub mod name;
+pub mod opening_hours;
 pub mod password;
+pub mod permission;
 pub mod phone;
+pub mod phone_metadata;
 pub mod photo;
+pub mod rating;
+pub mod role;
+pub mod search_query;
 pub mod tags;
 pub mod tax_id;
 pub mod temporal;
+pub mod tile;
+pub mod timezone;
 pub mod url;
@@ -0,0 +1,116 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::description::Description;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::name::Name;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// Content a flag points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModerationSubject {
+    Review(Id),
+    // The current name and description of the place, as left by its last edit
+    CustomerService(Id),
+}
+
+impl ModerationSubject {
+    pub fn new<S: AsRef<str>>(kind: S, id: Id) -> ResultApp<Self> {
+        match kind.as_ref().trim() {
+            "review" => Ok(ModerationSubject::Review(id)),
+            "customer-service" => Ok(ModerationSubject::CustomerService(id)),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-moderation-subject", "Invalid moderation subject")
+                    .with_args(HashMap::from([("subject".to_string(), other.to_string())])),
+            ))),
+        }
+    }
+
+    pub fn kind(&self) -> String {
+        match self {
+            ModerationSubject::Review(_) => "review",
+            ModerationSubject::CustomerService(_) => "customer-service",
+        }
+        .to_string()
+    }
+
+    pub fn id(&self) -> Id {
+        match self {
+            ModerationSubject::Review(id) | ModerationSubject::CustomerService(id) => *id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlagReason {
+    // Sent by a user through the report endpoint
+    UserReport,
+    Profanity,
+    Link,
+    // Many reviews from an account created moments ago
+    ReviewBurst,
+}
+
+impl FlagReason {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim() {
+            "user-report" => Ok(FlagReason::UserReport),
+            "profanity" => Ok(FlagReason::Profanity),
+            "link" => Ok(FlagReason::Link),
+            "review-burst" => Ok(FlagReason::ReviewBurst),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-flag-reason", "Invalid flag reason")
+                    .with_args(HashMap::from([("reason".to_string(), other.to_string())])),
+            ))),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            FlagReason::UserReport => "user-report",
+            FlagReason::Profanity => "profanity",
+            FlagReason::Link => "link",
+            FlagReason::ReviewBurst => "review-burst",
+        }
+        .to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModerationStatus {
+    Pending,
+    Approved,
+    // The subject is hidden from public listings, or a flagged place edit is reverted, the row is
+    // kept for audit
+    Rejected,
+}
+
+impl ModerationStatus {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim() {
+            "pending" => Ok(ModerationStatus::Pending),
+            "approved" => Ok(ModerationStatus::Approved),
+            "rejected" => Ok(ModerationStatus::Rejected),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-moderation-status", "Invalid moderation status")
+                    .with_args(HashMap::from([("status".to_string(), other.to_string())])),
+            ))),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            ModerationStatus::Pending => "pending",
+            ModerationStatus::Approved => "approved",
+            ModerationStatus::Rejected => "rejected",
+        }
+        .to_string()
+    }
+}
+
+// Name and description of a place before the edit a flag was raised on
+#[derive(Debug, Clone)]
+pub struct PreviousPlaceText {
+    pub name: Name,
+    pub description: Description,
+}
@@ -0,0 +1,364 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+// How far ahead next_open / closes_at are searched
+const LOOKAHEAD_DAYS: i64 = 8;
+
+pub trait PublicHolidays {
+    fn is_public_holiday(&self, date: NaiveDate) -> bool;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimeSpan {
+    start: u32, // minutes since local midnight
+    end: u32,   // may pass 24:00 for spans that run overnight
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    weekdays: [bool; 7],
+    public_holiday: bool,
+    // Empty means closed
+    spans: Vec<TimeSpan>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningStatus {
+    pub open: bool,
+    pub next_open: Option<DateTime<Tz>>,
+    pub closes_at: Option<DateTime<Tz>>,
+}
+
+// Subset of the OpenStreetMap opening_hours syntax: "24/7", weekday lists and ranges,
+// comma separated time spans, overnight spans and "PH" rules, later rules override
+// earlier ones for the days they select. E.g. "Mo-Fr 08:00-18:00; Sa 09:00-13:00; PH off"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningHours {
+    source: String,
+    rules: Vec<Rule>,
+}
+
+fn invalid(source: &str) -> ResultApp<OpeningHours> {
+    Err(Arc::new(AppError::Validation(
+        ErrorData::new("invalid-opening-hours", "Invalid opening hours").with_args(HashMap::from(
+            [("opening_hours".to_string(), source.to_string())],
+        )),
+    )))
+}
+
+fn parse_weekday(s: &str) -> Option<usize> {
+    WEEKDAYS.iter().position(|day| *day == s)
+}
+
+fn parse_selector(selector: &str) -> Option<([bool; 7], bool)> {
+    let mut weekdays = [false; 7];
+    let mut public_holiday = false;
+
+    for item in selector.split(',').map(str::trim) {
+        if item == "PH" {
+            public_holiday = true;
+        } else if let Some((from, to)) = item.split_once('-') {
+            let (from, to) = (parse_weekday(from)?, parse_weekday(to)?);
+            // Ranges like Fr-Mo wrap around the end of the week
+            let mut day = from;
+            loop {
+                weekdays[day] = true;
+                if day == to {
+                    break;
+                }
+                day = (day + 1) % 7;
+            }
+        } else {
+            weekdays[parse_weekday(item)?] = true;
+        }
+    }
+    Some((weekdays, public_holiday))
+}
+
+fn parse_time(s: &str) -> Option<u32> {
+    let (hours, minutes) = s.split_once(':')?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+    let (hours, minutes): (u32, u32) = (hours.parse().ok()?, minutes.parse().ok()?);
+    if hours > 48 || minutes > 59 {
+        return None;
+    }
+    Some(hours * 60 + minutes)
+}
+
+fn parse_span(s: &str) -> Option<TimeSpan> {
+    let (start, end) = s.split_once('-')?;
+    let start = parse_time(start)?;
+    let mut end = parse_time(end)?;
+    if start >= MINUTES_PER_DAY {
+        return None;
+    }
+    // 22:00-02:00 closes the next day
+    if end <= start {
+        end += MINUTES_PER_DAY;
+    }
+    if end - start > MINUTES_PER_DAY {
+        return None;
+    }
+    Some(TimeSpan { start, end })
+}
+
+fn parse_rule(rule: &str) -> Option<Rule> {
+    if rule == "24/7" {
+        return Some(Rule {
+            weekdays: [true; 7],
+            public_holiday: false,
+            spans: vec![TimeSpan {
+                start: 0,
+                end: MINUTES_PER_DAY,
+            }],
+        });
+    }
+
+    let starts_with_selector = rule.starts_with(|c: char| c.is_ascii_alphabetic())
+        && !rule.starts_with("off")
+        && !rule.starts_with("closed");
+    let (weekdays, public_holiday, schedule) = if starts_with_selector {
+        let (selector, schedule) = rule.split_once(' ').unwrap_or((rule, ""));
+        let (weekdays, public_holiday) = parse_selector(selector)?;
+        (weekdays, public_holiday, schedule.trim())
+    } else {
+        ([true; 7], false, rule)
+    };
+
+    let spans = match schedule {
+        "off" | "closed" => vec![],
+        "" => return None,
+        _ => schedule
+            .split(',')
+            .map(|span| parse_span(span.trim()))
+            .collect::<Option<Vec<_>>>()?,
+    };
+
+    Some(Rule {
+        weekdays,
+        public_holiday,
+        spans,
+    })
+}
+
+fn resolve_local(tz: Tz, local: NaiveDateTime) -> DateTime<Tz> {
+    // Local times skipped by a DST jump resolve to the first valid instant after them
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .unwrap_or_else(|| tz.from_utc_datetime(&local))
+}
+
+impl OpeningHours {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        let source = s.as_ref().trim();
+        let rules = source
+            .split(';')
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+            .map(parse_rule)
+            .collect::<Option<Vec<_>>>();
+
+        match rules {
+            Some(rules) if !rules.is_empty() => Ok(OpeningHours {
+                source: source.to_string(),
+                rules,
+            }),
+            _ => invalid(source),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        self.source.clone()
+    }
+
+    fn spans_on(&self, date: NaiveDate, holidays: &dyn PublicHolidays) -> &[TimeSpan] {
+        let weekday = date.weekday().num_days_from_monday() as usize;
+        let holiday_rules =
+            holidays.is_public_holiday(date) && self.rules.iter().any(|rule| rule.public_holiday);
+
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| {
+                if holiday_rules {
+                    rule.public_holiday
+                } else {
+                    rule.weekdays[weekday]
+                }
+            })
+            .map(|rule| rule.spans.as_slice())
+            .unwrap_or(&[])
+    }
+
+    // Local open intervals from the day before `date` on, touching intervals merged
+    fn intervals_from(
+        &self,
+        date: NaiveDate,
+        days: i64,
+        holidays: &dyn PublicHolidays,
+    ) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+        let mut intervals: Vec<(NaiveDateTime, NaiveDateTime)> = (-1..days)
+            .map(|offset| date + Duration::days(offset))
+            .flat_map(|day| {
+                let midnight = day.and_hms_opt(0, 0, 0).unwrap();
+                self.spans_on(day, holidays)
+                    .iter()
+                    .map(move |span| {
+                        (
+                            midnight + Duration::minutes(span.start as i64),
+                            midnight + Duration::minutes(span.end as i64),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        intervals.sort();
+
+        let mut merged: Vec<(NaiveDateTime, NaiveDateTime)> = vec![];
+        for (start, end) in intervals {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+
+    pub fn is_open_at(&self, at: DateTime<Utc>, tz: Tz, holidays: &dyn PublicHolidays) -> bool {
+        self.status_at(at, tz, holidays).open
+    }
+
+    pub fn status_at(
+        &self,
+        at: DateTime<Utc>,
+        tz: Tz,
+        holidays: &dyn PublicHolidays,
+    ) -> OpeningStatus {
+        let local = at.with_timezone(&tz).naive_local();
+        let intervals = self.intervals_from(local.date(), LOOKAHEAD_DAYS, holidays);
+        let horizon = (local.date() + Duration::days(LOOKAHEAD_DAYS))
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        match intervals
+            .iter()
+            .find(|(start, end)| *start <= local && local < *end)
+        {
+            // Open past the lookahead (e.g. 24/7) has no known closing time
+            Some((_, end)) => OpeningStatus {
+                open: true,
+                next_open: None,
+                closes_at: (*end < horizon).then(|| resolve_local(tz, *end)),
+            },
+            None => OpeningStatus {
+                open: false,
+                next_open: intervals
+                    .iter()
+                    .find(|(start, _)| *start > local)
+                    .map(|(start, _)| resolve_local(tz, *start)),
+                closes_at: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::America::Sao_Paulo;
+
+    // 2026-10-19 is a Monday, Sao Paulo is UTC-3 all year
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Sao_Paulo
+            .with_ymd_and_hms(2026, 10, day, hour, minute, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    struct NoPublicHolidays;
+
+    impl PublicHolidays for NoPublicHolidays {
+        fn is_public_holiday(&self, _date: NaiveDate) -> bool {
+            false
+        }
+    }
+
+    struct Holidays(Vec<NaiveDate>);
+
+    impl PublicHolidays for Holidays {
+        fn is_public_holiday(&self, date: NaiveDate) -> bool {
+            self.0.contains(&date)
+        }
+    }
+
+    #[test]
+    fn parses_weekday_ranges_and_multiple_intervals() {
+        let hours = OpeningHours::new("Mo-Fr 08:00-12:00,13:00-18:00; Sa 09:00-13:00").unwrap();
+        assert!(hours.is_open_at(at(19, 9, 0), Sao_Paulo, &NoPublicHolidays));
+        assert!(!hours.is_open_at(at(19, 12, 30), Sao_Paulo, &NoPublicHolidays));
+        assert!(hours.is_open_at(at(24, 12, 59), Sao_Paulo, &NoPublicHolidays));
+        assert!(!hours.is_open_at(at(25, 10, 0), Sao_Paulo, &NoPublicHolidays));
+    }
+
+    #[test]
+    fn overnight_spans_continue_into_the_next_day() {
+        let hours = OpeningHours::new("Fr 22:00-02:00").unwrap();
+        let status = hours.status_at(at(24, 1, 0), Sao_Paulo, &NoPublicHolidays);
+        assert!(status.open);
+        assert_eq!(
+            status.closes_at.unwrap().to_rfc3339(),
+            "2026-10-24T02:00:00-03:00"
+        );
+        assert!(!hours.is_open_at(at(24, 3, 0), Sao_Paulo, &NoPublicHolidays));
+    }
+
+    #[test]
+    fn next_open_skips_closed_days() {
+        let hours = OpeningHours::new("Mo-Fr 08:00-18:00; Sa,Su off").unwrap();
+        let status = hours.status_at(at(24, 10, 0), Sao_Paulo, &NoPublicHolidays);
+        assert!(!status.open);
+        assert_eq!(
+            status.next_open.unwrap().to_rfc3339(),
+            "2026-10-26T08:00:00-03:00"
+        );
+    }
+
+    #[test]
+    fn public_holiday_rules_override_weekdays() {
+        let hours = OpeningHours::new("Mo-Fr 08:00-18:00; PH off").unwrap();
+        let holidays = Holidays(vec![NaiveDate::from_ymd_opt(2026, 10, 19).unwrap()]);
+        assert!(!hours.is_open_at(at(19, 9, 0), Sao_Paulo, &holidays));
+        assert!(hours.is_open_at(at(20, 9, 0), Sao_Paulo, &holidays));
+    }
+
+    #[test]
+    fn always_open_has_no_closing_time() {
+        let status = OpeningHours::new("24/7").unwrap().status_at(
+            at(19, 3, 0),
+            Sao_Paulo,
+            &NoPublicHolidays,
+        );
+        assert!(status.open);
+        assert_eq!(status.closes_at, None);
+    }
+
+    #[test]
+    fn rejects_malformed_rules() {
+        assert!(OpeningHours::new("").is_err());
+        assert!(OpeningHours::new("Mo-Xx 08:00-18:00").is_err());
+        assert!(OpeningHours::new("Mo 8-18").is_err());
+        assert!(OpeningHours::new("Mo 25:00-26:00").is_err());
+    }
+}
@@ -1,23 +1,198 @@
+use crate::common::error::{AppError, ErrorData};
 use crate::common::result::ResultApp;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
 use sha2::{Digest, Sha256};
+use std::env;
+use std::sync::{Arc, OnceLock};
+
+const LEGACY_SHA256_HEX_LENGTH: usize = 64;
+
+static HASH_PARAMS: OnceLock<PasswordHashParams> = OnceLock::new();
+// Checked when no account matches, so an unknown email costs as much as a wrong password
+static DUMMY_PASSWORD: OnceLock<Password> = OnceLock::new();
+
+// Argon2id cost parameters, defaults follow the OWASP recommendation (19 MiB, 2 iterations)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordHashParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for PasswordHashParams {
+    fn default() -> Self {
+        Self {
+            memory_kib: 19_456,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+impl PasswordHashParams {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            memory_kib: env_u32("ARGON2_MEMORY_KIB").unwrap_or(default.memory_kib),
+            iterations: env_u32("ARGON2_ITERATIONS").unwrap_or(default.iterations),
+            parallelism: env_u32("ARGON2_PARALLELISM").unwrap_or(default.parallelism),
+        }
+    }
+
+    // Sets the parameters used by every new hash, must be called once at startup. The dummy hash
+    // is made right away, the first sign in with an unknown email would be slower otherwise
+    pub fn install(self) {
+        let _ = HASH_PARAMS.set(self);
+        Password::dummy();
+    }
+
+    pub fn current() -> Self {
+        *HASH_PARAMS.get_or_init(Self::default)
+    }
+
+    fn argon2(&self) -> ResultApp<Argon2<'static>> {
+        match Params::new(self.memory_kib, self.iterations, self.parallelism, None) {
+            Ok(params) => Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params)),
+            Err(_) => Err(Arc::new(AppError::Internal(ErrorData::new(
+                "invalid-password-hash-params",
+                "invalid argon2 parameters",
+            )))),
+        }
+    }
+}
+
+fn env_u32(key: &str) -> Option<u32> {
+    env::var(key).ok().and_then(|v| v.parse().ok())
+}
 
 #[derive(Debug, Clone)]
 pub struct Password(String);
 
 impl Password {
     pub fn new(value: String) -> ResultApp<Self> {
-        let mut hasher = Sha256::new();
-        hasher.update(value.as_bytes());
-        let result = hasher.finalize();
-        let hex_hash_password = hex::encode(result);
-        Ok(Self(hex_hash_password))
+        Self::new_with_params(value, &PasswordHashParams::current())
+    }
+
+    pub fn new_with_params(value: String, params: &PasswordHashParams) -> ResultApp<Self> {
+        let salt = SaltString::generate(&mut OsRng);
+        match params.argon2()?.hash_password(value.as_bytes(), &salt) {
+            Ok(hash) => Ok(Self(hash.to_string())),
+            Err(_) => Err(Arc::new(AppError::Internal(ErrorData::new(
+                "password-hash-failed",
+                "could not hash password",
+            )))),
+        }
+    }
+
+    // Argon2id hash of a throwaway secret, made once with the installed parameters
+    pub fn dummy() -> &'static Password {
+        DUMMY_PASSWORD.get_or_init(|| Password::new("dummy-password".to_string()).unwrap())
     }
 
     pub fn new_from_hashed_value(value: String) -> Self {
         Self(value)
     }
 
+    pub fn verify(&self, plaintext: &str) -> bool {
+        if self.is_legacy() {
+            let digest = hex::encode(Sha256::digest(plaintext.as_bytes()));
+            return constant_time_eq(digest.as_bytes(), self.0.to_lowercase().as_bytes());
+        }
+
+        match PasswordHash::new(&self.0) {
+            Ok(hash) => Argon2::default()
+                .verify_password(plaintext.as_bytes(), &hash)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    // True for legacy SHA-256 hashes and Argon2 hashes made with other cost parameters
+    pub fn needs_rehash(&self) -> bool {
+        if self.is_legacy() {
+            return true;
+        }
+
+        let current = PasswordHashParams::current();
+        match PasswordHash::new(&self.0) {
+            Ok(hash) => match Params::try_from(&hash) {
+                Ok(params) => {
+                    hash.algorithm != Algorithm::Argon2id.ident()
+                        || params.m_cost() != current.memory_kib
+                        || params.t_cost() != current.iterations
+                        || params.p_cost() != current.parallelism
+                }
+                Err(_) => true,
+            },
+            Err(_) => true,
+        }
+    }
+
+    fn is_legacy(&self) -> bool {
+        self.0.len() == LEGACY_SHA256_HEX_LENGTH && self.0.chars().all(|c| c.is_ascii_hexdigit())
+    }
+
     pub fn value(&self) -> String {
         self.0.clone()
     }
 }
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cheap_params() -> PasswordHashParams {
+        PasswordHashParams {
+            memory_kib: 1024,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn hash_is_phc_argon2id_and_verifies() {
+        let p = Password::new_with_params("s3cret".to_string(), &cheap_params()).unwrap();
+        assert!(p.value().starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+        assert!(p.verify("s3cret"));
+        assert!(!p.verify("wrong"));
+    }
+
+    #[test]
+    fn same_password_gets_different_salts() {
+        let a = Password::new_with_params("s3cret".to_string(), &cheap_params()).unwrap();
+        let b = Password::new_with_params("s3cret".to_string(), &cheap_params()).unwrap();
+        assert_ne!(a.value(), b.value());
+    }
+
+    #[test]
+    fn legacy_sha256_hash_verifies_and_needs_rehash() {
+        let legacy = hex::encode(Sha256::digest(b"s3cret"));
+        let p = Password::new_from_hashed_value(legacy);
+        assert!(p.verify("s3cret"));
+        assert!(!p.verify("wrong"));
+        assert!(p.needs_rehash());
+    }
+
+    #[test]
+    fn dummy_hash_costs_as_much_as_a_current_hash() {
+        let dummy = Password::dummy();
+        assert!(dummy.value().starts_with("$argon2id$"));
+        assert!(!dummy.needs_rehash());
+        assert!(!dummy.verify("s3cret"));
+    }
+
+    #[test]
+    fn hash_with_other_params_needs_rehash() {
+        let p = Password::new_with_params("s3cret".to_string(), &cheap_params()).unwrap();
+        assert!(p.needs_rehash());
+    }
+}
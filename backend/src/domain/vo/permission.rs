@@ -0,0 +1,74 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+// Codes match the rows seeded in the `permissions` table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+    UsersUpdateAny,
+    UsersDeleteAny,
+    RolesManage,
+    CustomerServicesCreate,
+    CustomerServicesUpdateAny,
+    CustomerServicesDeleteAny,
+    HolidaysManage,
+    CategoriesManage,
+    ReviewsDeleteAny,
+    ModerationDecide,
+    ClaimsDecide,
+}
+
+impl Permission {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim() {
+            "users:update-any" => Ok(Permission::UsersUpdateAny),
+            "users:delete-any" => Ok(Permission::UsersDeleteAny),
+            "roles:manage" => Ok(Permission::RolesManage),
+            "customer-services:create" => Ok(Permission::CustomerServicesCreate),
+            "customer-services:update-any" => Ok(Permission::CustomerServicesUpdateAny),
+            "customer-services:delete-any" => Ok(Permission::CustomerServicesDeleteAny),
+            "holidays:manage" => Ok(Permission::HolidaysManage),
+            "categories:manage" => Ok(Permission::CategoriesManage),
+            "reviews:delete-any" => Ok(Permission::ReviewsDeleteAny),
+            "moderation:decide" => Ok(Permission::ModerationDecide),
+            "claims:decide" => Ok(Permission::ClaimsDecide),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-permission", "Invalid permission").with_args(
+                    HashMap::from([("permission".to_string(), other.to_string())]),
+                ),
+            ))),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            Permission::UsersUpdateAny => "users:update-any",
+            Permission::UsersDeleteAny => "users:delete-any",
+            Permission::RolesManage => "roles:manage",
+            Permission::CustomerServicesCreate => "customer-services:create",
+            Permission::CustomerServicesUpdateAny => "customer-services:update-any",
+            Permission::CustomerServicesDeleteAny => "customer-services:delete-any",
+            Permission::HolidaysManage => "holidays:manage",
+            Permission::CategoriesManage => "categories:manage",
+            Permission::ReviewsDeleteAny => "reviews:delete-any",
+            Permission::ModerationDecide => "moderation:decide",
+            Permission::ClaimsDecide => "claims:decide",
+        }
+        .to_string()
+    }
+}
+
+impl Display for Permission {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+// Declares who may run a protected operation, use cases keep one as a constant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessPolicy {
+    Require(Permission),
+    OwnerOr(Permission),
+}
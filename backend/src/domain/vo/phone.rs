@@ -1,148 +1,361 @@
 use crate::common::error::{AppError, ErrorData};
 use crate::common::result::ResultApp;
+use crate::domain::vo::phone_metadata::{REGIONS, RegionMetadata, brazil_area_code, region_for};
 use regex::Regex;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
+
+static PHONE_CHARACTERS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\+?[\d\s().-]+$").unwrap());
+// E.164 allows up to 15 digits, starting with non-zero. We require leading '+'.
+static E164: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\+[1-9]\d{1,14}$").unwrap());
+static BRAZIL_AREA_CODE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(concat!("^", brazil_area_code!(), "$")).unwrap());
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Country {
     E164, // Fallback when only E.164 can be asserted
     US,
+    CA,
     BR,
+    AR,
+    PT,
+}
+
+impl Country {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim().to_ascii_uppercase().as_str() {
+            "US" => Ok(Country::US),
+            "CA" => Ok(Country::CA),
+            "BR" => Ok(Country::BR),
+            "AR" => Ok(Country::AR),
+            "PT" => Ok(Country::PT),
+            _ => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-phone-country", "Invalid phone country").with_args(
+                    HashMap::from([("country".to_string(), s.as_ref().to_string())]),
+                ),
+            ))),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            // Unknown region, as CLDR spells it
+            Country::E164 => "ZZ",
+            Country::US => "US",
+            Country::CA => "CA",
+            Country::BR => "BR",
+            Country::AR => "AR",
+            Country::PT => "PT",
+        }
+        .to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LineType {
+    Mobile,
+    FixedLine,
+    // Regions such as the NANP do not tell the two apart by number
+    FixedLineOrMobile,
+    TollFree,
+    PremiumRate,
+    Unknown,
+}
+
+impl LineType {
+    pub fn value(&self) -> String {
+        match self {
+            LineType::Mobile => "mobile",
+            LineType::FixedLine => "fixed-line",
+            LineType::FixedLineOrMobile => "fixed-line-or-mobile",
+            LineType::TollFree => "toll-free",
+            LineType::PremiumRate => "premium-rate",
+            LineType::Unknown => "unknown",
+        }
+        .to_string()
+    }
 }
 
 pub trait PhoneLike {
     fn validate(&self) -> ResultApp<()>;
     fn format(&self) -> String; // E.164 normalized
+    fn national_format(&self) -> String;
+    fn international_format(&self) -> String;
+    fn country(&self) -> Country;
+    fn line_type(&self) -> LineType;
 }
 
 #[derive(Debug, Clone)]
 pub struct Phone {
     country: Country,
+    line_type: LineType,
     // Always store the normalized E.164 representation (with leading '+')
     e164: String,
 }
 
 impl Phone {
-    // Create from raw input, auto-detecting country pattern and normalizing to E.164
+    // Create from raw input, detecting the region from the calling code or, for national input,
+    // from the numbering plans. Fails with `ambiguous-phone` when plans of several calling codes
+    // accept it
     pub fn new(raw: String) -> ResultApp<Self> {
-        let trimmed = raw.trim();
+        let phone = resolve(&raw, None)?;
+        phone.validate()?;
+        Ok(phone)
+    }
 
-        // If already valid E.164, accept as-is
-        if is_e164(trimmed) {
-            return Ok(Self {
-                country: Country::E164,
-                e164: trimmed.to_string(),
-            });
-        }
+    pub fn new_for_country(country: Country, raw: String) -> ResultApp<Self> {
+        let phone = resolve(&raw, Some(country))?;
+        phone.validate()?;
+        Ok(phone)
+    }
 
-        // Try US normalizations
-        if let Some(e164) = normalize_us(trimmed) {
-            return Ok(Self {
-                country: Country::US,
-                e164,
-            });
-        }
+    // For numbers already persisted, which may predate the current metadata
+    pub fn new_lenient(e164: String) -> Self {
+        resolve(&e164, None).unwrap_or(Self {
+            country: Country::E164,
+            line_type: LineType::Unknown,
+            e164,
+        })
+    }
 
-        // Try BR normalizations
-        if let Some(e164) = normalize_br(trimmed) {
-            return Ok(Self {
-                country: Country::BR,
-                e164,
-            });
-        }
+    pub fn value(&self) -> String {
+        self.e164.clone()
+    }
 
-        // Try to coerce digits into E.164 if it looks like it already includes a country code
-        if let Some(e164) = digits_to_e164_guess(trimmed) {
-            return Ok(Self {
-                country: Country::E164,
-                e164,
-            });
+    // National significant number, the E.164 value without `+` and the calling code
+    fn national_number(&self, region: &RegionMetadata) -> String {
+        self.e164[1 + region.calling_code.len()..].to_string()
+    }
+}
+
+impl PhoneLike for Phone {
+    fn validate(&self) -> ResultApp<()> {
+        if !is_e164(&self.e164) {
+            return Err(invalid_phone(self.country));
         }
+        match region_for(self.country) {
+            Some(region) => {
+                let national_number = self.national_number(region);
+                match region.line_type(&national_number) {
+                    Some(_) => Ok(()),
+                    None => Err(region_error(region, &national_number)),
+                }
+            }
+            None => Ok(()),
+        }
+    }
 
-        Err(Arc::new(AppError::Validation(ErrorData::new(
-            "invalid-phone",
-            "Invalid or unsupported phone format",
-        ))))
+    fn format(&self) -> String {
+        self.e164.clone()
     }
 
-    pub fn validate(&self) -> ResultApp<()> {
-        if is_e164(&self.e164) {
-            return Ok(());
+    fn national_format(&self) -> String {
+        match region_for(self.country) {
+            Some(region) => region
+                .format(&self.national_number(region), false)
+                .unwrap_or_else(|| self.e164.clone()),
+            None => self.e164.clone(),
         }
-        Err(Arc::new(AppError::Validation(ErrorData::new(
-            "invalid-phone",
-            "Invalid or unsupported phone format",
-        ))))
     }
 
-    pub fn value(&self) -> String {
-        self.e164.clone()
+    fn international_format(&self) -> String {
+        match region_for(self.country) {
+            Some(region) => region
+                .format(&self.national_number(region), true)
+                .unwrap_or_else(|| self.e164.clone()),
+            None => self.e164.clone(),
+        }
     }
 
-    pub fn country(&self) -> Country {
+    fn country(&self) -> Country {
         self.country
     }
+
+    fn line_type(&self) -> LineType {
+        self.line_type
+    }
 }
 
 impl Display for Phone {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.e164)
+        write!(f, "{}", self.format())
     }
 }
 
-fn is_e164(s: &str) -> bool {
-    // E.164 allows up to 15 digits, starting with non-zero. We require leading '+'.
-    let re = Regex::new(r"^\+[1-9]\d{1,14}$").unwrap();
-    re.is_match(s)
-}
+fn resolve(raw: &str, country: Option<Country>) -> ResultApp<Phone> {
+    let trimmed = raw.trim();
+    if !PHONE_CHARACTERS.is_match(trimmed) {
+        return Err(invalid_phone(country.unwrap_or(Country::E164)));
+    }
+    let international = trimmed.starts_with('+');
+    let digits = digits_only(trimmed);
 
-fn digits_only(s: &str) -> String {
-    s.chars().filter(|c| c.is_ascii_digit()).collect()
+    let regions: Vec<&RegionMetadata> = REGIONS
+        .iter()
+        .filter(|region| country.is_none_or(|country| region.country == country))
+        .collect();
+    // Regions sharing a calling code yield the same number, the first of them is kept
+    let mut candidates: Vec<Phone> = vec![];
+    for region in &regions {
+        let candidate = national_numbers(region, &digits, international)
+            .into_iter()
+            .find_map(|national_number| {
+                region.line_type(&national_number).map(|line_type| Phone {
+                    country: region.country,
+                    line_type,
+                    e164: format!("+{}{}", region.calling_code, national_number),
+                })
+            });
+        if let Some(candidate) = candidate
+            && !candidates.iter().any(|phone| phone.e164 == candidate.e164)
+        {
+            candidates.push(candidate);
+        }
+    }
+    match candidates.as_slice() {
+        [phone] => return Ok(phone.clone()),
+        [_, ..] => {
+            let regions: Vec<String> = candidates.iter().map(|p| p.country.value()).collect();
+            return Err(Arc::new(AppError::Validation(
+                ErrorData::new(
+                    "ambiguous-phone",
+                    "Phone number is valid in more than one region, the country must be given",
+                )
+                .with_args(HashMap::from([
+                    ("phone".to_string(), trimmed.to_string()),
+                    ("regions".to_string(), regions.join(",")),
+                ])),
+            )));
+        }
+        [] => {}
+    }
+
+    // A single region claims the number, its own rules explain why it was refused
+    let claimed: Vec<&&RegionMetadata> = regions
+        .iter()
+        .filter(|region| {
+            country.is_some() || (international && digits.starts_with(region.calling_code))
+        })
+        .collect();
+    if let [region] = claimed.as_slice() {
+        let national_number = if international {
+            digits[region.calling_code.len()..].to_string()
+        } else {
+            strip_national_prefix(region, &digits)
+        };
+        return Err(region_error(region, &national_number));
+    }
+
+    // Calling codes without metadata are kept as plain E.164
+    if country.is_none()
+        && claimed.is_empty()
+        && let Some(e164) = digits_to_e164_guess(&digits)
+        && !REGIONS
+            .iter()
+            .any(|region| e164[1..].starts_with(region.calling_code))
+    {
+        return Ok(Phone {
+            country: Country::E164,
+            line_type: LineType::Unknown,
+            e164,
+        });
+    }
+
+    Err(invalid_phone(country.unwrap_or(Country::E164)))
 }
 
-// US normalization: accept common forms and output +1XXXXXXXXXX
-fn normalize_us(s: &str) -> Option<String> {
-    // Extract digits
-    let digits = digits_only(s);
-    // Expect 10 digits (NPA-NXX-XXXX) or 11 with leading 1
-    let (cc, rest) = if digits.len() == 10 {
-        ("1", digits)
-    } else if digits.len() == 11 && digits.starts_with('1') {
-        ("1", digits[1..].to_string())
+// The ways the digits may spell a national significant number of the region
+fn national_numbers(region: &RegionMetadata, digits: &str, international: bool) -> Vec<String> {
+    let mut national_numbers = vec![];
+    if international {
+        if let Some(rest) = digits.strip_prefix(region.calling_code) {
+            national_numbers.push(rest.to_string());
+        }
     } else {
-        return None;
-    };
-    // Basic NANP sanity: first digit of area code and exchange cannot be 0 or 1
-    let ac_first = rest.chars().next()?;
-    let ex_first = rest.chars().nth(3)?;
-    if ac_first < '2' || ex_first < '2' {
-        return None;
+        national_numbers.push(digits.to_string());
+        national_numbers.push(strip_national_prefix(region, digits));
+        if let Some(rest) = digits.strip_prefix(region.calling_code) {
+            national_numbers.push(rest.to_string());
+        }
+    }
+
+    if let Some(rewrite) = region.national_rewrite {
+        let rewritten: Vec<String> = national_numbers
+            .iter()
+            .filter_map(|national_number| rewrite(national_number))
+            .collect();
+        national_numbers.extend(rewritten);
+    }
+    national_numbers
+}
+
+fn strip_national_prefix(region: &RegionMetadata, digits: &str) -> String {
+    region
+        .national_prefix
+        .and_then(|prefix| digits.strip_prefix(prefix))
+        .unwrap_or(digits)
+        .to_string()
+}
+
+fn region_error(region: &RegionMetadata, national_number: &str) -> Arc<AppError> {
+    if region.country == Country::BR
+        && let Some(error) = brazil_error(national_number)
+    {
+        return error;
     }
-    Some(format!("+{}{}", cc, rest))
+    invalid_phone(region.country)
 }
 
-// Brazil normalization: accept common forms and output +55AA9XXXXXXXX or +55AAXXXXXXXX (landline)
-fn normalize_br(s: &str) -> Option<String> {
-    let mut digits = digits_only(s);
-    // Remove leading country code 55 if present
-    if digits.starts_with("55") {
-        digits = digits[2..].to_string();
+// Pinpoints the Brazilian rules a ten or eleven digit number breaks
+fn brazil_error(national_number: &str) -> Option<Arc<AppError>> {
+    if !matches!(national_number.len(), 10 | 11)
+        || !national_number.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
     }
-    // Now expect DD + number; mobile is 11 digits (9 + 8), landline often 10 digits
-    if digits.len() == 11 {
-        // 2-digit area + 9-digit mobile
-        return Some(format!("+55{}", digits));
-    } else if digits.len() == 10 {
-        // landline
-        return Some(format!("+55{}", digits));
+    let area_code = &national_number[..2];
+    if !BRAZIL_AREA_CODE.is_match(area_code) {
+        return Some(Arc::new(AppError::Validation(
+            ErrorData::new("invalid-brazilian-area-code", "Invalid Brazilian area code").with_args(
+                HashMap::from([("area_code".to_string(), area_code.to_string())]),
+            ),
+        )));
+    }
+    // Eleven digits must be a mobile starting with 9, ten digits starting with 6 to 9 are
+    // mobiles missing it
+    let subscriber_first = &national_number[2..3];
+    let missing_nine = match national_number.len() {
+        11 => subscriber_first != "9",
+        _ => matches!(subscriber_first, "6" | "7" | "8" | "9"),
+    };
+    if missing_nine {
+        return Some(Arc::new(AppError::Validation(ErrorData::new(
+            "invalid-brazilian-mobile-prefix",
+            "Brazilian mobile numbers have nine digits starting with 9",
+        ))));
     }
     None
 }
 
+fn invalid_phone(country: Country) -> Arc<AppError> {
+    Arc::new(AppError::Validation(
+        ErrorData::new("invalid-phone", "Invalid or unsupported phone format")
+            .with_args(HashMap::from([("region".to_string(), country.value())])),
+    ))
+}
+
+fn is_e164(s: &str) -> bool {
+    E164.is_match(s)
+}
+
+fn digits_only(s: &str) -> String {
+    s.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
 // If it already contains a plausible country code and total digits 8..15, accept as E.164 with '+'
-fn digits_to_e164_guess(s: &str) -> Option<String> {
-    let digits = digits_only(s);
+fn digits_to_e164_guess(digits: &str) -> Option<String> {
     if digits.len() < 8 || digits.len() > 15 {
         return None;
     }
@@ -152,3 +365,127 @@ fn digits_to_e164_guess(s: &str) -> Option<String> {
     }
     Some(format!("+{}", digits))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::error_code;
+
+    fn phone(raw: &str) -> Phone {
+        Phone::new(raw.to_string()).unwrap()
+    }
+
+    #[test]
+    fn brazilian_numbers_are_detected_and_formatted() {
+        let mobile = phone("(11) 91234-5678");
+        assert_eq!(mobile.value(), "+5511912345678");
+        assert_eq!(mobile.country(), Country::BR);
+        assert_eq!(mobile.line_type(), LineType::Mobile);
+        assert_eq!(mobile.national_format(), "(11) 91234-5678");
+        assert_eq!(mobile.international_format(), "+55 11 91234-5678");
+
+        let landline = phone("+55 21 2345-6789");
+        assert_eq!(landline.line_type(), LineType::FixedLine);
+        assert_eq!(landline.national_format(), "(21) 2345-6789");
+
+        let toll_free = Phone::new_for_country(Country::BR, "0800 123 4567".to_string()).unwrap();
+        assert_eq!(toll_free.value(), "+558001234567");
+        assert_eq!(toll_free.line_type(), LineType::TollFree);
+        assert_eq!(toll_free.national_format(), "0800 123 4567");
+    }
+
+    #[test]
+    fn brazilian_area_code_and_mobile_prefix_are_enforced() {
+        assert_eq!(
+            error_code(Phone::new("+55 20 91234-5678".to_string())),
+            "invalid-brazilian-area-code"
+        );
+        assert_eq!(
+            error_code(Phone::new("+55 11 81234-5678".to_string())),
+            "invalid-brazilian-mobile-prefix"
+        );
+        // An eight digit mobile from before the 9 was added
+        assert_eq!(
+            error_code(Phone::new_for_country(
+                Country::BR,
+                "(11) 8123-4567".to_string()
+            )),
+            "invalid-brazilian-mobile-prefix"
+        );
+    }
+
+    #[test]
+    fn national_input_valid_in_several_regions_is_ambiguous() {
+        // 21 is the Rio de Janeiro DDD and 212 a New York area code, also valid in Argentina
+        assert_eq!(
+            error_code(Phone::new("(212) 555-0123".to_string())),
+            "ambiguous-phone"
+        );
+        assert_eq!(
+            error_code(Phone::new("0800 123 4567".to_string())),
+            "ambiguous-phone"
+        );
+        let us = Phone::new_for_country(Country::US, "(212) 555-0123".to_string()).unwrap();
+        assert_eq!(us.value(), "+12125550123");
+        let br = Phone::new_for_country(Country::BR, "(21) 2555-0123".to_string()).unwrap();
+        assert_eq!(br.value(), "+552125550123");
+        assert_eq!(phone("+1 212 555 0123").country(), Country::US);
+        // Canada and the US share +1, the same number is not ambiguous
+        assert_eq!(phone("(416) 555-0123").country(), Country::CA);
+    }
+
+    #[test]
+    fn north_american_numbers_are_told_apart() {
+        let us = phone("+1 (201) 555-0123");
+        assert_eq!(us.value(), "+12015550123");
+        assert_eq!(us.country(), Country::US);
+        assert_eq!(us.line_type(), LineType::FixedLineOrMobile);
+        assert_eq!(us.national_format(), "(201) 555-0123");
+        assert_eq!(us.international_format(), "+1 201-555-0123");
+
+        assert_eq!(phone("+1 416 555 0123").country(), Country::CA);
+        let toll_free = phone("1-800-555-0199");
+        assert_eq!(toll_free.country(), Country::US);
+        assert_eq!(toll_free.line_type(), LineType::TollFree);
+    }
+
+    #[test]
+    fn argentine_mobile_is_normalized_from_national_form() {
+        let mobile = Phone::new_for_country(Country::AR, "011 15 4123-4567".to_string()).unwrap();
+        assert_eq!(mobile.value(), "+5491141234567");
+        assert_eq!(mobile.line_type(), LineType::Mobile);
+        assert_eq!(mobile.national_format(), "011 15-4123-4567");
+        assert_eq!(mobile.international_format(), "+54 9 11 4123-4567");
+        assert_eq!(phone("+54 9 11 4123-4567").value(), "+5491141234567");
+    }
+
+    #[test]
+    fn portuguese_numbers_are_typed() {
+        let mobile = phone("+351 912 345 678");
+        assert_eq!(mobile.country(), Country::PT);
+        assert_eq!(mobile.line_type(), LineType::Mobile);
+        assert_eq!(mobile.international_format(), "+351 912 345 678");
+        assert_eq!(phone("+351 21 234 5678").line_type(), LineType::FixedLine);
+        assert_eq!(phone("+351 800 123 456").line_type(), LineType::TollFree);
+    }
+
+    #[test]
+    fn unknown_calling_codes_fall_back_to_e164() {
+        let uk = phone("+44 7911 123456");
+        assert_eq!(uk.value(), "+447911123456");
+        assert_eq!(uk.country(), Country::E164);
+        assert_eq!(uk.line_type(), LineType::Unknown);
+        assert_eq!(uk.national_format(), "+447911123456");
+        assert!(uk.validate().is_ok());
+    }
+
+    #[test]
+    fn garbage_is_rejected_and_persisted_numbers_are_kept() {
+        assert!(Phone::new("call me".to_string()).is_err());
+        assert!(Phone::new("123".to_string()).is_err());
+
+        let legacy = Phone::new_lenient("+551181234567".to_string());
+        assert_eq!(legacy.value(), "+551181234567");
+        assert_eq!(legacy.line_type(), LineType::Unknown);
+    }
+}
@@ -0,0 +1,253 @@
+use crate::domain::vo::phone::{Country, LineType};
+use regex::Regex;
+use std::sync::LazyLock;
+
+// Brazilian DDD area codes in use, shared by the line type patterns below
+macro_rules! brazil_area_code {
+    () => {
+        "(?:1[1-9]|2[12478]|3[1-578]|4[1-9]|5[1345]|6[1-9]|7[134579]|8[1-9]|9[1-9])"
+    };
+}
+pub(crate) use brazil_area_code;
+
+// Canadian area codes, any other valid +1 number is treated as a US one
+macro_rules! canada_area_code {
+    () => {
+        concat!(
+            "(?:204|226|236|249|250|263|289|306|343|354|365|367|368|382|403|416|418|428|431|",
+            "437|438|450|468|474|506|514|519|548|579|581|584|587|604|613|639|647|672|683|705|",
+            "709|742|753|778|780|782|807|819|825|867|873|879|902|905)"
+        )
+    };
+}
+
+// Templates are applied to the national significant number, the international one is prefixed
+// with `+` and the calling code
+pub struct NumberFormat {
+    pub pattern: &'static str,
+    pub national: &'static str,
+    pub international: &'static str,
+}
+
+pub struct RegionMetadata {
+    pub country: Country,
+    pub calling_code: &'static str,
+    // Trunk prefix dialled in front of the number inside the country
+    pub national_prefix: Option<&'static str>,
+    // Checked in order, the first matching pattern gives the line type
+    pub line_types: &'static [(LineType, &'static str)],
+    pub formats: &'static [NumberFormat],
+    // Turns a national dialling form into the national significant number, when the two differ
+    // by more than the trunk prefix
+    pub national_rewrite: Option<fn(&str) -> Option<String>>,
+}
+
+// Compiled into the binary in the spirit of libphonenumber's metadata. Regions sharing a calling
+// code are tried in table order, national input that fits several calling codes is ambiguous
+pub const REGIONS: &[RegionMetadata] = &[
+    RegionMetadata {
+        country: Country::BR,
+        calling_code: "55",
+        national_prefix: Some("0"),
+        line_types: &[
+            (LineType::TollFree, r"800\d{6,7}"),
+            (LineType::PremiumRate, r"[59]00\d{6,7}"),
+            // Mobiles gained the leading 9 in 2016, eight digit mobiles are no longer dialable
+            (LineType::Mobile, concat!(brazil_area_code!(), r"9\d{8}")),
+            (
+                LineType::FixedLine,
+                concat!(brazil_area_code!(), r"[2-5]\d{7}"),
+            ),
+        ],
+        formats: &[
+            NumberFormat {
+                pattern: r"^([589]00)(\d{2,3})(\d{4})$",
+                national: "0$1 $2 $3",
+                international: "$1 $2 $3",
+            },
+            NumberFormat {
+                pattern: r"^(\d{2})(\d{4,5})(\d{4})$",
+                national: "($1) $2-$3",
+                international: "$1 $2-$3",
+            },
+        ],
+        national_rewrite: None,
+    },
+    RegionMetadata {
+        country: Country::CA,
+        calling_code: "1",
+        national_prefix: Some("1"),
+        line_types: &[(
+            LineType::FixedLineOrMobile,
+            concat!(canada_area_code!(), r"[2-9]\d{6}"),
+        )],
+        formats: &[NumberFormat {
+            pattern: r"^(\d{3})(\d{3})(\d{4})$",
+            national: "($1) $2-$3",
+            international: "$1-$2-$3",
+        }],
+        national_rewrite: None,
+    },
+    RegionMetadata {
+        country: Country::US,
+        calling_code: "1",
+        national_prefix: Some("1"),
+        line_types: &[
+            (LineType::TollFree, r"8(?:00|33|44|55|66|77|88)[2-9]\d{6}"),
+            (LineType::PremiumRate, r"900[2-9]\d{6}"),
+            // Mobile and fixed numbers share the NANP numbering plan
+            (LineType::FixedLineOrMobile, r"[2-9]\d{2}[2-9]\d{6}"),
+        ],
+        formats: &[NumberFormat {
+            pattern: r"^(\d{3})(\d{3})(\d{4})$",
+            national: "($1) $2-$3",
+            international: "$1-$2-$3",
+        }],
+        national_rewrite: None,
+    },
+    RegionMetadata {
+        country: Country::AR,
+        calling_code: "54",
+        national_prefix: Some("0"),
+        line_types: &[
+            (LineType::TollFree, r"800\d{7}"),
+            (LineType::PremiumRate, r"600\d{7}"),
+            // Mobiles carry a 9 between the calling code and the area code
+            (LineType::Mobile, r"9(?:11|[23]\d)\d{8}"),
+            (LineType::FixedLine, r"(?:11|[23]\d)\d{8}"),
+        ],
+        // Area codes other than Buenos Aires are grouped as three digits
+        formats: &[
+            NumberFormat {
+                pattern: r"^([68]00)(\d{3})(\d{4})$",
+                national: "0$1 $2-$3",
+                international: "$1 $2-$3",
+            },
+            NumberFormat {
+                pattern: r"^9(11)(\d{4})(\d{4})$",
+                national: "0$1 15-$2-$3",
+                international: "9 $1 $2-$3",
+            },
+            NumberFormat {
+                pattern: r"^9(\d{3})(\d{3})(\d{4})$",
+                national: "0$1 15-$2-$3",
+                international: "9 $1 $2-$3",
+            },
+            NumberFormat {
+                pattern: r"^(11)(\d{4})(\d{4})$",
+                national: "0$1 $2-$3",
+                international: "$1 $2-$3",
+            },
+            NumberFormat {
+                pattern: r"^(\d{3})(\d{3})(\d{4})$",
+                national: "0$1 $2-$3",
+                international: "$1 $2-$3",
+            },
+        ],
+        national_rewrite: Some(argentina_national_mobile),
+    },
+    RegionMetadata {
+        country: Country::PT,
+        calling_code: "351",
+        national_prefix: None,
+        line_types: &[
+            (LineType::TollFree, r"80[02]\d{6}"),
+            (LineType::PremiumRate, r"(?:6\d\d|70[78])\d{6}"),
+            (LineType::Mobile, r"9[1236]\d{7}"),
+            (LineType::FixedLine, r"2\d{8}"),
+        ],
+        formats: &[
+            NumberFormat {
+                pattern: r"^(2[12])(\d{3})(\d{4})$",
+                national: "$1 $2 $3",
+                international: "$1 $2 $3",
+            },
+            NumberFormat {
+                pattern: r"^(\d{3})(\d{3})(\d{3})$",
+                national: "$1 $2 $3",
+                international: "$1 $2 $3",
+            },
+        ],
+        national_rewrite: None,
+    },
+];
+
+// The patterns of REGIONS, in the same order, built on first use instead of on every parse
+struct CompiledRegion {
+    line_types: Vec<(LineType, Regex)>,
+    formats: Vec<(Regex, &'static NumberFormat)>,
+}
+
+static COMPILED_REGIONS: LazyLock<Vec<CompiledRegion>> = LazyLock::new(|| {
+    REGIONS
+        .iter()
+        .map(|region| CompiledRegion {
+            line_types: region
+                .line_types
+                .iter()
+                .map(|(line_type, pattern)| {
+                    (
+                        *line_type,
+                        Regex::new(&format!("^(?:{})$", pattern)).unwrap(),
+                    )
+                })
+                .collect(),
+            formats: region
+                .formats
+                .iter()
+                .map(|number_format| (Regex::new(number_format.pattern).unwrap(), number_format))
+                .collect(),
+        })
+        .collect()
+});
+
+impl RegionMetadata {
+    fn compiled(&self) -> &'static CompiledRegion {
+        let position = REGIONS
+            .iter()
+            .position(|region| region.country == self.country)
+            .unwrap();
+        &COMPILED_REGIONS[position]
+    }
+
+    // None when the national significant number is not valid in the region
+    pub fn line_type(&self, national_number: &str) -> Option<LineType> {
+        self.compiled()
+            .line_types
+            .iter()
+            .find(|(_, pattern)| pattern.is_match(national_number))
+            .map(|(line_type, _)| *line_type)
+    }
+
+    pub fn format(&self, national_number: &str, international: bool) -> Option<String> {
+        self.compiled()
+            .formats
+            .iter()
+            .find(|(pattern, _)| pattern.is_match(national_number))
+            .map(|(pattern, number_format)| {
+                if international {
+                    let formatted = pattern.replace(national_number, number_format.international);
+                    format!("+{} {}", self.calling_code, formatted)
+                } else {
+                    pattern
+                        .replace(national_number, number_format.national)
+                        .to_string()
+                }
+            })
+    }
+}
+
+pub fn region_for(country: Country) -> Option<&'static RegionMetadata> {
+    REGIONS.iter().find(|region| region.country == country)
+}
+
+// Inside Argentina a mobile is dialled as area code, 15 and the subscriber, e.g. 11 15 4123-4567,
+// while its national significant number is 9, area code and subscriber
+fn argentina_national_mobile(digits: &str) -> Option<String> {
+    if digits.len() != 12 {
+        return None;
+    }
+    (2..=4)
+        .find(|&area_length| &digits[area_length..area_length + 2] == "15")
+        .map(|area_length| format!("9{}{}", &digits[..area_length], &digits[area_length + 2..]))
+}
@@ -5,3 +5,9 @@ pub struct Photo {
     pub url: Url,
     pub title: Option<String>,
 }
+
+impl Photo {
+    pub fn new(url: Url, title: Option<String>) -> Self {
+        Self { url, title }
+    }
+}
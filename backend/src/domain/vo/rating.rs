@@ -0,0 +1,74 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub const MIN_RATING: i16 = 1;
+pub const MAX_RATING: i16 = 5;
+
+// Stars given by a review
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rating(i16);
+
+impl Rating {
+    pub fn new(value: i16) -> ResultApp<Self> {
+        if !(MIN_RATING..=MAX_RATING).contains(&value) {
+            return Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-rating", "rating must be between 1 and 5 stars")
+                    .with_args(HashMap::from([("rating".to_string(), value.to_string())])),
+            )));
+        }
+        Ok(Rating(value))
+    }
+
+    pub fn value(&self) -> i16 {
+        self.0
+    }
+}
+
+// Running totals of the reviews of a place, kept up to date on every review write
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RatingSummary {
+    count: i64,
+    sum: i64,
+}
+
+impl RatingSummary {
+    pub fn new(count: i64, sum: i64) -> Self {
+        Self { count, sum }
+    }
+
+    pub fn count(&self) -> i64 {
+        self.count
+    }
+
+    pub fn sum(&self) -> i64 {
+        self.sum
+    }
+
+    // None until the place gets its first review, rounded to one decimal
+    pub fn average(&self) -> Option<f64> {
+        (self.count > 0).then(|| (self.sum as f64 / self.count as f64 * 10.0).round() / 10.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_one_to_five_stars() {
+        assert_eq!(Rating::new(1).unwrap().value(), 1);
+        assert_eq!(Rating::new(5).unwrap().value(), 5);
+        assert!(Rating::new(0).is_err());
+        assert!(Rating::new(6).is_err());
+        assert!(Rating::new(-3).is_err());
+    }
+
+    #[test]
+    fn average_is_rounded_and_absent_without_reviews() {
+        assert_eq!(RatingSummary::default().average(), None);
+        assert_eq!(RatingSummary::new(3, 13).average(), Some(4.3));
+        assert_eq!(RatingSummary::new(2, 9).average(), Some(4.5));
+    }
+}
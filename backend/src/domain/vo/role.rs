@@ -0,0 +1,66 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Admin,
+    Moderator,
+    BusinessOwner,
+    RegularUser,
+}
+
+impl Role {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim() {
+            "admin" => Ok(Role::Admin),
+            "moderator" => Ok(Role::Moderator),
+            "business-owner" => Ok(Role::BusinessOwner),
+            "regular-user" => Ok(Role::RegularUser),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-role", "Invalid role")
+                    .with_args(HashMap::from([("role".to_string(), other.to_string())])),
+            ))),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            Role::Admin => "admin",
+            Role::Moderator => "moderator",
+            Role::BusinessOwner => "business-owner",
+            Role::RegularUser => "regular-user",
+        }
+        .to_string()
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_round_trips_through_its_code() {
+        for role in [
+            Role::Admin,
+            Role::Moderator,
+            Role::BusinessOwner,
+            Role::RegularUser,
+        ] {
+            assert_eq!(Role::new(role.value()).unwrap(), role);
+        }
+    }
+
+    #[test]
+    fn unknown_role_is_rejected() {
+        assert!(Role::new("superuser").is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::sync::Arc;
+
+const MAX_QUERY_LENGTH: usize = 200;
+const MAX_TERMS: usize = 8;
+
+// Free text typed by a user, split into lowercase words; accents are kept and folded by the index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        let source = s.as_ref().trim();
+        let terms: Vec<String> = source
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .map(|term| term.to_lowercase())
+            .take(MAX_TERMS)
+            .collect();
+        if terms.is_empty() || source.chars().count() > MAX_QUERY_LENGTH {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-search-query",
+                "search query must have 1..=200 characters and at least one word",
+            ))));
+        }
+
+        Ok(SearchQuery { terms })
+    }
+
+    // At most 8 words, only letters and digits
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_words_on_punctuation() {
+        let query = SearchQuery::new("  Padaria São-João, 24h!  ").unwrap();
+        assert_eq!(query.terms(), ["padaria", "são", "joão", "24h"]);
+    }
+
+    #[test]
+    fn rejects_queries_without_words() {
+        assert!(SearchQuery::new("").is_err());
+        assert!(SearchQuery::new(" &|!:* ").is_err());
+        assert!(SearchQuery::new("a".repeat(201)).is_err());
+        assert_eq!(
+            SearchQuery::new("a b c d e f g h i j")
+                .unwrap()
+                .terms()
+                .len(),
+            MAX_TERMS
+        );
+    }
+}
@@ -1,3 +1,260 @@
-use std::collections::HashMap;
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 
-pub type Tags = HashMap<String, String>;
+// Declared value of a tag, values are stored in their canonical text form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagValueType {
+    // "yes" or "no", also accepts "true" and "false"
+    Bool,
+    Enum(&'static [&'static str]),
+    Integer { min: i64, max: i64 },
+    Text { max_length: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagSchema {
+    pub key: &'static str,
+    pub value_type: TagValueType,
+}
+
+// Keys follow the OpenStreetMap conventions so imported data keeps its meaning
+pub const TAG_SCHEMAS: &[TagSchema] = &[
+    TagSchema {
+        key: "wheelchair",
+        value_type: TagValueType::Enum(&["yes", "limited", "no"]),
+    },
+    TagSchema {
+        key: "wifi",
+        value_type: TagValueType::Bool,
+    },
+    TagSchema {
+        key: "payment:pix",
+        value_type: TagValueType::Bool,
+    },
+    TagSchema {
+        key: "payment:cash",
+        value_type: TagValueType::Bool,
+    },
+    TagSchema {
+        key: "payment:credit_card",
+        value_type: TagValueType::Bool,
+    },
+    TagSchema {
+        key: "payment:debit_card",
+        value_type: TagValueType::Bool,
+    },
+    TagSchema {
+        key: "parking",
+        value_type: TagValueType::Enum(&["no", "surface", "street", "underground", "multi-storey"]),
+    },
+    TagSchema {
+        key: "parking:capacity",
+        value_type: TagValueType::Integer {
+            min: 0,
+            max: 100_000,
+        },
+    },
+    TagSchema {
+        key: "toilets",
+        value_type: TagValueType::Bool,
+    },
+    TagSchema {
+        key: "delivery",
+        value_type: TagValueType::Bool,
+    },
+    TagSchema {
+        key: "brand",
+        value_type: TagValueType::Text { max_length: 120 },
+    },
+];
+
+pub fn tag_schema(key: &str) -> Option<&'static TagSchema> {
+    TAG_SCHEMAS.iter().find(|schema| schema.key == key)
+}
+
+impl TagValueType {
+    // Canonical form of the value, None when it does not fit the type
+    fn normalize(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        match self {
+            TagValueType::Bool => match value.to_lowercase().as_str() {
+                "yes" | "true" => Some("yes".to_string()),
+                "no" | "false" => Some("no".to_string()),
+                _ => None,
+            },
+            TagValueType::Enum(options) => {
+                let value = value.to_lowercase();
+                options.contains(&value.as_str()).then_some(value)
+            }
+            TagValueType::Integer { min, max } => value
+                .parse::<i64>()
+                .ok()
+                .filter(|number| (*min..=*max).contains(number))
+                .map(|number| number.to_string()),
+            TagValueType::Text { max_length } => (!value.is_empty()
+                && value.chars().count() <= *max_length)
+                .then(|| value.to_string()),
+        }
+    }
+}
+
+// Tags validated against the schema registry. Stored entries that predate it are carried along
+// untouched so writing the place back does not lose them, they are never shown or filtered on
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tags {
+    tags: BTreeMap<String, String>,
+    unrecognized: BTreeMap<String, String>,
+}
+
+impl Tags {
+    pub fn new(tags: HashMap<String, String>) -> ResultApp<Self> {
+        let mut normalized = BTreeMap::new();
+        for (key, value) in tags {
+            let key = key.trim().to_lowercase();
+            let Some(schema) = tag_schema(&key) else {
+                return Err(Arc::new(AppError::Validation(
+                    ErrorData::new("unknown-tag", "tag is not part of the tag schema")
+                        .with_args(HashMap::from([("tag".to_string(), key)])),
+                )));
+            };
+            let Some(value) = schema.value_type.normalize(&value) else {
+                return Err(Arc::new(AppError::Validation(
+                    ErrorData::new("invalid-tag-value", "tag value does not match its type")
+                        .with_args(HashMap::from([
+                            ("tag".to_string(), key),
+                            ("value".to_string(), value),
+                        ])),
+                )));
+            };
+            normalized.insert(key, value);
+        }
+        Ok(Tags {
+            tags: normalized,
+            unrecognized: BTreeMap::new(),
+        })
+    }
+
+    // Stored tags that no longer fit the registry are set aside instead of failing the read
+    pub fn new_lenient(tags: HashMap<String, String>) -> Self {
+        let mut lenient = Tags::default();
+        for (key, value) in tags {
+            match tag_schema(&key).and_then(|schema| schema.value_type.normalize(&value)) {
+                Some(normalized) => lenient.tags.insert(key, normalized),
+                None => lenient.unrecognized.insert(key, value),
+            };
+        }
+        lenient
+    }
+
+    // Replacing the tags of a place keeps the stored entries the new ones do not override
+    pub fn keeping_unrecognized(mut self, persisted: &Tags) -> Self {
+        self.unrecognized = persisted
+            .unrecognized
+            .iter()
+            .filter(|(key, _)| !self.tags.contains_key(*key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    pub fn value(&self) -> BTreeMap<String, String> {
+        self.tags.clone()
+    }
+
+    // Everything to write back, the entries set aside on read included
+    pub fn stored(&self) -> BTreeMap<String, String> {
+        let mut stored = self.unrecognized.clone();
+        stored.extend(self.tags.clone());
+        stored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(entries: &[(&str, &str)]) -> ResultApp<Tags> {
+        Tags::new(
+            entries
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn normalizes_values_to_their_canonical_form() {
+        let tags = tags(&[
+            ("Wheelchair", "Limited"),
+            ("payment:pix", "true"),
+            ("parking:capacity", " 040 "),
+            ("brand", " Drogasil "),
+        ])
+        .unwrap();
+        assert_eq!(
+            tags.value(),
+            BTreeMap::from([
+                ("brand".to_string(), "Drogasil".to_string()),
+                ("parking:capacity".to_string(), "40".to_string()),
+                ("payment:pix".to_string(), "yes".to_string()),
+                ("wheelchair".to_string(), "limited".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_mistyped_values() {
+        assert!(tags(&[("colour", "blue")]).is_err());
+        assert!(tags(&[("wifi", "maybe")]).is_err());
+        assert!(tags(&[("wheelchair", "partial")]).is_err());
+        assert!(tags(&[("parking:capacity", "-1")]).is_err());
+        assert!(tags(&[("parking:capacity", "many")]).is_err());
+        assert!(tags(&[("brand", " ")]).is_err());
+    }
+
+    #[test]
+    fn lenient_construction_sets_invalid_entries_aside() {
+        let tags = Tags::new_lenient(HashMap::from([
+            ("wifi".to_string(), "yes".to_string()),
+            ("colour".to_string(), "blue".to_string()),
+            ("parking".to_string(), "huge".to_string()),
+        ]));
+        assert_eq!(
+            tags.value(),
+            BTreeMap::from([("wifi".to_string(), "yes".to_string())])
+        );
+        assert_eq!(
+            tags.stored(),
+            BTreeMap::from([
+                ("colour".to_string(), "blue".to_string()),
+                ("parking".to_string(), "huge".to_string()),
+                ("wifi".to_string(), "yes".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn replaced_tags_keep_the_entries_set_aside() {
+        let persisted = Tags::new_lenient(HashMap::from([
+            ("wifi".to_string(), "yes".to_string()),
+            ("colour".to_string(), "blue".to_string()),
+            ("parking".to_string(), "huge".to_string()),
+        ]));
+        let replaced = tags(&[("parking", "street")])
+            .unwrap()
+            .keeping_unrecognized(&persisted);
+        assert_eq!(
+            replaced.stored(),
+            BTreeMap::from([
+                ("colour".to_string(), "blue".to_string()),
+                ("parking".to_string(), "street".to_string()),
+            ])
+        );
+    }
+}
@@ -1,27 +1,122 @@
 use crate::common::error::{AppError, ErrorData};
 use crate::common::result::ResultApp;
 use regex::Regex;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 const TAX_ID_BRAZIL_CPF_LENGTH: usize = 11;
+const TAX_ID_BRAZIL_CNPJ_LENGTH: usize = 14;
+const TAX_ID_ARGENTINA_CUIT_LENGTH: usize = 11;
+const TAX_ID_NINE_DIGITS_LENGTH: usize = 9;
+const CPF_FIRST_CHECK_WEIGHTS: [u32; 9] = [10, 9, 8, 7, 6, 5, 4, 3, 2];
+const CPF_SECOND_CHECK_WEIGHTS: [u32; 10] = [11, 10, 9, 8, 7, 6, 5, 4, 3, 2];
+const CNPJ_FIRST_CHECK_WEIGHTS: [u32; 12] = [5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+const CNPJ_SECOND_CHECK_WEIGHTS: [u32; 13] = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+const CUIT_CHECK_WEIGHTS: [u32; 10] = [5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+const NIF_CHECK_WEIGHTS: [u32; 8] = [9, 8, 7, 6, 5, 4, 3, 2];
+// Person and company type prefixes issued by AFIP
+const CUIT_PREFIXES: [&str; 9] = ["20", "23", "24", "25", "26", "27", "30", "33", "34"];
+// Prefixes the IRS never assigned to a campus
+const EIN_UNASSIGNED_PREFIXES: [&str; 17] = [
+    "00", "07", "08", "09", "17", "18", "19", "28", "29", "49", "69", "70", "78", "79", "89", "96",
+    "97",
+];
 
 pub trait Document {
     fn validate(&self) -> ResultApp<()>;
     fn format(&self) -> String;
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxCountry {
+    BR,
+    AR,
+    PT,
+    US,
+}
+
+impl TaxCountry {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        match s.as_ref().trim().to_ascii_uppercase().as_str() {
+            "BR" => Ok(TaxCountry::BR),
+            "AR" => Ok(TaxCountry::AR),
+            "PT" => Ok(TaxCountry::PT),
+            "US" => Ok(TaxCountry::US),
+            _ => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-tax-country", "Invalid tax id country").with_args(
+                    HashMap::from([("country".to_string(), s.as_ref().to_string())]),
+                ),
+            ))),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            TaxCountry::BR => "BR",
+            TaxCountry::AR => "AR",
+            TaxCountry::PT => "PT",
+            TaxCountry::US => "US",
+        }
+        .to_string()
+    }
+}
+
+// Every variant holds the normalized value, without mask characters
 #[derive(Debug, Clone)]
 pub enum TaxId {
     BrazilCpf(String),
     BrazilCnpj(String),
+    ArgentinaCuit(String),
+    PortugalNif(String),
+    UnitedStatesEin(String),
+    UnitedStatesSsn(String),
 }
 
 impl TaxId {
-    pub fn new(value: String) -> Self {
-        if value.len() == TAX_ID_BRAZIL_CPF_LENGTH {
-            TaxId::BrazilCpf(value)
-        } else {
-            TaxId::BrazilCnpj(value)
+    // Detects the country from the mask or the length, masked input such as `123.456.789-09`
+    // is accepted. Fails with `ambiguous-tax-id` when more than one kind of document fits
+    pub fn new<S: AsRef<str>>(value: S) -> ResultApp<Self> {
+        resolve(value.as_ref(), None)
+    }
+
+    pub fn new_for_country<S: AsRef<str>>(country: TaxCountry, value: S) -> ResultApp<Self> {
+        resolve(value.as_ref(), Some(country))
+    }
+
+    // Rebuilds a persisted tax id from the `kind()` it was stored with
+    pub fn new_from_kind<S: AsRef<str>>(kind: S, value: String) -> ResultApp<Self> {
+        match kind.as_ref() {
+            "brazil-cpf" => Ok(TaxId::BrazilCpf(value)),
+            "brazil-cnpj" => Ok(TaxId::BrazilCnpj(value)),
+            "argentina-cuit" => Ok(TaxId::ArgentinaCuit(value)),
+            "portugal-nif" => Ok(TaxId::PortugalNif(value)),
+            "united-states-ein" => Ok(TaxId::UnitedStatesEin(value)),
+            "united-states-ssn" => Ok(TaxId::UnitedStatesSsn(value)),
+            other => Err(Arc::new(AppError::Validation(
+                ErrorData::new("invalid-tax-id-kind", "Invalid tax id kind")
+                    .with_args(HashMap::from([("kind".to_string(), other.to_string())])),
+            ))),
+        }
+    }
+
+    pub fn kind(&self) -> String {
+        match self {
+            TaxId::BrazilCpf(_) => "brazil-cpf",
+            TaxId::BrazilCnpj(_) => "brazil-cnpj",
+            TaxId::ArgentinaCuit(_) => "argentina-cuit",
+            TaxId::PortugalNif(_) => "portugal-nif",
+            TaxId::UnitedStatesEin(_) => "united-states-ein",
+            TaxId::UnitedStatesSsn(_) => "united-states-ssn",
+        }
+        .to_string()
+    }
+
+    pub fn country(&self) -> TaxCountry {
+        match self {
+            TaxId::BrazilCpf(_) | TaxId::BrazilCnpj(_) => TaxCountry::BR,
+            TaxId::ArgentinaCuit(_) => TaxCountry::AR,
+            TaxId::PortugalNif(_) => TaxCountry::PT,
+            TaxId::UnitedStatesEin(_) | TaxId::UnitedStatesSsn(_) => TaxCountry::US,
         }
     }
 
@@ -33,44 +128,418 @@ impl TaxId {
                 Ok(cpf.to_string())
             }
             TaxId::BrazilCnpj(s) => {
-                let re = Regex::new(r"^(\d{2})(\d{3})(\d{3})(\d{4})(\d{2})$").unwrap();
+                let re =
+                    Regex::new(r"^([0-9A-Z]{2})([0-9A-Z]{3})([0-9A-Z]{3})([0-9A-Z]{4})(\d{2})$")
+                        .unwrap();
                 let cnpj = re.replace_all(s, "$1.$2.$3/$4-$5");
                 Ok(cnpj.to_string())
             }
+            TaxId::ArgentinaCuit(s) => {
+                let re = Regex::new(r"^(\d{2})(\d{8})(\d)$").unwrap();
+                let cuit = re.replace_all(s, "$1-$2-$3");
+                Ok(cuit.to_string())
+            }
+            TaxId::PortugalNif(s) => {
+                let re = Regex::new(r"^(\d{3})(\d{3})(\d{3})$").unwrap();
+                let nif = re.replace_all(s, "$1 $2 $3");
+                Ok(nif.to_string())
+            }
+            TaxId::UnitedStatesEin(s) => {
+                let re = Regex::new(r"^(\d{2})(\d{7})$").unwrap();
+                let ein = re.replace_all(s, "$1-$2");
+                Ok(ein.to_string())
+            }
+            TaxId::UnitedStatesSsn(s) => {
+                let re = Regex::new(r"^(\d{3})(\d{2})(\d{4})$").unwrap();
+                let ssn = re.replace_all(s, "$1-$2-$3");
+                Ok(ssn.to_string())
+            }
         }
     }
+
+    // Display form with the leading block and the check digits hidden, safe to write to logs
+    pub fn masked(&self) -> String {
+        let (hidden_prefix, hidden_suffix) = match self {
+            TaxId::BrazilCpf(_) => (3, 2),
+            TaxId::BrazilCnpj(_) => (5, 2),
+            TaxId::ArgentinaCuit(_) => (5, 1),
+            TaxId::PortugalNif(_) => (3, 3),
+            // US numbers are conventionally shown by their last four digits
+            TaxId::UnitedStatesEin(_) | TaxId::UnitedStatesSsn(_) => (5, 0),
+        };
+        let display = self.format().unwrap_or_else(|_| Document::format(self));
+        let length = display.chars().filter(char::is_ascii_alphanumeric).count();
+
+        let mut position = 0;
+        display
+            .chars()
+            .map(|c| {
+                if !c.is_ascii_alphanumeric() {
+                    return c;
+                }
+                let hidden = position < hidden_prefix || position + hidden_suffix >= length;
+                position += 1;
+                if hidden { '*' } else { c }
+            })
+            .collect()
+    }
 }
 
 impl Document for TaxId {
     fn format(&self) -> String {
         match &self {
-            TaxId::BrazilCpf(cpf) => cpf.to_string(),
-            TaxId::BrazilCnpj(cnpj) => cnpj.to_string(),
+            TaxId::BrazilCpf(value)
+            | TaxId::BrazilCnpj(value)
+            | TaxId::ArgentinaCuit(value)
+            | TaxId::PortugalNif(value)
+            | TaxId::UnitedStatesEin(value)
+            | TaxId::UnitedStatesSsn(value) => value.to_string(),
         }
     }
 
     fn validate(&self) -> ResultApp<()> {
-        match &self {
-            TaxId::BrazilCpf(cpf) => {
-                let re = Regex::new(r"^(\d{3})(\d{3})(\d{3})(\d{2})$").unwrap();
-                if re.is_match(cpf) {
-                    return Ok(());
-                }
-                Err(Arc::new(AppError::Validation(ErrorData::new(
-                    "invalid-brazilian-cpf",
-                    "Invalid Brazilian CPF",
-                ))))
-            }
-            TaxId::BrazilCnpj(cnpj) => {
-                let re = Regex::new(r"^(\d{2})(\d{3})(\d{3})(\d{4})(\d{2})$").unwrap();
-                if re.is_match(cnpj) {
-                    return Ok(());
-                }
-                Err(Arc::new(AppError::Validation(ErrorData::new(
-                    "invalid-brazilian-cnpj",
-                    "Invalid Brazilian CNPJ",
-                ))))
-            }
+        let (valid, code, message) = match &self {
+            TaxId::BrazilCpf(cpf) => (
+                Regex::new(r"^\d{11}$").unwrap().is_match(cpf)
+                    && !is_repeated_sequence(cpf)
+                    && cpf_check_digits_match(cpf),
+                "invalid-brazilian-cpf",
+                "Invalid Brazilian CPF",
+            ),
+            // Letters are allowed in the root and branch, the check digits stay numeric
+            TaxId::BrazilCnpj(cnpj) => (
+                Regex::new(r"^[0-9A-Z]{12}\d{2}$").unwrap().is_match(cnpj)
+                    && !is_repeated_sequence(cnpj)
+                    && cnpj_check_digits_match(cnpj),
+                "invalid-brazilian-cnpj",
+                "Invalid Brazilian CNPJ",
+            ),
+            TaxId::ArgentinaCuit(cuit) => (
+                Regex::new(r"^\d{11}$").unwrap().is_match(cuit)
+                    && CUIT_PREFIXES.contains(&&cuit[..2])
+                    && cuit_check_digit_matches(cuit),
+                "invalid-argentine-cuit",
+                "Invalid Argentine CUIT",
+            ),
+            // 4 and 7 only lead the NIFs of a few public and collective bodies
+            TaxId::PortugalNif(nif) => (
+                Regex::new(r"^([1235689]\d|45|7\d)\d{7}$")
+                    .unwrap()
+                    .is_match(nif)
+                    && nif_check_digit_matches(nif),
+                "invalid-portuguese-nif",
+                "Invalid Portuguese NIF",
+            ),
+            // EINs carry no check digit, only the campus prefix can be verified
+            TaxId::UnitedStatesEin(ein) => (
+                Regex::new(r"^\d{9}$").unwrap().is_match(ein)
+                    && !EIN_UNASSIGNED_PREFIXES.contains(&&ein[..2]),
+                "invalid-us-ein",
+                "Invalid US EIN",
+            ),
+            TaxId::UnitedStatesSsn(ssn) => (
+                Regex::new(r"^\d{9}$").unwrap().is_match(ssn) && ssn_blocks_are_assignable(ssn),
+                "invalid-us-ssn",
+                "Invalid US SSN",
+            ),
+        };
+        if valid {
+            return Ok(());
         }
+        Err(Arc::new(AppError::Validation(ErrorData::new(
+            code, message,
+        ))))
+    }
+}
+
+fn resolve(raw: &str, country: Option<TaxCountry>) -> ResultApp<TaxId> {
+    let normalized = normalize(raw);
+    let candidates: Vec<TaxId> = candidates(&normalized)
+        .into_iter()
+        .filter(|tax_id| country.is_none_or(|country| tax_id.country() == country))
+        .collect();
+
+    // A mask matching the display form of a single kind settles the choice
+    let trimmed = raw.trim().to_ascii_uppercase();
+    let masked: Vec<TaxId> = candidates
+        .iter()
+        .filter(|tax_id| tax_id.format().is_ok_and(|display| display == trimmed))
+        .cloned()
+        .collect();
+    let mut candidates = if trimmed != normalized && !masked.is_empty() {
+        masked
+    } else {
+        candidates
+    };
+
+    // With a single candidate its own error code tells the caller what is wrong
+    if candidates.len() == 1 {
+        let tax_id = candidates.remove(0);
+        tax_id.validate()?;
+        return Ok(tax_id);
+    }
+
+    let mut valid: Vec<TaxId> = candidates
+        .into_iter()
+        .filter(|tax_id| tax_id.validate().is_ok())
+        .collect();
+    match valid.len() {
+        1 => Ok(valid.remove(0)),
+        0 => Err(Arc::new(AppError::Validation(
+            ErrorData::new("invalid-tax-id", "Invalid tax id")
+                .with_args(HashMap::from([("tax_id".to_string(), raw.to_string())])),
+        ))),
+        _ => Err(Arc::new(AppError::Validation(
+            ErrorData::new(
+                "ambiguous-tax-id",
+                "Tax id fits more than one kind of document, the country must be given",
+            )
+            .with_args(HashMap::from([("tax_id".to_string(), raw.to_string())])),
+        ))),
+    }
+}
+
+// Every kind of document with the length of the normalized value
+fn candidates(normalized: &str) -> Vec<TaxId> {
+    let value = normalized.to_string();
+    let mut candidates = vec![];
+    if value.len() == TAX_ID_BRAZIL_CPF_LENGTH {
+        candidates.push(TaxId::BrazilCpf(value.clone()));
+    }
+    if value.len() == TAX_ID_BRAZIL_CNPJ_LENGTH {
+        candidates.push(TaxId::BrazilCnpj(value.clone()));
+    }
+    if value.len() == TAX_ID_ARGENTINA_CUIT_LENGTH {
+        candidates.push(TaxId::ArgentinaCuit(value.clone()));
+    }
+    if value.len() == TAX_ID_NINE_DIGITS_LENGTH {
+        candidates.push(TaxId::PortugalNif(value.clone()));
+        candidates.push(TaxId::UnitedStatesEin(value.clone()));
+        candidates.push(TaxId::UnitedStatesSsn(value));
+    }
+    candidates
+}
+
+// Drops the mask characters and whitespace, letters are upper cased for the alphanumeric CNPJ
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, '.' | '-' | '/') && !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+// `00000000000`, `11111111111` and the like pass the check digits but are never issued
+fn is_repeated_sequence(value: &str) -> bool {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => chars.all(|c| c == first),
+        None => false,
+    }
+}
+
+fn weighted_remainder(values: &[u32], weights: &[u32]) -> u32 {
+    values
+        .iter()
+        .zip(weights)
+        .map(|(value, weight)| value * weight)
+        .sum::<u32>()
+        % 11
+}
+
+// Mod 11 over the weighted values, remainders under two map to zero
+fn check_digit(values: &[u32], weights: &[u32]) -> u32 {
+    let remainder = weighted_remainder(values, weights);
+    if remainder < 2 { 0 } else { 11 - remainder }
+}
+
+fn digits(value: &str) -> Vec<u32> {
+    value.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+fn cpf_check_digits_match(cpf: &str) -> bool {
+    let digits = digits(cpf);
+    if digits.len() != TAX_ID_BRAZIL_CPF_LENGTH {
+        return false;
+    }
+    check_digit(&digits[..9], &CPF_FIRST_CHECK_WEIGHTS) == digits[9]
+        && check_digit(&digits[..10], &CPF_SECOND_CHECK_WEIGHTS) == digits[10]
+}
+
+// Each character is worth its ASCII code minus 48, so digits keep their value and `A` is 17
+fn cnpj_check_digits_match(cnpj: &str) -> bool {
+    let values: Vec<u32> = cnpj
+        .chars()
+        .filter(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+        .map(|c| c as u32 - '0' as u32)
+        .collect();
+    if values.len() != TAX_ID_BRAZIL_CNPJ_LENGTH {
+        return false;
+    }
+    check_digit(&values[..12], &CNPJ_FIRST_CHECK_WEIGHTS) == values[12]
+        && check_digit(&values[..13], &CNPJ_SECOND_CHECK_WEIGHTS) == values[13]
+}
+
+// A remainder of one would need a check digit of ten, AFIP changes the prefix instead
+fn cuit_check_digit_matches(cuit: &str) -> bool {
+    let digits = digits(cuit);
+    if digits.len() != TAX_ID_ARGENTINA_CUIT_LENGTH {
+        return false;
+    }
+    match weighted_remainder(&digits[..10], &CUIT_CHECK_WEIGHTS) {
+        0 => digits[10] == 0,
+        1 => false,
+        remainder => digits[10] == 11 - remainder,
+    }
+}
+
+fn nif_check_digit_matches(nif: &str) -> bool {
+    let digits = digits(nif);
+    if digits.len() != TAX_ID_NINE_DIGITS_LENGTH {
+        return false;
+    }
+    check_digit(&digits[..8], &NIF_CHECK_WEIGHTS) == digits[8]
+}
+
+// Area 000, 666 and 900-999, group 00 and serial 0000 are never assigned
+fn ssn_blocks_are_assignable(ssn: &str) -> bool {
+    let (area, group, serial) = (&ssn[..3], &ssn[3..5], &ssn[5..]);
+    area != "000" && area != "666" && !area.starts_with('9') && group != "00" && serial != "0000"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::error_code;
+
+    #[test]
+    fn formatted_input_is_normalized() {
+        let cpf = TaxId::new("529.982.247-25").unwrap();
+        assert!(matches!(&cpf, TaxId::BrazilCpf(s) if s == "52998224725"));
+        assert_eq!(cpf.format().unwrap(), "529.982.247-25");
+
+        let cnpj = TaxId::new(" 11.222.333/0001-81 ").unwrap();
+        assert!(matches!(&cnpj, TaxId::BrazilCnpj(s) if s == "11222333000181"));
+    }
+
+    #[test]
+    fn cpf_check_digits_are_verified() {
+        assert!(TaxId::new("52998224725").is_ok());
+        assert!(TaxId::new("12345678909").is_ok());
+        assert!(TaxId::new_for_country(TaxCountry::BR, "52998224724").is_err());
+        assert_eq!(
+            error_code(TaxId::new_for_country(TaxCountry::BR, "123.456.789-00")),
+            "invalid-brazilian-cpf"
+        );
+    }
+
+    #[test]
+    fn repeated_sequences_are_rejected() {
+        assert!(TaxId::new("111.111.111-11").is_err());
+        assert!(TaxId::new("00000000000").is_err());
+        assert!(TaxId::new("00000000000000").is_err());
+        assert!(
+            TaxId::BrazilCpf("99999999999".to_string())
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn cnpj_with_valid_check_digits_is_accepted() {
+        assert!(TaxId::new("11222333000181").is_ok());
+        assert!(TaxId::new("11.444.777/0001-61").is_ok());
+        assert!(TaxId::new("11222333000182").is_err());
+        assert!(TaxId::new("11222333000191").is_err());
+    }
+
+    #[test]
+    fn alphanumeric_cnpj_is_accepted() {
+        let cnpj = TaxId::new("12.abc.345/01de-35").unwrap();
+        assert!(matches!(&cnpj, TaxId::BrazilCnpj(s) if s == "12ABC34501DE35"));
+        assert_eq!(cnpj.format().unwrap(), "12.ABC.345/01DE-35");
+        assert!(TaxId::new("12ABC34501DE36").is_err());
+        // Letters are never allowed in the check digits
+        assert!(
+            TaxId::BrazilCnpj("12ABC34501DEA5".to_string())
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn unknown_length_is_rejected() {
+        assert!(TaxId::new("1234567890").is_err());
+        assert!(TaxId::new("").is_err());
+    }
+
+    #[test]
+    fn argentine_cuit_is_validated() {
+        let cuit = TaxId::new("20-17254359-7").unwrap();
+        assert!(matches!(&cuit, TaxId::ArgentinaCuit(s) if s == "20172543597"));
+        assert_eq!(cuit.format().unwrap(), "20-17254359-7");
+        assert!(TaxId::new_for_country(TaxCountry::AR, "30500010912").is_ok());
+        assert_eq!(
+            error_code(TaxId::new_for_country(TaxCountry::AR, "20172543598")),
+            "invalid-argentine-cuit"
+        );
+        // Unknown person or company type
+        assert!(TaxId::new_for_country(TaxCountry::AR, "10172543597").is_err());
+    }
+
+    #[test]
+    fn portuguese_nif_is_validated() {
+        let nif = TaxId::new("123 456 789").unwrap();
+        assert!(matches!(&nif, TaxId::PortugalNif(s) if s == "123456789"));
+        assert_eq!(
+            error_code(TaxId::new_for_country(TaxCountry::PT, "123456788")),
+            "invalid-portuguese-nif"
+        );
+        assert!(TaxId::new_for_country(TaxCountry::PT, "412345678").is_err());
+    }
+
+    #[test]
+    fn us_ein_and_ssn_are_told_apart_by_mask() {
+        let ein = TaxId::new("12-3456789").unwrap();
+        assert!(matches!(&ein, TaxId::UnitedStatesEin(s) if s == "123456789"));
+        let ssn = TaxId::new("123-45-6789").unwrap();
+        assert!(matches!(&ssn, TaxId::UnitedStatesSsn(s) if s == "123456789"));
+
+        assert_eq!(
+            error_code(TaxId::new_for_country(TaxCountry::US, "07-3456789")),
+            "invalid-us-ein"
+        );
+        assert_eq!(
+            error_code(TaxId::new_for_country(TaxCountry::US, "666-45-6789")),
+            "invalid-us-ssn"
+        );
+    }
+
+    #[test]
+    fn ambiguous_input_requires_a_country() {
+        // Nine plain digits are a valid NIF, EIN and SSN at once
+        assert_eq!(error_code(TaxId::new("123456789")), "ambiguous-tax-id");
+        assert!(TaxId::new_for_country(TaxCountry::PT, "123456789").is_ok());
+        assert_eq!(
+            error_code(TaxId::new_for_country(TaxCountry::US, "123456789")),
+            "ambiguous-tax-id"
+        );
+    }
+
+    #[test]
+    fn masked_form_hides_leading_block_and_check_digits() {
+        assert_eq!(
+            TaxId::new("123.456.789-09").unwrap().masked(),
+            "***.456.789-**"
+        );
+        assert_eq!(
+            TaxId::new("11.222.333/0001-81").unwrap().masked(),
+            "**.***.333/0001-**"
+        );
+        assert_eq!(
+            TaxId::new("20-17254359-7").unwrap().masked(),
+            "**-***54359-*"
+        );
+        assert_eq!(TaxId::new("123-45-6789").unwrap().masked(), "***-**-6789");
     }
 }
@@ -35,9 +35,22 @@ impl Date {
         Self(chrono::Utc::now().naive_utc().date())
     }
 
+    pub fn new_from_naive_date(date: chrono::NaiveDate) -> Self {
+        Self(date)
+    }
+
     pub fn value(&self) -> String {
         self.0.format("%Y-%m-%d").to_string()
     }
+
+    pub fn to_naive_date(&self) -> chrono::NaiveDate {
+        self.0
+    }
+
+    // Whole years elapsed up to `later`, None when `later` comes first
+    pub fn years_until(&self, later: &Date) -> Option<u32> {
+        later.0.years_since(self.0)
+    }
 }
 
 #[cfg(test)]
@@ -69,4 +82,15 @@ mod tests {
             s.len()
         );
     }
+
+    #[test]
+    fn years_until_counts_whole_years() {
+        let birth =
+            Date::new_from_naive_date(chrono::NaiveDate::from_ymd_opt(2000, 2, 29).unwrap());
+        let eve = Date::new_from_naive_date(chrono::NaiveDate::from_ymd_opt(2018, 2, 28).unwrap());
+        let day = Date::new_from_naive_date(chrono::NaiveDate::from_ymd_opt(2018, 3, 1).unwrap());
+        assert_eq!(birth.years_until(&eve), Some(17));
+        assert_eq!(birth.years_until(&day), Some(18));
+        assert_eq!(day.years_until(&birth), None);
+    }
 }
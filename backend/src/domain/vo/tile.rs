@@ -0,0 +1,110 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::vo::geopoint::{BoundingBox, GeoPoint};
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+pub const MAX_TILE_ZOOM: u8 = 22;
+
+// Web Mercator stops here, beyond it the projection goes to infinity
+const MAX_MERCATOR_LATITUDE: f64 = 85.051_128_779_806_59;
+
+// XYZ (slippy map) tile, y grows southwards from the top-left corner of the world
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    pub fn new(z: u8, x: u32, y: u32) -> ResultApp<Self> {
+        if z > MAX_TILE_ZOOM || x >= 1 << z || y >= 1 << z {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-tile",
+                "Invalid tile coordinates",
+            ))));
+        }
+        Ok(Tile { z, x, y })
+    }
+
+    fn tiles_per_side(&self) -> f64 {
+        (1u64 << self.z) as f64
+    }
+
+    // Area covered by the tile grown by `buffer` tile widths on every side, so symbols on the
+    // edge are drawn by both neighbours
+    pub fn bounding_box(&self, buffer: f64) -> BoundingBox {
+        let n = self.tiles_per_side();
+        let lon_at = |x: f64| (x / n * 360.0 - 180.0).clamp(-180.0, 180.0);
+        let lat_at = |y: f64| {
+            (PI * (1.0 - 2.0 * y / n))
+                .sinh()
+                .atan()
+                .to_degrees()
+                .clamp(-90.0, 90.0)
+        };
+
+        BoundingBox {
+            min_lat: lat_at(self.y as f64 + 1.0 + buffer),
+            min_lon: lon_at(self.x as f64 - buffer),
+            max_lat: lat_at(self.y as f64 - buffer),
+            max_lon: lon_at(self.x as f64 + 1.0 + buffer),
+        }
+    }
+
+    // Tile-local integer coordinates on an `extent` wide grid, origin at the top-left corner
+    pub fn project(&self, point: &GeoPoint, extent: u32) -> (i32, i32) {
+        let n = self.tiles_per_side();
+        let lat = point
+            .lat
+            .clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE)
+            .to_radians();
+        let world_x = (point.lon + 180.0) / 360.0 * n;
+        let world_y = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / PI) / 2.0 * n;
+
+        let extent = extent as f64;
+        (
+            ((world_x - self.x as f64) * extent).round() as i32,
+            ((world_y - self.y as f64) * extent).round() as i32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_coordinates_outside_the_zoom_level() {
+        assert!(Tile::new(0, 0, 0).is_ok());
+        assert!(Tile::new(1, 2, 0).is_err());
+        assert!(Tile::new(23, 0, 0).is_err());
+    }
+
+    #[test]
+    fn world_tile_covers_the_mercator_square() {
+        let bbox = Tile::new(0, 0, 0).unwrap().bounding_box(0.0);
+        assert_eq!(bbox.min_lon, -180.0);
+        assert_eq!(bbox.max_lon, 180.0);
+        assert!((bbox.max_lat - MAX_MERCATOR_LATITUDE).abs() < 1e-9);
+    }
+
+    #[test]
+    fn projects_into_tile_local_coordinates() {
+        let sao_paulo = GeoPoint::new(-23.5505, -46.6333).unwrap();
+        let tile = Tile::new(10, 379, 580).unwrap();
+        let bbox = tile.bounding_box(0.0);
+        assert!(bbox.min_lat <= sao_paulo.lat && sao_paulo.lat <= bbox.max_lat);
+        assert!(bbox.min_lon <= sao_paulo.lon && sao_paulo.lon <= bbox.max_lon);
+
+        let (x, y) = tile.project(&sao_paulo, 4096);
+        assert!((0..4096).contains(&x) && (0..4096).contains(&y));
+
+        let world = Tile::new(0, 0, 0).unwrap();
+        assert_eq!(
+            world.project(&GeoPoint::new(0.0, 0.0).unwrap(), 4096),
+            (2048, 2048)
+        );
+    }
+}
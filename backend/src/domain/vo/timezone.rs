@@ -0,0 +1,54 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// IANA time zone name, places default to Brasília time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timezone(Tz);
+
+impl Default for Timezone {
+    fn default() -> Self {
+        Self(chrono_tz::America::Sao_Paulo)
+    }
+}
+
+impl Timezone {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
+        let s = s.as_ref().trim();
+        match s.parse::<Tz>() {
+            Ok(tz) => Ok(Timezone(tz)),
+            Err(_) => Err(Arc::new(AppError::Validation(
+                ErrorData::new(
+                    "invalid-timezone",
+                    "timezone must be an IANA time zone name",
+                )
+                .with_args(HashMap::from([("timezone".to_string(), s.to_string())])),
+            ))),
+        }
+    }
+
+    pub fn tz(&self) -> Tz {
+        self.0
+    }
+
+    pub fn value(&self) -> String {
+        self.0.name().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_iana_names_only() {
+        assert_eq!(
+            Timezone::new(" America/Sao_Paulo ").unwrap().value(),
+            Timezone::default().value()
+        );
+        assert!(Timezone::new("Brasilia").is_err());
+        assert!(Timezone::new("").is_err());
+    }
+}
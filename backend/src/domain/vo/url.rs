@@ -1,11 +1,18 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use std::sync::Arc;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Url(String);
 
 impl Url {
-    pub fn new<S: AsRef<str>>(s: S) -> Result<Self, &'static str> {
+    pub fn new<S: AsRef<str>>(s: S) -> ResultApp<Self> {
         let s = s.as_ref().trim();
-        if !(s.starts_with("http://") || s.starts_with("https://")) {
-            return Err("url must start with http:// or https://");
+        if !(s.starts_with("http://") || s.starts_with("https://")) || s.len() > 2048 {
+            return Err(Arc::new(AppError::Validation(ErrorData::new(
+                "invalid-url",
+                "url must start with http:// or https://",
+            ))));
         }
         Ok(Url(s.to_owned()))
     }
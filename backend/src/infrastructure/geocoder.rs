@@ -0,0 +1,68 @@
+use crate::domain::vo::address::Address;
+use crate::domain::vo::gazetteer::{Gazetteer, GazetteerEntry, NearestLocality};
+use crate::domain::vo::geopoint::GeoPoint;
+use std::env;
+use std::fs;
+
+const DEFAULT_GAZETTEER_PATH: &str = "data/gazetteer.csv";
+
+pub struct GeocoderConfig {
+    pub(crate) gazetteer_path: String,
+}
+
+impl GeocoderConfig {
+    pub fn from_env() -> Self {
+        Self {
+            gazetteer_path: env::var("GAZETTEER_PATH")
+                .unwrap_or_else(|_| DEFAULT_GAZETTEER_PATH.to_string()),
+        }
+    }
+}
+
+// Turns addresses and free text into coordinates and back, without leaving the process
+pub trait Geocoder: Send + Sync {
+    fn geocode_address(&self, address: &Address) -> Option<GazetteerEntry>;
+    fn geocode(&self, text: &str) -> Option<GazetteerEntry>;
+    fn reverse_geocode(&self, point: &GeoPoint) -> Option<NearestLocality>;
+}
+
+pub struct GazetteerGeocoder {
+    gazetteer: Gazetteer,
+}
+
+impl GazetteerGeocoder {
+    // A missing or malformed file leaves the gazetteer empty, every lookup then finds nothing
+    pub fn new(geocoder_config: GeocoderConfig) -> Self {
+        let path = geocoder_config.gazetteer_path;
+        let gazetteer = match fs::read_to_string(&path) {
+            Ok(csv) => Gazetteer::parse(&csv).unwrap_or_else(|error| {
+                log::error!("gazetteer {} could not be parsed: {}", path, error);
+                Gazetteer::default()
+            }),
+            Err(error) => {
+                log::warn!("gazetteer {} could not be read: {}", path, error);
+                Gazetteer::default()
+            }
+        };
+        if gazetteer.is_empty() {
+            log::warn!("gazetteer is empty, places must be sent with coordinates");
+        } else {
+            log::info!("gazetteer loaded with {} entries", gazetteer.len());
+        }
+        Self { gazetteer }
+    }
+}
+
+impl Geocoder for GazetteerGeocoder {
+    fn geocode_address(&self, address: &Address) -> Option<GazetteerEntry> {
+        self.gazetteer.locate_address(address).cloned()
+    }
+
+    fn geocode(&self, text: &str) -> Option<GazetteerEntry> {
+        self.gazetteer.locate(text).cloned()
+    }
+
+    fn reverse_geocode(&self, point: &GeoPoint) -> Option<NearestLocality> {
+        self.gazetteer.nearest_locality(point)
+    }
+}
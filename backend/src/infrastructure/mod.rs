@@ -1 +1,4 @@
+pub mod geocoder;
+pub mod mvt;
 pub mod postgres;
+pub mod token;
@@ -0,0 +1,168 @@
+// Minimal Mapbox Vector Tile 2.1 encoder for point layers, written against
+// https://github.com/mapbox/vector-tile-spec/blob/master/2.1/vector_tile.proto
+use std::collections::HashMap;
+
+pub const DEFAULT_EXTENT: u32 = 4096;
+
+const MVT_VERSION: u64 = 2;
+const GEOM_TYPE_POINT: u64 = 1;
+const COMMAND_MOVE_TO: u32 = 1;
+
+const WIRE_VARINT: u32 = 0;
+const WIRE_LEN: u32 = 2;
+
+#[derive(Debug, Clone)]
+pub struct PointFeature {
+    pub x: i32,
+    pub y: i32,
+    pub properties: Vec<(String, String)>,
+}
+
+#[derive(Default)]
+struct ProtoWriter {
+    buffer: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buffer.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        self.buffer.push(value as u8);
+    }
+
+    fn key(&mut self, field: u32, wire_type: u32) {
+        self.varint(((field << 3) | wire_type) as u64);
+    }
+
+    fn uint_field(&mut self, field: u32, value: u64) {
+        self.key(field, WIRE_VARINT);
+        self.varint(value);
+    }
+
+    fn bytes_field(&mut self, field: u32, bytes: &[u8]) {
+        self.key(field, WIRE_LEN);
+        self.varint(bytes.len() as u64);
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    fn packed_field(&mut self, field: u32, values: &[u32]) {
+        let mut packed = ProtoWriter::default();
+        for value in values {
+            packed.varint(*value as u64);
+        }
+        self.bytes_field(field, &packed.buffer);
+    }
+}
+
+fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+fn command(id: u32, count: u32) -> u32 {
+    (id & 0x7) | (count << 3)
+}
+
+// Encodes a tile holding a single layer of point features with string properties
+pub fn encode_point_layer(name: &str, extent: u32, features: &[PointFeature]) -> Vec<u8> {
+    let mut keys: Vec<&str> = vec![];
+    let mut key_index: HashMap<&str, u32> = HashMap::new();
+    let mut values: Vec<&str> = vec![];
+    let mut value_index: HashMap<&str, u32> = HashMap::new();
+
+    let mut layer = ProtoWriter::default();
+    layer.bytes_field(1, name.as_bytes());
+
+    for feature in features {
+        let mut tags = Vec::with_capacity(feature.properties.len() * 2);
+        for (key, value) in &feature.properties {
+            let k = *key_index.entry(key.as_str()).or_insert_with(|| {
+                keys.push(key.as_str());
+                keys.len() as u32 - 1
+            });
+            let v = *value_index.entry(value.as_str()).or_insert_with(|| {
+                values.push(value.as_str());
+                values.len() as u32 - 1
+            });
+            tags.push(k);
+            tags.push(v);
+        }
+
+        let mut encoded_feature = ProtoWriter::default();
+        encoded_feature.packed_field(2, &tags);
+        encoded_feature.uint_field(3, GEOM_TYPE_POINT);
+        encoded_feature.packed_field(
+            4,
+            &[
+                command(COMMAND_MOVE_TO, 1),
+                zigzag(feature.x),
+                zigzag(feature.y),
+            ],
+        );
+        layer.bytes_field(2, &encoded_feature.buffer);
+    }
+
+    for key in keys {
+        layer.bytes_field(3, key.as_bytes());
+    }
+    for value in values {
+        let mut encoded_value = ProtoWriter::default();
+        encoded_value.bytes_field(1, value.as_bytes());
+        layer.bytes_field(4, &encoded_value.buffer);
+    }
+    layer.uint_field(5, extent as u64);
+    layer.uint_field(15, MVT_VERSION);
+
+    let mut tile = ProtoWriter::default();
+    tile.bytes_field(3, &layer.buffer);
+    tile.buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zigzag_interleaves_signed_values() {
+        assert_eq!(zigzag(0), 0);
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+        assert_eq!(zigzag(-2), 3);
+    }
+
+    #[test]
+    fn varints_use_seven_bits_per_byte() {
+        let mut writer = ProtoWriter::default();
+        writer.varint(300);
+        assert_eq!(writer.buffer, vec![0xac, 0x02]);
+    }
+
+    #[test]
+    fn encodes_a_single_point_layer() {
+        let tile = encode_point_layer(
+            "p",
+            4096,
+            &[PointFeature {
+                x: 25,
+                y: 17,
+                properties: vec![("id".to_string(), "a".to_string())],
+            }],
+        );
+
+        let layer = vec![
+            0x0a, 0x01, b'p', // name
+            0x12, 0x0b, // feature
+            0x12, 0x02, 0x00, 0x00, // tags
+            0x18, 0x01, // type point
+            0x22, 0x03, 0x09, 0x32, 0x22, // MoveTo(25, 17)
+            0x1a, 0x02, b'i', b'd', // keys
+            0x22, 0x03, 0x0a, 0x01, b'a', // values
+            0x28, 0x80, 0x20, // extent
+            0x78, 0x02, // version
+        ];
+        let mut expected = vec![0x1a, layer.len() as u8];
+        expected.extend(layer);
+        assert_eq!(tile, expected);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::principal::Principal;
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::sync::Arc;
+
+const DEFAULT_ACCESS_TOKEN_TTL_SECONDS: i64 = 15 * 60;
+const DEFAULT_REFRESH_TOKEN_TTL_SECONDS: i64 = 30 * 24 * 60 * 60;
+const OPAQUE_TOKEN_BYTES: usize = 32;
+
+pub struct TokenConfig {
+    pub(crate) jwt_secret: String,
+    pub(crate) access_token_ttl_seconds: i64,
+    pub(crate) refresh_token_ttl_seconds: i64,
+}
+
+impl TokenConfig {
+    pub fn from_env() -> Self {
+        Self {
+            jwt_secret: env::var("JWT_SECRET").unwrap(),
+            access_token_ttl_seconds: env::var("ACCESS_TOKEN_TTL_SECONDS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_ACCESS_TOKEN_TTL_SECONDS),
+            refresh_token_ttl_seconds: env::var("REFRESH_TOKEN_TTL_SECONDS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_REFRESH_TOKEN_TTL_SECONDS),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    pub sub: String,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+pub trait TokenService: Send + Sync {
+    fn issue_access_token(&self, principal: &Principal) -> ResultApp<String>;
+    fn validate_access_token(&self, token: &str) -> ResultApp<AccessTokenClaims>;
+    fn access_token_ttl_seconds(&self) -> i64;
+    fn refresh_token_ttl_seconds(&self) -> i64;
+}
+
+pub struct JwtTokenService {
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    access_token_ttl_seconds: i64,
+    refresh_token_ttl_seconds: i64,
+}
+
+impl JwtTokenService {
+    pub fn new(token_config: TokenConfig) -> Self {
+        Self {
+            encoding_key: EncodingKey::from_secret(token_config.jwt_secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(token_config.jwt_secret.as_bytes()),
+            access_token_ttl_seconds: token_config.access_token_ttl_seconds,
+            refresh_token_ttl_seconds: token_config.refresh_token_ttl_seconds,
+        }
+    }
+}
+
+impl TokenService for JwtTokenService {
+    fn issue_access_token(&self, principal: &Principal) -> ResultApp<String> {
+        let now = chrono::Utc::now().timestamp();
+        let claims = AccessTokenClaims {
+            sub: principal.user_id.value(),
+            roles: principal.roles.iter().map(|role| role.value()).collect(),
+            permissions: principal
+                .permissions
+                .iter()
+                .map(|permission| permission.value())
+                .collect(),
+            iat: now,
+            exp: now + self.access_token_ttl_seconds,
+        };
+
+        match encode(&Header::default(), &claims, &self.encoding_key) {
+            Ok(token) => Ok(token),
+            Err(err) => Err(Arc::new(AppError::Internal(
+                ErrorData::new("token-signing-failed", "could not sign access token")
+                    .with_cause(Some(Arc::new(err))),
+            ))),
+        }
+    }
+
+    fn validate_access_token(&self, token: &str) -> ResultApp<AccessTokenClaims> {
+        match decode::<AccessTokenClaims>(token, &self.decoding_key, &Validation::default()) {
+            Ok(data) => Ok(data.claims),
+            Err(err) => Err(Arc::new(AppError::Unauthorized(
+                ErrorData::new("invalid-access-token", "invalid access token")
+                    .with_cause(Some(Arc::new(err))),
+            ))),
+        }
+    }
+
+    fn access_token_ttl_seconds(&self) -> i64 {
+        self.access_token_ttl_seconds
+    }
+
+    fn refresh_token_ttl_seconds(&self) -> i64 {
+        self.refresh_token_ttl_seconds
+    }
+}
+
+// Random URL-safe token handed to clients, it carries no claims
+pub fn generate_opaque_token() -> String {
+    let mut bytes = [0u8; OPAQUE_TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+pub fn hash_opaque_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::vo::id::Id;
+    use crate::domain::vo::permission::Permission;
+    use crate::domain::vo::role::Role;
+    use std::collections::HashSet;
+
+    fn service(secret: &str) -> JwtTokenService {
+        JwtTokenService::new(TokenConfig {
+            jwt_secret: secret.to_string(),
+            access_token_ttl_seconds: 60,
+            refresh_token_ttl_seconds: 120,
+        })
+    }
+
+    #[test]
+    fn access_token_round_trips_subject_roles_and_expiry() {
+        let service = service("test-secret");
+        let user_id = Id::new().unwrap();
+
+        let principal = Principal::new(
+            user_id,
+            HashSet::from([Role::Admin]),
+            HashSet::from([Permission::RolesManage]),
+        );
+
+        let token = service.issue_access_token(&principal).unwrap();
+        let claims = service.validate_access_token(&token).unwrap();
+
+        assert_eq!(claims.sub, user_id.value());
+        assert_eq!(claims.roles, vec!["admin".to_string()]);
+        assert_eq!(claims.permissions, vec!["roles:manage".to_string()]);
+        assert_eq!(claims.exp - claims.iat, 60);
+    }
+
+    #[test]
+    fn access_token_signed_with_other_secret_is_rejected() {
+        let principal = Principal::new(Id::new().unwrap(), HashSet::new(), HashSet::new());
+        let token = service("other-secret")
+            .issue_access_token(&principal)
+            .unwrap();
+        assert!(
+            service("test-secret")
+                .validate_access_token(&token)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn opaque_tokens_are_unique_and_hash_deterministically() {
+        let a = generate_opaque_token();
+        let b = generate_opaque_token();
+        assert_ne!(a, b);
+        assert_eq!(hash_opaque_token(&a), hash_opaque_token(&a));
+        assert_eq!(hash_opaque_token(&a).len(), 64);
+    }
+}
@@ -1,8 +1,98 @@
+use crate::domain::usecase::auth::login::{LoginUseCase, LoginUseCaseImpl};
+use crate::domain::usecase::auth::signin::{SignInUseCase, SignInUseCaseImpl};
+use crate::domain::usecase::auth::signup::{SignUpUseCase, SignUpUseCaseImpl};
+use crate::domain::usecase::autocomplete::suggest::{AutocompleteUseCase, AutocompleteUseCaseImpl};
+use crate::domain::usecase::category::get_category::{GetCategoryUseCase, GetCategoryUseCaseImpl};
+use crate::domain::usecase::category::manage_categories::{
+    ManageCategoriesUseCase, ManageCategoriesUseCaseImpl,
+};
+use crate::domain::usecase::claim::manage_claims::{ManageClaimsUseCase, ManageClaimsUseCaseImpl};
+use crate::domain::usecase::customer_service::cluster_customer_services::{
+    ClusterCustomerServicesUseCase, ClusterCustomerServicesUseCaseImpl,
+};
+use crate::domain::usecase::customer_service::create_customer_service::{
+    CreateCustomerServiceUseCase, CreateCustomerServiceUseCaseImpl,
+};
+use crate::domain::usecase::customer_service::delete_customer_service::{
+    DeleteCustomerServiceUseCase, DeleteCustomerServiceUseCaseImpl,
+};
+use crate::domain::usecase::customer_service::find_customer_services_by_cell::{
+    FindCustomerServicesByCellUseCase, FindCustomerServicesByCellUseCaseImpl,
+};
+use crate::domain::usecase::customer_service::find_nearby_customer_services::{
+    FindNearbyCustomerServicesUseCase, FindNearbyCustomerServicesUseCaseImpl,
+};
+use crate::domain::usecase::customer_service::get_customer_service::{
+    GetCustomerServiceUseCase, GetCustomerServiceUseCaseImpl,
+};
+use crate::domain::usecase::customer_service::get_customer_service_tile::{
+    GetCustomerServiceTileUseCase, GetCustomerServiceTileUseCaseImpl,
+};
+use crate::domain::usecase::customer_service::search_customer_services::{
+    SearchCustomerServicesUseCase, SearchCustomerServicesUseCaseImpl,
+};
+use crate::domain::usecase::customer_service::update_customer_service::{
+    UpdateCustomerServiceUseCase, UpdateCustomerServiceUseCaseImpl,
+};
+use crate::domain::usecase::geocoding::geocode::{GeocodeUseCase, GeocodeUseCaseImpl};
+use crate::domain::usecase::holiday::holiday_calendar::{
+    HolidayCalendarUseCase, HolidayCalendarUseCaseImpl,
+};
+use crate::domain::usecase::holiday::manage_holidays::{
+    ManageHolidaysUseCase, ManageHolidaysUseCaseImpl,
+};
+use crate::domain::usecase::moderation::flag_content::{
+    FlagContentUseCase, FlagContentUseCaseImpl,
+};
+use crate::domain::usecase::moderation::moderate_content::{
+    ModerateContentUseCase, ModerateContentUseCaseImpl,
+};
+use crate::domain::usecase::person::manage_person::{ManagePersonUseCase, ManagePersonUseCaseImpl};
+use crate::domain::usecase::review::get_review::{GetReviewUseCase, GetReviewUseCaseImpl};
+use crate::domain::usecase::review::manage_reviews::{
+    ManageReviewsUseCase, ManageReviewsUseCaseImpl,
+};
 use crate::domain::usecase::user::create_user::{CreateUserUseCase, CreateUserUseCaseImpl};
 use crate::domain::usecase::user::delete_user::{DeleteUserUseCase, DeleteUserUseCaseImpl};
+use crate::domain::usecase::user::manage_user_roles::{
+    ManageUserRolesUseCase, ManageUserRolesUseCaseImpl,
+};
 use crate::domain::usecase::user::update_user::{UpdateUserUseCase, UpdateUserUseCaseImpl};
+use crate::domain::vo::password::PasswordHashParams;
+use crate::infrastructure::geocoder::{GazetteerGeocoder, Geocoder, GeocoderConfig};
 use crate::infrastructure::postgres::{DbConfig, PostgresBaseRepository};
+use crate::infrastructure::token::{JwtTokenService, TokenConfig, TokenService};
+use crate::presentation::auth::auth_route;
+use crate::presentation::autocomplete::autocomplete_route;
+use crate::presentation::category::category_route;
+use crate::presentation::claim::claim_route;
+use crate::presentation::customer_service::customer_service_route;
+use crate::presentation::geocoding::geocoding_route;
+use crate::presentation::holiday::holiday_route;
+use crate::presentation::moderation::moderation_route;
+use crate::presentation::review::review_route;
+use crate::presentation::tile::tile_route;
 use crate::presentation::user::user_route;
+use crate::repositories::category::category_repository::{
+    CategoryRepository, CategoryRepositoryPostgres,
+};
+use crate::repositories::claim::ownership_claim_repository::{
+    OwnershipClaimRepository, OwnershipClaimRepositoryPostgres,
+};
+use crate::repositories::customer_service::customer_service_repository::{
+    CustomerServiceRepository, CustomerServiceRepositoryPostgres,
+};
+use crate::repositories::holiday::holiday_repository::{
+    HolidayRepository, HolidayRepositoryPostgres,
+};
+use crate::repositories::moderation::moderation_flag_repository::{
+    ModerationFlagRepository, ModerationFlagRepositoryPostgres,
+};
+use crate::repositories::person::person_repository::{PersonRepository, PersonRepositoryPostgres};
+use crate::repositories::refresh_token::refresh_token_repository::{
+    RefreshTokenRepository, RefreshTokenRepositoryPostgres,
+};
+use crate::repositories::review::review_repository::{ReviewRepository, ReviewRepositoryPostgres};
 use crate::repositories::user::user_repository::{UserRepository, UserRepositoryPostgres};
 use actix_web::middleware::Logger;
 use actix_web::{App, HttpServer, web};
@@ -19,13 +109,37 @@ mod repositories;
 async fn main() -> Result<(), std::io::Error> {
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("debug"));
     let database_url = env::var("DATABASE_URL").unwrap();
+    PasswordHashParams::from_env().install();
 
     let db_config = DbConfig { database_url };
+    let token_service: Arc<dyn TokenService> =
+        Arc::new(JwtTokenService::new(TokenConfig::from_env()));
+    let token_service_data = web::Data::new(token_service.clone());
+    let geocoder: Arc<dyn Geocoder> = Arc::new(GazetteerGeocoder::new(GeocoderConfig::from_env()));
 
     let base_repository = PostgresBaseRepository::new(db_config);
     let user_repository: Arc<dyn UserRepository> =
-        Arc::new(UserRepositoryPostgres::new(base_repository));
+        Arc::new(UserRepositoryPostgres::new(base_repository.clone()));
     let user_repository_data = web::Data::new(user_repository.clone());
+    let refresh_token_repository: Arc<dyn RefreshTokenRepository> =
+        Arc::new(RefreshTokenRepositoryPostgres::new(base_repository.clone()));
+    let customer_service_repository: Arc<dyn CustomerServiceRepository> = Arc::new(
+        CustomerServiceRepositoryPostgres::new(base_repository.clone()),
+    );
+    let holiday_repository: Arc<dyn HolidayRepository> =
+        Arc::new(HolidayRepositoryPostgres::new(base_repository.clone()));
+    let category_repository: Arc<dyn CategoryRepository> =
+        Arc::new(CategoryRepositoryPostgres::new(base_repository.clone()));
+    let review_repository: Arc<dyn ReviewRepository> =
+        Arc::new(ReviewRepositoryPostgres::new(base_repository.clone()));
+    let moderation_flag_repository: Arc<dyn ModerationFlagRepository> = Arc::new(
+        ModerationFlagRepositoryPostgres::new(base_repository.clone()),
+    );
+    let ownership_claim_repository: Arc<dyn OwnershipClaimRepository> = Arc::new(
+        OwnershipClaimRepositoryPostgres::new(base_repository.clone()),
+    );
+    let person_repository: Arc<dyn PersonRepository> =
+        Arc::new(PersonRepositoryPostgres::new(base_repository));
 
     let create_user_use_case: Arc<dyn CreateUserUseCase> =
         Arc::new(CreateUserUseCaseImpl::new(user_repository.clone()));
@@ -39,13 +153,219 @@ async fn main() -> Result<(), std::io::Error> {
         Arc::new(UpdateUserUseCaseImpl::new(user_repository.clone()));
     let update_user_use_case_data = web::Data::new(update_user_use_case.clone());
 
+    let manage_user_roles_use_case: Arc<dyn ManageUserRolesUseCase> =
+        Arc::new(ManageUserRolesUseCaseImpl::new(user_repository.clone()));
+    let manage_user_roles_use_case_data = web::Data::new(manage_user_roles_use_case.clone());
+
+    let manage_person_use_case: Arc<dyn ManagePersonUseCase> = Arc::new(
+        ManagePersonUseCaseImpl::new(person_repository.clone(), user_repository.clone()),
+    );
+    let manage_person_use_case_data = web::Data::new(manage_person_use_case.clone());
+
+    let sign_up_use_case: Arc<dyn SignUpUseCase> =
+        Arc::new(SignUpUseCaseImpl::new(create_user_use_case.clone()));
+    let sign_up_use_case_data = web::Data::new(sign_up_use_case.clone());
+
+    let sign_in_use_case: Arc<dyn SignInUseCase> =
+        Arc::new(SignInUseCaseImpl::new(user_repository.clone()));
+
+    let login_use_case: Arc<dyn LoginUseCase> = Arc::new(LoginUseCaseImpl::new(
+        sign_in_use_case.clone(),
+        user_repository.clone(),
+        refresh_token_repository.clone(),
+        token_service.clone(),
+    ));
+    let login_use_case_data = web::Data::new(login_use_case.clone());
+
+    let holiday_calendar_use_case: Arc<dyn HolidayCalendarUseCase> =
+        Arc::new(HolidayCalendarUseCaseImpl::new(holiday_repository.clone()));
+    let holiday_calendar_use_case_data = web::Data::new(holiday_calendar_use_case.clone());
+
+    let manage_holidays_use_case: Arc<dyn ManageHolidaysUseCase> =
+        Arc::new(ManageHolidaysUseCaseImpl::new(
+            holiday_repository.clone(),
+            holiday_calendar_use_case.clone(),
+        ));
+    let manage_holidays_use_case_data = web::Data::new(manage_holidays_use_case.clone());
+
+    let autocomplete_use_case: Arc<dyn AutocompleteUseCase> =
+        Arc::new(AutocompleteUseCaseImpl::new(
+            customer_service_repository.clone(),
+            category_repository.clone(),
+        ));
+    if let Err(error) = autocomplete_use_case.rebuild().await {
+        log::error!("autocomplete index could not be built: {}", error);
+    }
+    let autocomplete_use_case_data = web::Data::new(autocomplete_use_case.clone());
+
+    let geocode_use_case: Arc<dyn GeocodeUseCase> =
+        Arc::new(GeocodeUseCaseImpl::new(geocoder.clone()));
+    let geocode_use_case_data = web::Data::new(geocode_use_case.clone());
+
+    let get_category_use_case: Arc<dyn GetCategoryUseCase> =
+        Arc::new(GetCategoryUseCaseImpl::new(category_repository.clone()));
+    let get_category_use_case_data = web::Data::new(get_category_use_case.clone());
+
+    let manage_categories_use_case: Arc<dyn ManageCategoriesUseCase> =
+        Arc::new(ManageCategoriesUseCaseImpl::new(
+            category_repository.clone(),
+            autocomplete_use_case.clone(),
+        ));
+    let manage_categories_use_case_data = web::Data::new(manage_categories_use_case.clone());
+
+    let get_customer_service_use_case: Arc<dyn GetCustomerServiceUseCase> =
+        Arc::new(GetCustomerServiceUseCaseImpl::new(
+            customer_service_repository.clone(),
+            holiday_calendar_use_case.clone(),
+            get_category_use_case.clone(),
+        ));
+    let get_customer_service_use_case_data = web::Data::new(get_customer_service_use_case.clone());
+
+    let get_review_use_case: Arc<dyn GetReviewUseCase> = Arc::new(GetReviewUseCaseImpl::new(
+        review_repository.clone(),
+        get_customer_service_use_case.clone(),
+    ));
+    let get_review_use_case_data = web::Data::new(get_review_use_case.clone());
+
+    let flag_content_use_case: Arc<dyn FlagContentUseCase> = Arc::new(FlagContentUseCaseImpl::new(
+        moderation_flag_repository.clone(),
+        review_repository.clone(),
+        user_repository.clone(),
+        get_review_use_case.clone(),
+        get_customer_service_use_case.clone(),
+    ));
+    let flag_content_use_case_data = web::Data::new(flag_content_use_case.clone());
+
+    let create_customer_service_use_case: Arc<dyn CreateCustomerServiceUseCase> =
+        Arc::new(CreateCustomerServiceUseCaseImpl::new(
+            customer_service_repository.clone(),
+            get_category_use_case.clone(),
+            autocomplete_use_case.clone(),
+            flag_content_use_case.clone(),
+            geocoder.clone(),
+        ));
+    let create_customer_service_use_case_data =
+        web::Data::new(create_customer_service_use_case.clone());
+
+    let find_nearby_customer_services_use_case: Arc<dyn FindNearbyCustomerServicesUseCase> =
+        Arc::new(FindNearbyCustomerServicesUseCaseImpl::new(
+            customer_service_repository.clone(),
+        ));
+    let find_nearby_customer_services_use_case_data =
+        web::Data::new(find_nearby_customer_services_use_case.clone());
+
+    let search_customer_services_use_case: Arc<dyn SearchCustomerServicesUseCase> = Arc::new(
+        SearchCustomerServicesUseCaseImpl::new(customer_service_repository.clone()),
+    );
+    let search_customer_services_use_case_data =
+        web::Data::new(search_customer_services_use_case.clone());
+
+    let cluster_customer_services_use_case: Arc<dyn ClusterCustomerServicesUseCase> = Arc::new(
+        ClusterCustomerServicesUseCaseImpl::new(customer_service_repository.clone()),
+    );
+    let cluster_customer_services_use_case_data =
+        web::Data::new(cluster_customer_services_use_case.clone());
+
+    let find_customer_services_by_cell_use_case: Arc<dyn FindCustomerServicesByCellUseCase> =
+        Arc::new(FindCustomerServicesByCellUseCaseImpl::new(
+            customer_service_repository.clone(),
+        ));
+    let find_customer_services_by_cell_use_case_data =
+        web::Data::new(find_customer_services_by_cell_use_case.clone());
+
+    let get_customer_service_tile_use_case: Arc<dyn GetCustomerServiceTileUseCase> = Arc::new(
+        GetCustomerServiceTileUseCaseImpl::new(customer_service_repository.clone()),
+    );
+    let get_customer_service_tile_use_case_data =
+        web::Data::new(get_customer_service_tile_use_case.clone());
+
+    let update_customer_service_use_case: Arc<dyn UpdateCustomerServiceUseCase> =
+        Arc::new(UpdateCustomerServiceUseCaseImpl::new(
+            customer_service_repository.clone(),
+            ownership_claim_repository.clone(),
+            get_category_use_case.clone(),
+            autocomplete_use_case.clone(),
+            flag_content_use_case.clone(),
+        ));
+    let update_customer_service_use_case_data =
+        web::Data::new(update_customer_service_use_case.clone());
+
+    let delete_customer_service_use_case: Arc<dyn DeleteCustomerServiceUseCase> =
+        Arc::new(DeleteCustomerServiceUseCaseImpl::new(
+            customer_service_repository.clone(),
+            autocomplete_use_case.clone(),
+        ));
+    let delete_customer_service_use_case_data =
+        web::Data::new(delete_customer_service_use_case.clone());
+
+    let manage_reviews_use_case: Arc<dyn ManageReviewsUseCase> =
+        Arc::new(ManageReviewsUseCaseImpl::new(
+            review_repository.clone(),
+            get_review_use_case.clone(),
+            get_customer_service_use_case.clone(),
+            flag_content_use_case.clone(),
+        ));
+    let manage_reviews_use_case_data = web::Data::new(manage_reviews_use_case.clone());
+
+    let moderate_content_use_case: Arc<dyn ModerateContentUseCase> =
+        Arc::new(ModerateContentUseCaseImpl::new(
+            moderation_flag_repository.clone(),
+            review_repository.clone(),
+            customer_service_repository.clone(),
+            autocomplete_use_case.clone(),
+        ));
+    let moderate_content_use_case_data = web::Data::new(moderate_content_use_case.clone());
+
+    let manage_claims_use_case: Arc<dyn ManageClaimsUseCase> =
+        Arc::new(ManageClaimsUseCaseImpl::new(
+            ownership_claim_repository.clone(),
+            user_repository.clone(),
+            get_customer_service_use_case.clone(),
+        ));
+    let manage_claims_use_case_data = web::Data::new(manage_claims_use_case.clone());
+
     HttpServer::new(move || {
         App::new()
             .app_data(create_user_use_case_data.clone())
             .app_data(update_user_use_case_data.clone())
             .app_data(delete_user_use_case_data.clone())
+            .app_data(manage_user_roles_use_case_data.clone())
+            .app_data(manage_person_use_case_data.clone())
+            .app_data(sign_up_use_case_data.clone())
+            .app_data(login_use_case_data.clone())
+            .app_data(autocomplete_use_case_data.clone())
+            .app_data(geocode_use_case_data.clone())
+            .app_data(get_category_use_case_data.clone())
+            .app_data(manage_categories_use_case_data.clone())
+            .app_data(holiday_calendar_use_case_data.clone())
+            .app_data(manage_holidays_use_case_data.clone())
+            .app_data(create_customer_service_use_case_data.clone())
+            .app_data(get_customer_service_use_case_data.clone())
+            .app_data(find_nearby_customer_services_use_case_data.clone())
+            .app_data(search_customer_services_use_case_data.clone())
+            .app_data(find_customer_services_by_cell_use_case_data.clone())
+            .app_data(cluster_customer_services_use_case_data.clone())
+            .app_data(get_customer_service_tile_use_case_data.clone())
+            .app_data(update_customer_service_use_case_data.clone())
+            .app_data(delete_customer_service_use_case_data.clone())
+            .app_data(get_review_use_case_data.clone())
+            .app_data(manage_reviews_use_case_data.clone())
+            .app_data(flag_content_use_case_data.clone())
+            .app_data(moderate_content_use_case_data.clone())
+            .app_data(manage_claims_use_case_data.clone())
             .app_data(user_repository_data.clone())
+            .app_data(token_service_data.clone())
             .wrap(Logger::default())
+            .configure(auth_route::routes)
+            .configure(customer_service_route::routes)
+            .configure(tile_route::routes)
+            .configure(holiday_route::routes)
+            .configure(category_route::routes)
+            .configure(autocomplete_route::routes)
+            .configure(geocoding_route::routes)
+            .configure(review_route::routes)
+            .configure(moderation_route::routes)
+            .configure(claim_route::routes)
             .configure(user_route::routes)
     })
     .bind("0.0.0.0:8080")?
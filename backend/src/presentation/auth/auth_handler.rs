@@ -0,0 +1,81 @@
+use crate::common::error::AppError;
+use crate::domain::usecase::auth::login::LoginUseCase;
+use crate::domain::usecase::auth::signup::SignUpUseCase;
+use crate::domain::vo::email::Email;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::password::Password;
+use crate::presentation::auth::dto::{
+    AuthTokensResponseDto, LoginDataDto, RefreshTokenDataDto, SignUpDataDto,
+};
+use crate::presentation::user::dto::UserDataResponseDto;
+use actix_web::{HttpResponse, post, web};
+use std::sync::Arc;
+use validator::Validate;
+
+#[post("/signup")]
+pub async fn sign_up(
+    sign_up_use_case: web::Data<Arc<dyn SignUpUseCase>>,
+    sign_up_data: web::Json<SignUpDataDto>,
+) -> HttpResponse {
+    if let Err(error) = sign_up_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let sign_up_data = sign_up_data.into_inner();
+    let name = match Name::new(sign_up_data.name) {
+        Ok(n) => n,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let email = match Email::new(sign_up_data.email) {
+        Ok(e) => e,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let password = match Password::new(sign_up_data.password) {
+        Ok(p) => p,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match sign_up_use_case.sign_up(name, email, password).await {
+        Ok(user) => HttpResponse::Created().json(UserDataResponseDto::from(&user)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[post("/login")]
+pub async fn login(
+    login_use_case: web::Data<Arc<dyn LoginUseCase>>,
+    login_data: web::Json<LoginDataDto>,
+) -> HttpResponse {
+    if let Err(error) = login_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let login_data = login_data.into_inner();
+    let email = match Email::new(login_data.email) {
+        Ok(e) => e,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match login_use_case.login(&email, &login_data.password).await {
+        Ok(tokens) => HttpResponse::Ok().json(AuthTokensResponseDto::from(tokens)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[post("/refresh")]
+pub async fn refresh_token(
+    login_use_case: web::Data<Arc<dyn LoginUseCase>>,
+    refresh_token_data: web::Json<RefreshTokenDataDto>,
+) -> HttpResponse {
+    if let Err(error) = refresh_token_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    match login_use_case
+        .refresh(&refresh_token_data.into_inner().refresh_token)
+        .await
+    {
+        Ok(tokens) => HttpResponse::Ok().json(AuthTokensResponseDto::from(tokens)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
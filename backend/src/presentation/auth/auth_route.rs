@@ -0,0 +1,11 @@
+use crate::presentation::auth::auth_handler::{login, refresh_token, sign_up};
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/auth")
+            .service(sign_up)
+            .service(login)
+            .service(refresh_token),
+    );
+}
@@ -0,0 +1,79 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::principal::Principal;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::Permission;
+use crate::domain::vo::role::Role;
+use crate::infrastructure::token::TokenService;
+use actix_web::dev::Payload;
+use actix_web::error::InternalError;
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, web};
+use std::future::{Ready, ready};
+use std::sync::Arc;
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+// Resolves the caller from the `Authorization: Bearer <token>` header
+impl FromRequest for Principal {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(resolve_principal(req).map_err(|error| {
+            let app_error = AppError::from(error);
+            InternalError::from_response(app_error.clone(), HttpResponse::from(app_error)).into()
+        }))
+    }
+}
+
+fn unauthorized(code: &str, message: &str) -> Arc<AppError> {
+    Arc::new(AppError::Unauthorized(ErrorData::new(code, message)))
+}
+
+fn resolve_principal(req: &HttpRequest) -> ResultApp<Principal> {
+    let token_service = match req.app_data::<web::Data<Arc<dyn TokenService>>>() {
+        Some(token_service) => token_service,
+        None => {
+            return Err(Arc::new(AppError::Internal(ErrorData::new(
+                "internal",
+                "token service not configured",
+            ))));
+        }
+    };
+
+    let token = match req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+    {
+        Some(token) => token.trim(),
+        None => {
+            return Err(unauthorized(
+                "missing-access-token",
+                "missing bearer access token",
+            ));
+        }
+    };
+
+    let claims = token_service.validate_access_token(token)?;
+    let user_id = match Id::new_from_string(claims.sub) {
+        Ok(user_id) => user_id,
+        Err(_) => return Err(unauthorized("invalid-access-token", "invalid access token")),
+    };
+
+    // Claims unknown to this build are dropped, they can only narrow what the caller may do
+    let roles = claims
+        .roles
+        .into_iter()
+        .filter_map(|role| Role::new(role).ok())
+        .collect();
+    let permissions = claims
+        .permissions
+        .into_iter()
+        .filter_map(|permission| Permission::new(permission).ok())
+        .collect();
+
+    Ok(Principal::new(user_id, roles, permissions))
+}
@@ -0,0 +1,46 @@
+use crate::domain::usecase::auth::login::AuthTokens;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct SignUpDataDto {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 1, max = 50))]
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct LoginDataDto {
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 1, max = 50))]
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RefreshTokenDataDto {
+    #[validate(length(min = 1, max = 128))]
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthTokensResponseDto {
+    access_token: String,
+    token_type: String,
+    expires_in: i64,
+    refresh_token: String,
+}
+
+impl From<AuthTokens> for AuthTokensResponseDto {
+    fn from(value: AuthTokens) -> Self {
+        Self {
+            access_token: value.access_token,
+            token_type: "Bearer".to_string(),
+            expires_in: value.expires_in,
+            refresh_token: value.refresh_token,
+        }
+    }
+}
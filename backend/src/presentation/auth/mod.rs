@@ -0,0 +1,4 @@
+pub mod auth_handler;
+pub mod auth_route;
+pub mod authenticated;
+pub mod dto;
@@ -0,0 +1,51 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::domain::usecase::autocomplete::suggest::AutocompleteUseCase;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::domain::vo::search_query::SearchQuery;
+use crate::presentation::autocomplete::dto::{AutocompleteQuery, SuggestionResponseDto};
+use actix_web::{HttpResponse, get, web};
+use std::sync::Arc;
+use validator::Validate;
+
+const DEFAULT_SUGGESTIONS: usize = 8;
+
+#[get("")]
+pub async fn autocomplete(
+    autocomplete_use_case: web::Data<Arc<dyn AutocompleteUseCase>>,
+    autocomplete_query: web::Query<AutocompleteQuery>,
+) -> HttpResponse {
+    if let Err(error) = autocomplete_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let autocomplete_query = autocomplete_query.into_inner();
+    let query = match SearchQuery::new(&autocomplete_query.q) {
+        Ok(q) => q,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let near = match (autocomplete_query.lat, autocomplete_query.lon) {
+        (Some(lat), Some(lon)) => match GeoPoint::new(lat, lon) {
+            Ok(g) => Some(g),
+            Err(error) => return HttpResponse::from(AppError::from(error)),
+        },
+        (None, None) => None,
+        _ => {
+            return HttpResponse::from(AppError::Validation(ErrorData::new(
+                "invalid-latitude-longitude",
+                "latitude and longitude must be sent together",
+            )));
+        }
+    };
+
+    let suggestions = autocomplete_use_case.suggest(
+        &query,
+        near.as_ref(),
+        autocomplete_query.limit.unwrap_or(DEFAULT_SUGGESTIONS),
+    );
+    HttpResponse::Ok().json(
+        suggestions
+            .iter()
+            .map(SuggestionResponseDto::from)
+            .collect::<Vec<_>>(),
+    )
+}
@@ -0,0 +1,6 @@
+use crate::presentation::autocomplete::autocomplete_handler::autocomplete;
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(web::scope("/autocomplete").service(autocomplete));
+}
@@ -0,0 +1,37 @@
+use crate::domain::vo::autocomplete_index::RankedSuggestion;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct AutocompleteQuery {
+    #[validate(length(min = 1, max = 100))]
+    pub q: String,
+    // Places near the caller rank higher when both are sent
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: Option<f64>,
+    #[validate(range(min = 1, max = 20))]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestionResponseDto {
+    kind: String,
+    text: String,
+    value: String,
+    distance_m: Option<f64>,
+}
+
+impl From<&RankedSuggestion> for SuggestionResponseDto {
+    fn from(value: &RankedSuggestion) -> Self {
+        SuggestionResponseDto {
+            kind: value.suggestion.kind.value(),
+            text: value.suggestion.text.clone(),
+            value: value.suggestion.value.clone(),
+            distance_m: value
+                .distance_meters
+                .map(|distance_meters| (distance_meters * 10.0).round() / 10.0),
+        }
+    }
+}
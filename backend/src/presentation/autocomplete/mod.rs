@@ -0,0 +1,3 @@
+pub mod autocomplete_handler;
+pub mod autocomplete_route;
+pub mod dto;
@@ -0,0 +1,124 @@
+use crate::common::error::AppError;
+use crate::domain::entity::category::{Category, CategoryPartial};
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::category::get_category::GetCategoryUseCase;
+use crate::domain::usecase::category::manage_categories::ManageCategoriesUseCase;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::presentation::category::dto::{
+    CategoryDataDto, CategoryPartialDataDto, CategoryResponseDto, LocaleQuery,
+};
+use actix_web::{HttpResponse, delete, get, patch, post, web};
+use std::sync::Arc;
+use validator::Validate;
+
+#[post("")]
+pub async fn create_category(
+    manage_use_case: web::Data<Arc<dyn ManageCategoriesUseCase>>,
+    principal: Principal,
+    category_data: web::Json<CategoryDataDto>,
+) -> HttpResponse {
+    if let Err(error) = category_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let category = match Category::try_from(category_data.into_inner()) {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.create_category(&principal, &category).await {
+        Ok(category) => HttpResponse::Created().json(CategoryResponseDto::new(&category, None)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("")]
+pub async fn list_categories(
+    get_use_case: web::Data<Arc<dyn GetCategoryUseCase>>,
+    locale_query: web::Query<LocaleQuery>,
+) -> HttpResponse {
+    if let Err(error) = locale_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    match get_use_case.list_categories().await {
+        Ok(categories) => HttpResponse::Ok().json(
+            categories
+                .iter()
+                .map(|category| CategoryResponseDto::new(category, locale_query.locale.as_deref()))
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/{slug}")]
+pub async fn get_category_by_slug(
+    get_use_case: web::Data<Arc<dyn GetCategoryUseCase>>,
+    slug_path: web::Path<String>,
+    locale_query: web::Query<LocaleQuery>,
+) -> HttpResponse {
+    if let Err(error) = locale_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let slug = match CustomerServiceCategory::new(slug_path.into_inner()) {
+        Ok(s) => s,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match get_use_case.get_category(&slug).await {
+        Ok(category) => HttpResponse::Ok().json(CategoryResponseDto::new(
+            &category,
+            locale_query.locale.as_deref(),
+        )),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[patch("/{slug}")]
+pub async fn patch_category_by_slug(
+    manage_use_case: web::Data<Arc<dyn ManageCategoriesUseCase>>,
+    principal: Principal,
+    slug_path: web::Path<String>,
+    category_partial_data: web::Json<CategoryPartialDataDto>,
+) -> HttpResponse {
+    if let Err(error) = category_partial_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let slug = match CustomerServiceCategory::new(slug_path.into_inner()) {
+        Ok(s) => s,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let mut category_partial = match CategoryPartial::try_from(category_partial_data.into_inner()) {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    category_partial.set_slug(slug);
+
+    match manage_use_case
+        .update_category(&principal, &category_partial)
+        .await
+    {
+        Ok(category) => HttpResponse::Ok().json(CategoryResponseDto::new(&category, None)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[delete("/{slug}")]
+pub async fn delete_category_by_slug(
+    manage_use_case: web::Data<Arc<dyn ManageCategoriesUseCase>>,
+    principal: Principal,
+    slug_path: web::Path<String>,
+) -> HttpResponse {
+    let slug = match CustomerServiceCategory::new(slug_path.into_inner()) {
+        Ok(s) => s,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.delete_category(&principal, &slug).await {
+        Ok(category) => HttpResponse::Ok().json(CategoryResponseDto::new(&category, None)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
@@ -0,0 +1,16 @@
+use crate::presentation::category::category_handler::{
+    create_category, delete_category_by_slug, get_category_by_slug, list_categories,
+    patch_category_by_slug,
+};
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/categories")
+            .service(create_category)
+            .service(list_categories)
+            .service(get_category_by_slug)
+            .service(patch_category_by_slug)
+            .service(delete_category_by_slug),
+    );
+}
@@ -0,0 +1,88 @@
+use crate::domain::entity::category::{Category, CategoryPartial};
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::localized_labels::{DEFAULT_LOCALE, LocalizedLabels};
+use crate::presentation::deserialize::deserialize_present;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CategoryDataDto {
+    #[validate(length(min = 1, max = 50))]
+    slug: String,
+    #[validate(length(min = 1, max = 50))]
+    parent: Option<String>,
+    labels: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CategoryPartialDataDto {
+    // null moves the category to the top level
+    #[serde(default, deserialize_with = "deserialize_present")]
+    parent: Option<Option<String>>,
+    labels: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct LocaleQuery {
+    #[validate(length(min = 2, max = 10))]
+    pub locale: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryResponseDto {
+    id: String,
+    slug: String,
+    parent: Option<String>,
+    // Label in the requested locale
+    label: String,
+    labels: BTreeMap<String, String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl TryFrom<CategoryDataDto> for Category {
+    type Error = Arc<dyn Error + Send + Sync>;
+
+    fn try_from(value: CategoryDataDto) -> Result<Self, Self::Error> {
+        Category::new(
+            CustomerServiceCategory::new(value.slug)?,
+            value.parent.map(CustomerServiceCategory::new).transpose()?,
+            LocalizedLabels::new(value.labels)?,
+        )
+    }
+}
+
+impl TryFrom<CategoryPartialDataDto> for CategoryPartial {
+    type Error = Arc<dyn Error + Send + Sync>;
+
+    fn try_from(value: CategoryPartialDataDto) -> Result<Self, Self::Error> {
+        Ok(CategoryPartial {
+            slug: None,
+            parent: value
+                .parent
+                .map(|parent| parent.map(CustomerServiceCategory::new).transpose())
+                .transpose()?,
+            labels: value.labels.map(LocalizedLabels::new).transpose()?,
+        })
+    }
+}
+
+impl CategoryResponseDto {
+    pub fn new(value: &Category, locale: Option<&str>) -> Self {
+        CategoryResponseDto {
+            id: value.id.value(),
+            slug: value.slug.value(),
+            parent: value.parent.as_ref().map(|parent| parent.value()),
+            label: value
+                .labels
+                .label(locale.unwrap_or(DEFAULT_LOCALE))
+                .to_string(),
+            labels: value.labels.value(),
+            created_at: value.created_at.value(),
+            updated_at: value.updated_at.value(),
+        }
+    }
+}
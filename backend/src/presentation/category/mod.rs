@@ -0,0 +1,3 @@
+pub mod category_handler;
+pub mod category_route;
+pub mod dto;
@@ -0,0 +1,116 @@
+use crate::common::error::AppError;
+use crate::domain::entity::ownership_claim::ClaimStatus;
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::claim::manage_claims::ManageClaimsUseCase;
+use crate::domain::vo::id::Id;
+use crate::presentation::claim::dto::{ClaimDataDto, ClaimQueueQuery, OwnershipClaimResponseDto};
+use actix_web::{HttpResponse, get, post, web};
+use std::sync::Arc;
+use validator::Validate;
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+
+// Registered under the `/customer-services` scope
+#[post("/{id}/claims")]
+pub async fn create_customer_service_claim(
+    manage_use_case: web::Data<Arc<dyn ManageClaimsUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+    claim_data: web::Json<ClaimDataDto>,
+) -> HttpResponse {
+    if let Err(error) = claim_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let customer_service_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let claim = match claim_data
+        .into_inner()
+        .into_claim(customer_service_id, principal.user_id)
+    {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.submit_claim(&principal, &claim).await {
+        Ok(claim) => HttpResponse::Created().json(OwnershipClaimResponseDto::from(&claim)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("")]
+pub async fn list_pending_claims(
+    manage_use_case: web::Data<Arc<dyn ManageClaimsUseCase>>,
+    principal: Principal,
+    queue_query: web::Query<ClaimQueueQuery>,
+) -> HttpResponse {
+    if let Err(error) = queue_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    match manage_use_case
+        .list_pending(
+            &principal,
+            queue_query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+            queue_query.offset.unwrap_or(0),
+        )
+        .await
+    {
+        Ok(claims) => HttpResponse::Ok().json(
+            claims
+                .iter()
+                .map(OwnershipClaimResponseDto::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+async fn decide_claim(
+    manage_use_case: &Arc<dyn ManageClaimsUseCase>,
+    principal: &Principal,
+    id_path: String,
+    decision: ClaimStatus,
+) -> HttpResponse {
+    let claim_id = match Id::new_from_string(id_path) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.decide(principal, &claim_id, decision).await {
+        Ok(claim) => HttpResponse::Ok().json(OwnershipClaimResponseDto::from(&claim)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[post("/{id}/approve")]
+pub async fn approve_claim(
+    manage_use_case: web::Data<Arc<dyn ManageClaimsUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    decide_claim(
+        &manage_use_case,
+        &principal,
+        id_path.into_inner(),
+        ClaimStatus::Approved,
+    )
+    .await
+}
+
+#[post("/{id}/reject")]
+pub async fn reject_claim(
+    manage_use_case: web::Data<Arc<dyn ManageClaimsUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    decide_claim(
+        &manage_use_case,
+        &principal,
+        id_path.into_inner(),
+        ClaimStatus::Rejected,
+    )
+    .await
+}
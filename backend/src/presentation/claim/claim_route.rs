@@ -0,0 +1,11 @@
+use crate::presentation::claim::claim_handler::{approve_claim, list_pending_claims, reject_claim};
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/claims")
+            .service(list_pending_claims)
+            .service(approve_claim)
+            .service(reject_claim),
+    );
+}
@@ -0,0 +1,66 @@
+use crate::domain::entity::ownership_claim::OwnershipClaim;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::tax_id::{TaxCountry, TaxId};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ClaimDataDto {
+    // Masked or not, e.g. `11.222.333/0001-81`
+    #[validate(length(min = 14, max = 18))]
+    cnpj: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ClaimQueueQuery {
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 0))]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnershipClaimResponseDto {
+    id: String,
+    customer_service_id: String,
+    claimant_id: String,
+    cnpj: String,
+    status: String,
+    reviewer_id: Option<String>,
+    decided_at: Option<String>,
+    created_at: String,
+}
+
+impl ClaimDataDto {
+    pub fn into_claim(
+        self,
+        customer_service_id: Id,
+        claimant_id: Id,
+    ) -> Result<OwnershipClaim, Arc<dyn Error + Send + Sync>> {
+        OwnershipClaim::new(
+            customer_service_id,
+            claimant_id,
+            TaxId::new_for_country(TaxCountry::BR, self.cnpj)?,
+        )
+    }
+}
+
+impl From<&OwnershipClaim> for OwnershipClaimResponseDto {
+    fn from(value: &OwnershipClaim) -> Self {
+        Self {
+            id: value.id.value(),
+            customer_service_id: value.customer_service_id.value(),
+            claimant_id: value.claimant_id.value(),
+            cnpj: value.tax_id.format().unwrap_or_default(),
+            status: value.status.value(),
+            reviewer_id: value.reviewer_id.map(|reviewer_id| reviewer_id.value()),
+            decided_at: value
+                .decided_at
+                .as_ref()
+                .map(|decided_at| decided_at.value()),
+            created_at: value.created_at.value(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod claim_handler;
+pub mod claim_route;
+pub mod dto;
@@ -0,0 +1,438 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::domain::entity::customer_service::{
+    CustomerServiceFilter, CustomerServicePartial, CustomerServiceSort, NewCustomerService,
+};
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::customer_service::cluster_customer_services::ClusterCustomerServicesUseCase;
+use crate::domain::usecase::customer_service::create_customer_service::CreateCustomerServiceUseCase;
+use crate::domain::usecase::customer_service::delete_customer_service::DeleteCustomerServiceUseCase;
+use crate::domain::usecase::customer_service::find_customer_services_by_cell::FindCustomerServicesByCellUseCase;
+use crate::domain::usecase::customer_service::find_nearby_customer_services::FindNearbyCustomerServicesUseCase;
+use crate::domain::usecase::customer_service::get_customer_service::GetCustomerServiceUseCase;
+use crate::domain::usecase::customer_service::search_customer_services::{
+    DEFAULT_SEARCH_RADIUS_METERS, SearchCustomerServicesUseCase,
+};
+use crate::domain::usecase::customer_service::update_customer_service::UpdateCustomerServiceUseCase;
+use crate::domain::usecase::holiday::holiday_calendar::HolidayCalendarUseCase;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::geohash::Geohash;
+use crate::domain::vo::geopoint::{BoundingBox, GeoPoint};
+use crate::domain::vo::holiday_calendar::HolidayCalendar;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::search_query::SearchQuery;
+use crate::domain::vo::tags::Tags;
+use crate::presentation::customer_service::dto::{
+    CellCountQuery, CellCountResponseDto, CellQuery, ClusterQuery, ClusterResponseDto,
+    CustomerServiceDataDto, CustomerServicePartialDataDto, CustomerServiceResponseDto,
+    NearbyCustomerServiceResponseDto, NearbyQuery, PageQuery, SearchQueryDto,
+    SearchResultResponseDto, tag_filters,
+};
+use actix_web::{HttpResponse, delete, get, patch, post, web};
+use chrono::{Datelike, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use validator::Validate;
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+// Sent with open_now while more places may follow, the next page passes them as scan_offset and
+// offset. A truncated page stopped scanning before it filled and may be short or empty
+const NEXT_SCAN_OFFSET_HEADER: &str = "X-Next-Scan-Offset";
+const NEXT_OFFSET_HEADER: &str = "X-Next-Offset";
+const SCAN_TRUNCATED_HEADER: &str = "X-Scan-Truncated";
+
+// Loaded once the write went through, failing to read the holidays must not fail it. The opening
+// status then only knows national holidays
+async fn calendar_after_write(
+    holiday_calendar_use_case: &web::Data<Arc<dyn HolidayCalendarUseCase>>,
+) -> HolidayCalendar {
+    let now = Utc::now();
+    match holiday_calendar_use_case.calendar_around(now).await {
+        Ok(holiday_calendar) => holiday_calendar,
+        Err(error) => {
+            log::warn!("could not load the holiday calendar: {}", error);
+            HolidayCalendar::brazil(now.year() - 1..=now.year() + 1, vec![])
+        }
+    }
+}
+
+#[post("")]
+pub async fn create_customer_service(
+    holiday_calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    create_use_case: web::Data<Arc<dyn CreateCustomerServiceUseCase>>,
+    principal: Principal,
+    customer_service_data: web::Json<CustomerServiceDataDto>,
+) -> HttpResponse {
+    if let Err(error) = customer_service_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let customer_service = match NewCustomerService::try_from(customer_service_data.into_inner()) {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match create_use_case
+        .create_customer_service(&principal, &customer_service)
+        .await
+    {
+        Ok(customer_service) => HttpResponse::Created().json(CustomerServiceResponseDto::new(
+            &customer_service,
+            &calendar_after_write(&holiday_calendar_use_case).await,
+        )),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("")]
+pub async fn list_customer_services(
+    holiday_calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    get_use_case: web::Data<Arc<dyn GetCustomerServiceUseCase>>,
+    page_query: web::Query<PageQuery>,
+    raw_query: web::Query<HashMap<String, String>>,
+) -> HttpResponse {
+    if let Err(error) = page_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let page_query = page_query.into_inner();
+    let category = match page_query
+        .category
+        .map(CustomerServiceCategory::new)
+        .transpose()
+    {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let tags = match Tags::new(tag_filters(&raw_query)) {
+        Ok(t) => t,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let sort = match page_query.sort.map(CustomerServiceSort::new).transpose() {
+        Ok(s) => s.unwrap_or_default(),
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let filter = CustomerServiceFilter {
+        open_at: page_query.open_now.unwrap_or(false).then(Utc::now),
+        categories: category.map(|category| HashSet::from([category])),
+        tags: (!tags.is_empty()).then_some(tags),
+        sort,
+    };
+    let holiday_calendar = match holiday_calendar_use_case.calendar_around(Utc::now()).await {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match get_use_case
+        .list_customer_services(
+            &filter,
+            page_query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+            page_query.offset.unwrap_or(0),
+            page_query.scan_offset.unwrap_or(0),
+        )
+        .await
+    {
+        Ok(page) => {
+            let mut response = HttpResponse::Ok();
+            if let Some(next) = page.next {
+                response
+                    .insert_header((NEXT_SCAN_OFFSET_HEADER, next.scan_offset))
+                    .insert_header((NEXT_OFFSET_HEADER, next.offset));
+            }
+            if page.truncated {
+                response.insert_header((SCAN_TRUNCATED_HEADER, "true"));
+            }
+            response.json(
+                page.customer_services
+                    .iter()
+                    .map(|customer_service| {
+                        CustomerServiceResponseDto::new(customer_service, &holiday_calendar)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        }
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/nearby")]
+pub async fn find_nearby_customer_services(
+    holiday_calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    find_nearby_use_case: web::Data<Arc<dyn FindNearbyCustomerServicesUseCase>>,
+    nearby_query: web::Query<NearbyQuery>,
+) -> HttpResponse {
+    if let Err(error) = nearby_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let nearby_query = nearby_query.into_inner();
+    let center = match GeoPoint::new(nearby_query.lat, nearby_query.lon) {
+        Ok(g) => g,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    let holiday_calendar = match holiday_calendar_use_case.calendar_around(Utc::now()).await {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match find_nearby_use_case
+        .find_nearby(
+            &center,
+            nearby_query.radius_m,
+            nearby_query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        )
+        .await
+    {
+        Ok(nearby) => HttpResponse::Ok().json(
+            nearby
+                .iter()
+                .map(|nearby| NearbyCustomerServiceResponseDto::new(nearby, &holiday_calendar))
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/search")]
+pub async fn search_customer_services(
+    holiday_calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    search_use_case: web::Data<Arc<dyn SearchCustomerServicesUseCase>>,
+    search_query: web::Query<SearchQueryDto>,
+) -> HttpResponse {
+    if let Err(error) = search_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let search_query = search_query.into_inner();
+    let query = match SearchQuery::new(&search_query.q) {
+        Ok(q) => q,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let near = match (search_query.lat, search_query.lon) {
+        (Some(lat), Some(lon)) => match GeoPoint::new(lat, lon) {
+            Ok(center) => Some((
+                center,
+                search_query
+                    .radius_m
+                    .unwrap_or(DEFAULT_SEARCH_RADIUS_METERS),
+            )),
+            Err(error) => return HttpResponse::from(AppError::from(error)),
+        },
+        (None, None) => None,
+        _ => {
+            return HttpResponse::from(AppError::Validation(ErrorData::new(
+                "invalid-latitude-longitude",
+                "latitude and longitude must be sent together",
+            )));
+        }
+    };
+
+    let holiday_calendar = match holiday_calendar_use_case.calendar_around(Utc::now()).await {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match search_use_case
+        .search(
+            &query,
+            near,
+            search_query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+            search_query.offset.unwrap_or(0),
+        )
+        .await
+    {
+        Ok(results) => HttpResponse::Ok().json(
+            results
+                .iter()
+                .map(|result| SearchResultResponseDto::new(result, &holiday_calendar))
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/clusters")]
+pub async fn cluster_customer_services(
+    cluster_use_case: web::Data<Arc<dyn ClusterCustomerServicesUseCase>>,
+    cluster_query: web::Query<ClusterQuery>,
+) -> HttpResponse {
+    if let Err(error) = cluster_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let bounding_box = match BoundingBox::parse(&cluster_query.bbox) {
+        Ok(b) => b,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match cluster_use_case
+        .cluster(&bounding_box, cluster_query.zoom)
+        .await
+    {
+        Ok(clusters) => HttpResponse::Ok().json(
+            clusters
+                .iter()
+                .map(ClusterResponseDto::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/cells/{geohash}")]
+pub async fn find_customer_services_by_cell(
+    holiday_calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    find_by_cell_use_case: web::Data<Arc<dyn FindCustomerServicesByCellUseCase>>,
+    geohash_path: web::Path<String>,
+    cell_query: web::Query<CellQuery>,
+) -> HttpResponse {
+    if let Err(error) = cell_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let cell = match Geohash::new(geohash_path.into_inner()) {
+        Ok(g) => g,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    let cell_query = cell_query.into_inner();
+    let holiday_calendar = match holiday_calendar_use_case.calendar_around(Utc::now()).await {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match find_by_cell_use_case
+        .find_in_cell(
+            &cell,
+            cell_query.neighbours.unwrap_or(false),
+            cell_query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        )
+        .await
+    {
+        Ok(customer_services) => HttpResponse::Ok().json(
+            customer_services
+                .iter()
+                .map(|customer_service| {
+                    CustomerServiceResponseDto::new(customer_service, &holiday_calendar)
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/cells/{geohash}/counts")]
+pub async fn count_customer_services_per_cell(
+    find_by_cell_use_case: web::Data<Arc<dyn FindCustomerServicesByCellUseCase>>,
+    geohash_path: web::Path<String>,
+    cell_count_query: web::Query<CellCountQuery>,
+) -> HttpResponse {
+    if let Err(error) = cell_count_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let within = match Geohash::new(geohash_path.into_inner()) {
+        Ok(g) => g,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match find_by_cell_use_case
+        .count_per_cell(&within, cell_count_query.precision)
+        .await
+    {
+        Ok(counts) => HttpResponse::Ok().json(
+            counts
+                .iter()
+                .map(CellCountResponseDto::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/{id}")]
+pub async fn get_customer_service_by_id(
+    holiday_calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    get_use_case: web::Data<Arc<dyn GetCustomerServiceUseCase>>,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    let customer_service_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    let holiday_calendar = match holiday_calendar_use_case.calendar_around(Utc::now()).await {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match get_use_case
+        .get_customer_service(&customer_service_id)
+        .await
+    {
+        Ok(customer_service) => HttpResponse::Ok().json(CustomerServiceResponseDto::new(
+            &customer_service,
+            &holiday_calendar,
+        )),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[patch("/{id}")]
+pub async fn patch_customer_service_by_id(
+    holiday_calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    update_use_case: web::Data<Arc<dyn UpdateCustomerServiceUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+    customer_service_partial_data: web::Json<CustomerServicePartialDataDto>,
+) -> HttpResponse {
+    if let Err(error) = customer_service_partial_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let customer_service_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let mut customer_service_partial =
+        match CustomerServicePartial::try_from(customer_service_partial_data.into_inner()) {
+            Ok(c) => c,
+            Err(error) => return HttpResponse::from(AppError::from(error)),
+        };
+    customer_service_partial.set_id(customer_service_id);
+
+    match update_use_case
+        .update_customer_service(&principal, &customer_service_partial)
+        .await
+    {
+        Ok(customer_service) => HttpResponse::Ok().json(CustomerServiceResponseDto::new(
+            &customer_service,
+            &calendar_after_write(&holiday_calendar_use_case).await,
+        )),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[delete("/{id}")]
+pub async fn delete_customer_service_by_id(
+    holiday_calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    delete_use_case: web::Data<Arc<dyn DeleteCustomerServiceUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    let customer_service_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match delete_use_case
+        .delete_customer_service(&principal, &customer_service_id)
+        .await
+    {
+        Ok(customer_service) => HttpResponse::Ok().json(CustomerServiceResponseDto::new(
+            &customer_service,
+            &calendar_after_write(&holiday_calendar_use_case).await,
+        )),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
@@ -0,0 +1,31 @@
+use crate::presentation::claim::claim_handler::create_customer_service_claim;
+use crate::presentation::customer_service::customer_service_handler::{
+    cluster_customer_services, count_customer_services_per_cell, create_customer_service,
+    delete_customer_service_by_id, find_customer_services_by_cell, find_nearby_customer_services,
+    get_customer_service_by_id, list_customer_services, patch_customer_service_by_id,
+    search_customer_services,
+};
+use crate::presentation::review::review_handler::{
+    create_customer_service_review, list_customer_service_reviews,
+};
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/customer-services")
+            .service(create_customer_service)
+            .service(list_customer_services)
+            // Must be registered before `/{id}` so "nearby", "search" or "clusters" is not taken for an id
+            .service(find_nearby_customer_services)
+            .service(search_customer_services)
+            .service(cluster_customer_services)
+            .service(find_customer_services_by_cell)
+            .service(count_customer_services_per_cell)
+            .service(create_customer_service_review)
+            .service(list_customer_service_reviews)
+            .service(create_customer_service_claim)
+            .service(get_customer_service_by_id)
+            .service(patch_customer_service_by_id)
+            .service(delete_customer_service_by_id),
+    );
+}
@@ -0,0 +1,519 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::domain::entity::customer_service::{
+    CellCount, CustomerService, CustomerServiceCluster, CustomerServicePartial,
+    CustomerServiceSearchResult, NearbyCustomerService, NewCustomerService,
+};
+use crate::domain::vo::address::{Address, AddressData};
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::description::Description;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::domain::vo::holiday_calendar::HolidayCalendar;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::opening_hours::OpeningHours;
+use crate::domain::vo::phone::{Country, Phone, PhoneLike};
+use crate::domain::vo::photo::Photo;
+use crate::domain::vo::tags::Tags;
+use crate::domain::vo::timezone::Timezone;
+use crate::domain::vo::url::Url;
+use crate::presentation::deserialize::deserialize_present;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct PhotoDto {
+    #[validate(url)]
+    url: String,
+    #[validate(length(max = 255))]
+    title: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct AddressDto {
+    #[validate(length(min = 1, max = 200))]
+    street: String,
+    #[validate(length(max = 20))]
+    number: Option<String>,
+    #[validate(length(max = 100))]
+    complement: Option<String>,
+    #[validate(length(max = 120))]
+    neighbourhood: Option<String>,
+    #[validate(length(min = 1, max = 120))]
+    city: String,
+    state: Option<String>,
+    #[validate(length(min = 1, max = 12))]
+    postal_code: String,
+    // ISO 3166-1 alpha-2, BR, AR, PT or US
+    country: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddressResponseDto {
+    #[serde(flatten)]
+    address: AddressDto,
+    single_line: String,
+}
+
+impl TryFrom<AddressDto> for Address {
+    type Error = Arc<dyn Error + Send + Sync>;
+
+    fn try_from(value: AddressDto) -> Result<Self, Self::Error> {
+        Address::new(AddressData {
+            street: value.street,
+            number: value.number,
+            complement: value.complement,
+            neighbourhood: value.neighbourhood,
+            city: value.city,
+            state: value.state,
+            postal_code: value.postal_code,
+            country: value.country,
+        })
+    }
+}
+
+impl AddressResponseDto {
+    pub fn new(value: &Address) -> Self {
+        Self {
+            address: AddressDto {
+                street: value.street(),
+                number: value.number(),
+                complement: value.complement(),
+                neighbourhood: value.neighbourhood(),
+                city: value.city().value(),
+                state: value.state(),
+                postal_code: value.formatted_postal_code(),
+                country: value.country().value(),
+            },
+            single_line: value.single_line(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CustomerServiceDataDto {
+    #[validate(length(min = 1, max = 120))]
+    name: String,
+    #[validate(length(max = 2048))]
+    description: Option<String>,
+    // Located from the address when both are left out
+    #[validate(range(min = -90.0, max = 90.0))]
+    latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    longitude: Option<f64>,
+    // Takes the place of state and city when sent
+    #[validate(nested)]
+    address: Option<AddressDto>,
+    #[validate(length(min = 1, max = 30))]
+    phone: String,
+    // ISO 3166 alpha-2 code, detected from the phone when left out
+    #[validate(length(equal = 2))]
+    phone_country: Option<String>,
+    #[validate(url)]
+    website: Option<String>,
+    #[validate(nested)]
+    #[serde(default)]
+    photos: Vec<PhotoDto>,
+    #[serde(default)]
+    tags: HashMap<String, String>,
+    #[serde(default)]
+    categories: Vec<String>,
+    #[validate(length(max = 1024))]
+    opening_hours: Option<String>,
+    timezone: Option<String>,
+    state: Option<String>,
+    #[validate(length(min = 1, max = 120))]
+    city: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CustomerServicePartialDataDto {
+    #[validate(length(min = 1, max = 120))]
+    name: Option<String>,
+    #[validate(length(max = 2048))]
+    description: Option<String>,
+    #[validate(range(min = -90.0, max = 90.0))]
+    latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    longitude: Option<f64>,
+    // null removes the address, the place keeps its city and state
+    #[serde(default, deserialize_with = "deserialize_present")]
+    #[validate(nested)]
+    address: Option<Option<AddressDto>>,
+    #[validate(length(min = 1, max = 30))]
+    phone: Option<String>,
+    // ISO 3166 alpha-2 code, detected from the phone when left out
+    #[validate(length(equal = 2))]
+    phone_country: Option<String>,
+    // null removes the website
+    #[serde(default, deserialize_with = "deserialize_present")]
+    #[validate(url)]
+    website: Option<Option<String>>,
+    #[validate(nested)]
+    photos: Option<Vec<PhotoDto>>,
+    tags: Option<HashMap<String, String>>,
+    categories: Option<Vec<String>>,
+    // null removes the opening hours
+    #[serde(default, deserialize_with = "deserialize_present")]
+    #[validate(length(max = 1024))]
+    opening_hours: Option<Option<String>>,
+    timezone: Option<String>,
+    state: Option<String>,
+    #[validate(length(min = 1, max = 120))]
+    city: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct PageQuery {
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 0))]
+    pub offset: Option<i64>,
+    pub open_now: Option<bool>,
+    // Category slug, also matches every category below it
+    pub category: Option<String>,
+    // "newest" (default) or "rating"
+    pub sort: Option<String>,
+    // With open_now, where the scan resumes, taken from the X-Next-Scan-Offset of the last page
+    #[validate(range(min = 0))]
+    pub scan_offset: Option<i64>,
+}
+
+const TAG_FILTER_PREFIX: &str = "tag.";
+
+// `tag.<key>=<value>` query parameters, `tag.payment:pix=yes` filters on `payment:pix`
+pub fn tag_filters(query: &HashMap<String, String>) -> HashMap<String, String> {
+    query
+        .iter()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(TAG_FILTER_PREFIX)
+                .map(|tag| (tag.to_string(), value.clone()))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct NearbyQuery {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+    #[validate(range(min = 1.0, max = 50000.0))]
+    pub radius_m: f64,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct SearchQueryDto {
+    #[validate(length(min = 1, max = 200))]
+    pub q: String,
+    // Ranks by relevance and distance together when both are sent
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: Option<f64>,
+    #[validate(range(min = 1.0, max = 50000.0))]
+    pub radius_m: Option<f64>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 0))]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CellQuery {
+    pub neighbours: Option<bool>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CellCountQuery {
+    #[validate(range(min = 1, max = 8))]
+    pub precision: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ClusterQuery {
+    pub bbox: String,
+    #[validate(range(max = 22))]
+    pub zoom: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerServiceResponseDto {
+    id: String,
+    name: String,
+    description: String,
+    latitude: f64,
+    longitude: f64,
+    address: Option<AddressResponseDto>,
+    phone: String,
+    phone_national: String,
+    phone_international: String,
+    phone_region: String,
+    phone_type: String,
+    website: Option<String>,
+    photos: Vec<PhotoDto>,
+    tags: BTreeMap<String, String>,
+    categories: Vec<String>,
+    opening_hours: Option<String>,
+    timezone: String,
+    state: Option<String>,
+    city: Option<String>,
+    open_now: Option<bool>,
+    next_open: Option<String>,
+    closes_at: Option<String>,
+    rating_average: Option<f64>,
+    rating_count: i64,
+    created_at: String,
+    updated_at: String,
+}
+
+fn photos_from_dto(photos: Vec<PhotoDto>) -> Result<Vec<Photo>, Arc<dyn Error + Send + Sync>> {
+    photos
+        .into_iter()
+        .map(|photo| Ok(Photo::new(Url::new(photo.url)?, photo.title)))
+        .collect()
+}
+
+fn categories_from_dto(
+    categories: Vec<String>,
+) -> Result<HashSet<CustomerServiceCategory>, Arc<dyn Error + Send + Sync>> {
+    categories
+        .into_iter()
+        .map(CustomerServiceCategory::new)
+        .collect()
+}
+
+impl TryFrom<CustomerServiceDataDto> for NewCustomerService {
+    type Error = Arc<dyn Error + Send + Sync>;
+
+    fn try_from(value: CustomerServiceDataDto) -> Result<Self, Self::Error> {
+        let location = match (value.latitude, value.longitude) {
+            (Some(latitude), Some(longitude)) => Some(GeoPoint::new(latitude, longitude)?),
+            (None, None) => None,
+            _ => {
+                return Err(Arc::new(AppError::Validation(ErrorData::new(
+                    "invalid-latitude-longitude",
+                    "latitude and longitude must be sent together",
+                ))));
+            }
+        };
+        let address: Option<Address> = value.address.map(Address::try_from).transpose()?;
+        let (state, city) = match &address {
+            Some(address) => (address.brazil_state(), Some(address.city())),
+            None => (
+                value.state.map(BrazilState::new).transpose()?,
+                value.city.map(Name::new).transpose()?,
+            ),
+        };
+        Ok(NewCustomerService {
+            name: Name::new(value.name)?,
+            description: Description::new(value.description.unwrap_or_default())?,
+            location,
+            address,
+            phone: phone_from_dto(value.phone, value.phone_country)?,
+            website: value.website.map(Url::new).transpose()?,
+            photos: photos_from_dto(value.photos)?,
+            tags: Tags::new(value.tags)?,
+            categories: categories_from_dto(value.categories)?,
+            opening_hours: value.opening_hours.map(OpeningHours::new).transpose()?,
+            timezone: value
+                .timezone
+                .map(Timezone::new)
+                .transpose()?
+                .unwrap_or_default(),
+            state,
+            city,
+        })
+    }
+}
+
+fn phone_from_dto(
+    phone: String,
+    country: Option<String>,
+) -> Result<Phone, Arc<dyn Error + Send + Sync>> {
+    match country {
+        Some(country) => Phone::new_for_country(Country::new(country)?, phone),
+        None => Phone::new(phone),
+    }
+}
+
+impl TryFrom<CustomerServicePartialDataDto> for CustomerServicePartial {
+    type Error = Arc<dyn Error + Send + Sync>;
+
+    fn try_from(value: CustomerServicePartialDataDto) -> Result<Self, Self::Error> {
+        let location = match (value.latitude, value.longitude) {
+            (Some(latitude), Some(longitude)) => Some(GeoPoint::new(latitude, longitude)?),
+            (None, None) => None,
+            _ => {
+                return Err(Arc::new(AppError::Validation(ErrorData::new(
+                    "invalid-latitude-longitude",
+                    "latitude and longitude must be sent together",
+                ))));
+            }
+        };
+
+        Ok(CustomerServicePartial {
+            id: None,
+            name: value.name.map(Name::new).transpose()?,
+            description: value.description.map(Description::new).transpose()?,
+            location,
+            address: value
+                .address
+                .map(|address| address.map(Address::try_from).transpose())
+                .transpose()?,
+            phone: value
+                .phone
+                .map(|phone| phone_from_dto(phone, value.phone_country))
+                .transpose()?,
+            website: value
+                .website
+                .map(|website| website.map(Url::new).transpose())
+                .transpose()?,
+            photos: value.photos.map(photos_from_dto).transpose()?,
+            tags: value.tags.map(Tags::new).transpose()?,
+            categories: value.categories.map(categories_from_dto).transpose()?,
+            opening_hours: value
+                .opening_hours
+                .map(|opening_hours| opening_hours.map(OpeningHours::new).transpose())
+                .transpose()?,
+            timezone: value.timezone.map(Timezone::new).transpose()?,
+            state: value.state.map(BrazilState::new).transpose()?,
+            city: value.city.map(Name::new).transpose()?,
+        })
+    }
+}
+
+impl CustomerServiceResponseDto {
+    pub fn new(value: &CustomerService, holiday_calendar: &HolidayCalendar) -> Self {
+        let (latitude, longitude) = value.location.value();
+        let mut categories: Vec<String> = value.categories.iter().map(|c| c.value()).collect();
+        categories.sort();
+        let opening_status = value.opening_status(Utc::now(), holiday_calendar);
+
+        Self {
+            id: value.id.value(),
+            name: value.name.value(),
+            description: value.description.value(),
+            latitude,
+            longitude,
+            address: value.address.as_ref().map(AddressResponseDto::new),
+            phone: value.phone.value(),
+            phone_national: value.phone.national_format(),
+            phone_international: value.phone.international_format(),
+            phone_region: value.phone.country().value(),
+            phone_type: value.phone.line_type().value(),
+            website: value.website.as_ref().map(|w| w.as_str().to_string()),
+            photos: value
+                .photos
+                .iter()
+                .map(|photo| PhotoDto {
+                    url: photo.url.as_str().to_string(),
+                    title: photo.title.clone(),
+                })
+                .collect(),
+            tags: value.tags.value(),
+            categories,
+            opening_hours: value.opening_hours.as_ref().map(|o| o.value()),
+            timezone: value.timezone.value(),
+            state: value.state.as_ref().map(|state| state.value()),
+            city: value.city.as_ref().map(|city| city.value()),
+            open_now: opening_status.as_ref().map(|status| status.open),
+            next_open: opening_status
+                .as_ref()
+                .and_then(|status| status.next_open)
+                .map(|next_open| next_open.to_rfc3339()),
+            closes_at: opening_status
+                .as_ref()
+                .and_then(|status| status.closes_at)
+                .map(|closes_at| closes_at.to_rfc3339()),
+            rating_average: value.rating.average(),
+            rating_count: value.rating.count(),
+            created_at: value.created_at.value(),
+            updated_at: value.updated_at.value(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearbyCustomerServiceResponseDto {
+    #[serde(flatten)]
+    customer_service: CustomerServiceResponseDto,
+    distance_m: f64,
+}
+
+impl NearbyCustomerServiceResponseDto {
+    pub fn new(value: &NearbyCustomerService, holiday_calendar: &HolidayCalendar) -> Self {
+        Self {
+            customer_service: CustomerServiceResponseDto::new(
+                &value.customer_service,
+                holiday_calendar,
+            ),
+            distance_m: (value.distance_meters * 10.0).round() / 10.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResultResponseDto {
+    #[serde(flatten)]
+    customer_service: CustomerServiceResponseDto,
+    score: f64,
+    distance_m: Option<f64>,
+}
+
+impl SearchResultResponseDto {
+    pub fn new(value: &CustomerServiceSearchResult, holiday_calendar: &HolidayCalendar) -> Self {
+        Self {
+            customer_service: CustomerServiceResponseDto::new(
+                &value.customer_service,
+                holiday_calendar,
+            ),
+            score: value.score,
+            distance_m: value
+                .distance_meters
+                .map(|distance_meters| (distance_meters * 10.0).round() / 10.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellCountResponseDto {
+    cell: String,
+    count: i64,
+}
+
+impl From<&CellCount> for CellCountResponseDto {
+    fn from(value: &CellCount) -> Self {
+        Self {
+            cell: value.cell.value(),
+            count: value.count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterResponseDto {
+    latitude: f64,
+    longitude: f64,
+    count: i64,
+    representative_id: String,
+}
+
+impl From<&CustomerServiceCluster> for ClusterResponseDto {
+    fn from(value: &CustomerServiceCluster) -> Self {
+        let (latitude, longitude) = value.centroid.value();
+        Self {
+            latitude,
+            longitude,
+            count: value.count,
+            representative_id: value.representative_id.value(),
+        }
+    }
+}
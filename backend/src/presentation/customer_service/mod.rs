@@ -0,0 +1,3 @@
+pub mod customer_service_handler;
+pub mod customer_service_route;
+pub mod dto;
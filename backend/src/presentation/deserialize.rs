@@ -0,0 +1,35 @@
+use serde::{Deserialize, Deserializer};
+
+// Tells an explicit null apart from a missing field
+pub fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Patch {
+        #[serde(default, deserialize_with = "deserialize_present")]
+        website: Option<Option<String>>,
+    }
+
+    fn website(json: &str) -> Option<Option<String>> {
+        serde_json::from_str::<Patch>(json).unwrap().website
+    }
+
+    #[test]
+    fn missing_null_and_value_are_told_apart() {
+        assert_eq!(website("{}"), None);
+        assert_eq!(website(r#"{"website":null}"#), Some(None));
+        assert_eq!(
+            website(r#"{"website":"https://example.com"}"#),
+            Some(Some("https://example.com".to_string()))
+        );
+    }
+}
@@ -23,6 +23,10 @@ impl From<AppError> for HttpResponse {
             AppError::Unauthorized(ed) => HttpResponse::Unauthorized().json(
                 get_error_json_response(StatusCode::UNAUTHORIZED.as_u16(), ed),
             ),
+            AppError::Forbidden(ed) => HttpResponse::Forbidden()
+                .json(get_error_json_response(StatusCode::FORBIDDEN.as_u16(), ed)),
+            AppError::Conflict(ed) => HttpResponse::Conflict()
+                .json(get_error_json_response(StatusCode::CONFLICT.as_u16(), ed)),
             AppError::UnprocessableEntity(ed) => HttpResponse::UnprocessableEntity().json(
                 get_error_json_response(StatusCode::UNPROCESSABLE_ENTITY.as_u16(), ed),
             ),
@@ -0,0 +1,54 @@
+use crate::domain::vo::gazetteer::{GazetteerEntry, NearestLocality};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct GeocodeQuery {
+    // A place name, optionally narrowed as in "Pinheiros, São Paulo, SP"
+    #[validate(length(min = 1, max = 200))]
+    pub q: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ReverseGeocodeQuery {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaceResponseDto {
+    kind: String,
+    name: String,
+    city: String,
+    state: Option<String>,
+    country: String,
+    latitude: f64,
+    longitude: f64,
+    distance_m: Option<f64>,
+}
+
+impl From<&GazetteerEntry> for PlaceResponseDto {
+    fn from(value: &GazetteerEntry) -> Self {
+        PlaceResponseDto {
+            kind: value.kind.value(),
+            name: value.name.clone(),
+            city: value.city_name().to_string(),
+            state: value.state.clone(),
+            country: value.country.clone(),
+            latitude: value.location.lat,
+            longitude: value.location.lon,
+            distance_m: None,
+        }
+    }
+}
+
+impl From<&NearestLocality> for PlaceResponseDto {
+    fn from(value: &NearestLocality) -> Self {
+        PlaceResponseDto {
+            distance_m: Some((value.distance_meters * 10.0).round() / 10.0),
+            ..PlaceResponseDto::from(&value.entry)
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::common::error::AppError;
+use crate::domain::usecase::geocoding::geocode::GeocodeUseCase;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::presentation::geocoding::dto::{GeocodeQuery, PlaceResponseDto, ReverseGeocodeQuery};
+use actix_web::{HttpResponse, get, web};
+use std::sync::Arc;
+use validator::Validate;
+
+#[get("")]
+pub async fn geocode(
+    geocode_use_case: web::Data<Arc<dyn GeocodeUseCase>>,
+    geocode_query: web::Query<GeocodeQuery>,
+) -> HttpResponse {
+    if let Err(error) = geocode_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    match geocode_use_case.geocode(&geocode_query.q) {
+        Ok(entry) => HttpResponse::Ok().json(PlaceResponseDto::from(&entry)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("")]
+pub async fn reverse_geocode(
+    geocode_use_case: web::Data<Arc<dyn GeocodeUseCase>>,
+    reverse_geocode_query: web::Query<ReverseGeocodeQuery>,
+) -> HttpResponse {
+    if let Err(error) = reverse_geocode_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let point = match GeoPoint::new(reverse_geocode_query.lat, reverse_geocode_query.lon) {
+        Ok(p) => p,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match geocode_use_case.reverse_geocode(&point) {
+        Ok(locality) => HttpResponse::Ok().json(PlaceResponseDto::from(&locality)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
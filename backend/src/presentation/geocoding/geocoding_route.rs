@@ -0,0 +1,8 @@
+use crate::presentation::geocoding::geocoding_handler::{geocode, reverse_geocode};
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config
+        .service(web::scope("/geocode").service(geocode))
+        .service(web::scope("/reverse-geocode").service(reverse_geocode));
+}
@@ -0,0 +1,3 @@
+pub mod dto;
+pub mod geocoding_handler;
+pub mod geocoding_route;
@@ -0,0 +1,77 @@
+use crate::domain::entity::holiday::{Holiday, ObservedHoliday};
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::name::Name;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct HolidayDataDto {
+    #[validate(length(min = 1, max = 120))]
+    name: String,
+    // ISO 8601 calendar date, the year is ignored for recurring holidays
+    date: NaiveDate,
+    #[serde(default)]
+    recurring: bool,
+    state: Option<String>,
+    #[validate(length(min = 1, max = 120))]
+    city: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct HolidayQuery {
+    #[validate(range(min = 1900, max = 2100))]
+    pub year: Option<i32>,
+    pub state: Option<String>,
+    #[validate(length(min = 1, max = 120))]
+    pub city: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayResponseDto {
+    id: Option<String>,
+    name: String,
+    date: NaiveDate,
+    state: Option<String>,
+    city: Option<String>,
+}
+
+impl TryFrom<HolidayDataDto> for Holiday {
+    type Error = Arc<dyn Error + Send + Sync>;
+
+    fn try_from(value: HolidayDataDto) -> Result<Self, Self::Error> {
+        Holiday::new(
+            Name::new(value.name)?,
+            value.date,
+            value.recurring,
+            value.state.map(BrazilState::new).transpose()?,
+            value.city.map(Name::new).transpose()?,
+        )
+    }
+}
+
+impl From<&Holiday> for HolidayResponseDto {
+    fn from(value: &Holiday) -> Self {
+        HolidayResponseDto {
+            id: Some(value.id.value()),
+            name: value.name.value(),
+            date: value.date,
+            state: value.state.as_ref().map(|s| s.value()),
+            city: value.city.as_ref().map(|c| c.value()),
+        }
+    }
+}
+
+impl From<&ObservedHoliday> for HolidayResponseDto {
+    fn from(value: &ObservedHoliday) -> Self {
+        HolidayResponseDto {
+            id: value.id.as_ref().map(|i| i.value()),
+            name: value.name.clone(),
+            date: value.date,
+            state: value.state.as_ref().map(|s| s.value()),
+            city: value.city.as_ref().map(|c| c.value()),
+        }
+    }
+}
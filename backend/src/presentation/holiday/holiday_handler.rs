@@ -0,0 +1,91 @@
+use crate::common::error::AppError;
+use crate::domain::entity::holiday::Holiday;
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::holiday::holiday_calendar::HolidayCalendarUseCase;
+use crate::domain::usecase::holiday::manage_holidays::ManageHolidaysUseCase;
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::name::Name;
+use crate::presentation::holiday::dto::{HolidayDataDto, HolidayQuery, HolidayResponseDto};
+use actix_web::{HttpResponse, delete, get, post, web};
+use chrono::{Datelike, Utc};
+use std::sync::Arc;
+use validator::Validate;
+
+#[post("")]
+pub async fn create_holiday(
+    manage_use_case: web::Data<Arc<dyn ManageHolidaysUseCase>>,
+    principal: Principal,
+    holiday_data: web::Json<HolidayDataDto>,
+) -> HttpResponse {
+    if let Err(error) = holiday_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let holiday = match Holiday::try_from(holiday_data.into_inner()) {
+        Ok(h) => h,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.create_holiday(&principal, &holiday).await {
+        Ok(holiday) => HttpResponse::Created().json(HolidayResponseDto::from(&holiday)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("")]
+pub async fn list_holidays(
+    calendar_use_case: web::Data<Arc<dyn HolidayCalendarUseCase>>,
+    holiday_query: web::Query<HolidayQuery>,
+) -> HttpResponse {
+    if let Err(error) = holiday_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let holiday_query = holiday_query.into_inner();
+    let state = match holiday_query.state.map(BrazilState::new).transpose() {
+        Ok(s) => s,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let city = match holiday_query.city.map(Name::new).transpose() {
+        Ok(c) => c,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match calendar_use_case
+        .list_holidays(
+            holiday_query.year.unwrap_or_else(|| Utc::now().year()),
+            state.as_ref(),
+            city.as_ref(),
+        )
+        .await
+    {
+        Ok(holidays) => HttpResponse::Ok().json(
+            holidays
+                .iter()
+                .map(HolidayResponseDto::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[delete("/{id}")]
+pub async fn delete_holiday_by_id(
+    manage_use_case: web::Data<Arc<dyn ManageHolidaysUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    let holiday_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case
+        .delete_holiday(&principal, &holiday_id)
+        .await
+    {
+        Ok(holiday) => HttpResponse::Ok().json(HolidayResponseDto::from(&holiday)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
@@ -0,0 +1,13 @@
+use crate::presentation::holiday::holiday_handler::{
+    create_holiday, delete_holiday_by_id, list_holidays,
+};
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/holidays")
+            .service(create_holiday)
+            .service(list_holidays)
+            .service(delete_holiday_by_id),
+    );
+}
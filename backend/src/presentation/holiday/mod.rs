@@ -0,0 +1,3 @@
+pub mod dto;
+pub mod holiday_handler;
+pub mod holiday_route;
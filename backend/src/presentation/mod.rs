@@ -1,2 +1,14 @@
+pub mod auth;
+pub mod autocomplete;
+pub mod category;
+pub mod claim;
+pub mod customer_service;
+pub mod deserialize;
 pub mod error_handler;
+pub mod geocoding;
+pub mod holiday;
+pub mod moderation;
+pub mod person;
+pub mod review;
+pub mod tile;
 pub mod user;
@@ -0,0 +1,65 @@
+use crate::domain::entity::moderation_flag::ModerationFlag;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct FlagDataDto {
+    // "review" or "customer-service"
+    pub subject_type: String,
+    pub subject_id: String,
+    #[validate(length(max = 2048))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct QueueQuery {
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 0))]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationFlagResponseDto {
+    id: String,
+    subject_type: String,
+    subject_id: String,
+    reason: String,
+    reporter_id: Option<String>,
+    note: String,
+    status: String,
+    moderator_id: Option<String>,
+    decided_at: Option<String>,
+    created_at: String,
+    // Text of the place before the flagged edit, restored when the flag is rejected
+    previous_name: Option<String>,
+    previous_description: Option<String>,
+}
+
+impl From<&ModerationFlag> for ModerationFlagResponseDto {
+    fn from(value: &ModerationFlag) -> Self {
+        Self {
+            id: value.id.value(),
+            subject_type: value.subject.kind(),
+            subject_id: value.subject.id().value(),
+            reason: value.reason.value(),
+            reporter_id: value.reporter_id.map(|reporter_id| reporter_id.value()),
+            note: value.note.value(),
+            status: value.status.value(),
+            moderator_id: value.moderator_id.map(|moderator_id| moderator_id.value()),
+            decided_at: value
+                .decided_at
+                .as_ref()
+                .map(|decided_at| decided_at.value()),
+            created_at: value.created_at.value(),
+            previous_name: value
+                .previous_text
+                .as_ref()
+                .map(|previous_text| previous_text.name.value()),
+            previous_description: value
+                .previous_text
+                .as_ref()
+                .map(|previous_text| previous_text.description.value()),
+        }
+    }
+}
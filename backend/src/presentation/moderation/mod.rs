@@ -0,0 +1,3 @@
+pub mod dto;
+pub mod moderation_handler;
+pub mod moderation_route;
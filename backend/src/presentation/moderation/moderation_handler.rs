@@ -0,0 +1,127 @@
+use crate::common::error::AppError;
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::moderation::flag_content::FlagContentUseCase;
+use crate::domain::usecase::moderation::moderate_content::ModerateContentUseCase;
+use crate::domain::vo::description::Description;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::moderation::{ModerationStatus, ModerationSubject};
+use crate::presentation::moderation::dto::{FlagDataDto, ModerationFlagResponseDto, QueueQuery};
+use actix_web::{HttpResponse, get, post, web};
+use std::sync::Arc;
+use validator::Validate;
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+
+#[post("/flags")]
+pub async fn create_flag(
+    flag_content_use_case: web::Data<Arc<dyn FlagContentUseCase>>,
+    principal: Principal,
+    flag_data: web::Json<FlagDataDto>,
+) -> HttpResponse {
+    if let Err(error) = flag_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let flag_data = flag_data.into_inner();
+    let subject = match Id::new_from_string(flag_data.subject_id)
+        .and_then(|id| ModerationSubject::new(flag_data.subject_type, id))
+    {
+        Ok(s) => s,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let note = match Description::new(flag_data.note.unwrap_or_default()) {
+        Ok(n) => n,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match flag_content_use_case
+        .report(&principal, &subject, &note)
+        .await
+    {
+        Ok(flag) => HttpResponse::Created().json(ModerationFlagResponseDto::from(&flag)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/queue")]
+pub async fn list_moderation_queue(
+    moderate_use_case: web::Data<Arc<dyn ModerateContentUseCase>>,
+    principal: Principal,
+    queue_query: web::Query<QueueQuery>,
+) -> HttpResponse {
+    if let Err(error) = queue_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    match moderate_use_case
+        .list_queue(
+            &principal,
+            queue_query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+            queue_query.offset.unwrap_or(0),
+        )
+        .await
+    {
+        Ok(flags) => HttpResponse::Ok().json(
+            flags
+                .iter()
+                .map(ModerationFlagResponseDto::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+async fn decide_flag(
+    moderate_use_case: &Arc<dyn ModerateContentUseCase>,
+    principal: &Principal,
+    id_path: String,
+    decision: ModerationStatus,
+) -> HttpResponse {
+    let flag_id = match Id::new_from_string(id_path) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match moderate_use_case
+        .decide(principal, &flag_id, decision)
+        .await
+    {
+        Ok(flags) => HttpResponse::Ok().json(
+            flags
+                .iter()
+                .map(ModerationFlagResponseDto::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[post("/flags/{id}/approve")]
+pub async fn approve_flag(
+    moderate_use_case: web::Data<Arc<dyn ModerateContentUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    decide_flag(
+        &moderate_use_case,
+        &principal,
+        id_path.into_inner(),
+        ModerationStatus::Approved,
+    )
+    .await
+}
+
+#[post("/flags/{id}/reject")]
+pub async fn reject_flag(
+    moderate_use_case: web::Data<Arc<dyn ModerateContentUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    decide_flag(
+        &moderate_use_case,
+        &principal,
+        id_path.into_inner(),
+        ModerationStatus::Rejected,
+    )
+    .await
+}
@@ -0,0 +1,14 @@
+use crate::presentation::moderation::moderation_handler::{
+    approve_flag, create_flag, list_moderation_queue, reject_flag,
+};
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/moderation")
+            .service(create_flag)
+            .service(list_moderation_queue)
+            .service(approve_flag)
+            .service(reject_flag),
+    );
+}
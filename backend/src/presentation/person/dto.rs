@@ -0,0 +1,77 @@
+use crate::domain::entity::person::Person;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::tax_id::{TaxCountry, TaxId};
+use crate::domain::vo::temporal::Date;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct PersonDataDto {
+    #[validate(length(min = 1, max = 120))]
+    name: String,
+    #[validate(length(min = 1, max = 120))]
+    social_name: Option<String>,
+    // ISO 8601 calendar date
+    birth_date: NaiveDate,
+    // Masked or not, e.g. `123.456.789-09`
+    #[validate(length(min = 9, max = 20))]
+    tax_id: String,
+    // ISO 3166 alpha-2 code, detected from the tax id when left out
+    #[validate(length(equal = 2))]
+    tax_id_country: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonResponseDto {
+    id: String,
+    user_id: String,
+    // Social name when there is one, legal name otherwise
+    display_name: String,
+    name: String,
+    social_name: Option<String>,
+    birth_date: String,
+    tax_id: String,
+    tax_id_country: String,
+    created_at: String,
+    updated_at: String,
+}
+
+impl PersonDataDto {
+    pub fn into_person(self, user_id: Id) -> Result<Person, Arc<dyn Error + Send + Sync>> {
+        let tax_id = match self.tax_id_country {
+            Some(country) => TaxId::new_for_country(TaxCountry::new(country)?, self.tax_id)?,
+            None => TaxId::new(self.tax_id)?,
+        };
+        Person::new(
+            user_id,
+            Name::new(self.name)?,
+            Date::new_from_naive_date(self.birth_date),
+            self.social_name.map(Name::new).transpose()?,
+            tax_id,
+        )
+    }
+}
+
+impl From<&Person> for PersonResponseDto {
+    fn from(value: &Person) -> Self {
+        Self {
+            id: value.id.value(),
+            user_id: value.user_id.value(),
+            display_name: value.display_name().value(),
+            name: value.name.value(),
+            social_name: value
+                .social_name
+                .as_ref()
+                .map(|social_name| social_name.value()),
+            birth_date: value.birth_date.value(),
+            tax_id: value.tax_id.format().unwrap_or_default(),
+            tax_id_country: value.tax_id.country().value(),
+            created_at: value.created_at.value(),
+            updated_at: value.updated_at.value(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::common::error::AppError;
+use crate::domain::entity::principal::Principal;
+use crate::domain::usecase::person::manage_person::ManagePersonUseCase;
+use crate::domain::vo::id::Id;
+use crate::presentation::person::dto::{PersonDataDto, PersonResponseDto};
+use actix_web::{HttpResponse, get, put, web};
+use std::sync::Arc;
+use validator::Validate;
+
+// Registered under the users scope
+#[get("/users/{id}/person")]
+pub async fn get_user_person(
+    manage_use_case: web::Data<Arc<dyn ManagePersonUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    let user_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.get_person(&principal, &user_id).await {
+        Ok(person) => HttpResponse::Ok().json(PersonResponseDto::from(&person)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+// Registered under the users scope
+#[put("/users/{id}/person")]
+pub async fn put_user_person(
+    manage_use_case: web::Data<Arc<dyn ManagePersonUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+    person_data: web::Json<PersonDataDto>,
+) -> HttpResponse {
+    if let Err(error) = person_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let user_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let person = match person_data.into_inner().into_person(user_id) {
+        Ok(p) => p,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.save_person(&principal, &person).await {
+        Ok(person) => HttpResponse::Ok().json(PersonResponseDto::from(&person)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
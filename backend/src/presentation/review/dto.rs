@@ -0,0 +1,83 @@
+use crate::domain::entity::review::{Review, ReviewPartial};
+use crate::domain::vo::description::Description;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::rating::Rating;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ReviewDataDto {
+    #[validate(range(min = 1, max = 5))]
+    rating: i16,
+    #[validate(length(max = 2048))]
+    text: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ReviewPartialDataDto {
+    #[validate(range(min = 1, max = 5))]
+    rating: Option<i16>,
+    #[validate(length(max = 2048))]
+    text: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ReviewPageQuery {
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 0))]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewResponseDto {
+    id: String,
+    customer_service_id: String,
+    author_id: String,
+    rating: i16,
+    text: String,
+    created_at: String,
+    updated_at: String,
+}
+
+impl ReviewDataDto {
+    pub fn into_review(
+        self,
+        customer_service_id: Id,
+        author_id: Id,
+    ) -> Result<Review, Arc<dyn Error + Send + Sync>> {
+        Review::new(
+            customer_service_id,
+            author_id,
+            Rating::new(self.rating)?,
+            Description::new(self.text.unwrap_or_default())?,
+        )
+    }
+}
+
+impl TryFrom<ReviewPartialDataDto> for ReviewPartial {
+    type Error = Arc<dyn Error + Send + Sync>;
+
+    fn try_from(value: ReviewPartialDataDto) -> Result<Self, Self::Error> {
+        Ok(ReviewPartial {
+            rating: value.rating.map(Rating::new).transpose()?,
+            text: value.text.map(Description::new).transpose()?,
+        })
+    }
+}
+
+impl From<&Review> for ReviewResponseDto {
+    fn from(value: &Review) -> Self {
+        Self {
+            id: value.id.value(),
+            customer_service_id: value.customer_service_id.value(),
+            author_id: value.author_id.value(),
+            rating: value.rating.value(),
+            text: value.text.value(),
+            created_at: value.created_at.value(),
+            updated_at: value.updated_at.value(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dto;
+pub mod review_handler;
+pub mod review_route;
@@ -0,0 +1,140 @@
+use crate::common::error::AppError;
+use crate::domain::entity::principal::Principal;
+use crate::domain::entity::review::ReviewPartial;
+use crate::domain::usecase::review::get_review::GetReviewUseCase;
+use crate::domain::usecase::review::manage_reviews::ManageReviewsUseCase;
+use crate::domain::vo::id::Id;
+use crate::presentation::review::dto::{
+    ReviewDataDto, ReviewPageQuery, ReviewPartialDataDto, ReviewResponseDto,
+};
+use actix_web::{HttpResponse, delete, get, patch, post, web};
+use std::sync::Arc;
+use validator::Validate;
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+
+// Registered under the `/customer-services` scope
+#[post("/{id}/reviews")]
+pub async fn create_customer_service_review(
+    manage_use_case: web::Data<Arc<dyn ManageReviewsUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+    review_data: web::Json<ReviewDataDto>,
+) -> HttpResponse {
+    if let Err(error) = review_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let customer_service_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let review = match review_data
+        .into_inner()
+        .into_review(customer_service_id, principal.user_id)
+    {
+        Ok(r) => r,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.create_review(&principal, &review).await {
+        Ok(review) => HttpResponse::Created().json(ReviewResponseDto::from(&review)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+// Registered under the `/customer-services` scope
+#[get("/{id}/reviews")]
+pub async fn list_customer_service_reviews(
+    get_use_case: web::Data<Arc<dyn GetReviewUseCase>>,
+    id_path: web::Path<String>,
+    page_query: web::Query<ReviewPageQuery>,
+) -> HttpResponse {
+    if let Err(error) = page_query.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let customer_service_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match get_use_case
+        .list_reviews(
+            &customer_service_id,
+            page_query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+            page_query.offset.unwrap_or(0),
+        )
+        .await
+    {
+        Ok(reviews) => HttpResponse::Ok().json(
+            reviews
+                .iter()
+                .map(ReviewResponseDto::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[get("/{id}")]
+pub async fn get_review_by_id(
+    get_use_case: web::Data<Arc<dyn GetReviewUseCase>>,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    let review_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match get_use_case.get_review(&review_id).await {
+        Ok(review) => HttpResponse::Ok().json(ReviewResponseDto::from(&review)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[patch("/{id}")]
+pub async fn patch_review_by_id(
+    manage_use_case: web::Data<Arc<dyn ManageReviewsUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+    review_partial_data: web::Json<ReviewPartialDataDto>,
+) -> HttpResponse {
+    if let Err(error) = review_partial_data.validate() {
+        return HttpResponse::from(AppError::from(error));
+    }
+
+    let review_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let review_partial = match ReviewPartial::try_from(review_partial_data.into_inner()) {
+        Ok(r) => r,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case
+        .update_review(&principal, &review_id, &review_partial)
+        .await
+    {
+        Ok(review) => HttpResponse::Ok().json(ReviewResponseDto::from(&review)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[delete("/{id}")]
+pub async fn delete_review_by_id(
+    manage_use_case: web::Data<Arc<dyn ManageReviewsUseCase>>,
+    principal: Principal,
+    id_path: web::Path<String>,
+) -> HttpResponse {
+    let review_id = match Id::new_from_string(id_path.into_inner()) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_use_case.delete_review(&principal, &review_id).await {
+        Ok(review) => HttpResponse::Ok().json(ReviewResponseDto::from(&review)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
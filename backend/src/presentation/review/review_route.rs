@@ -0,0 +1,13 @@
+use crate::presentation::review::review_handler::{
+    delete_review_by_id, get_review_by_id, patch_review_by_id,
+};
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/reviews")
+            .service(get_review_by_id)
+            .service(patch_review_by_id)
+            .service(delete_review_by_id),
+    );
+}
@@ -0,0 +1,2 @@
+pub mod tile_handler;
+pub mod tile_route;
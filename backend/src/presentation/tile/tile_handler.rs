@@ -0,0 +1,57 @@
+use crate::common::error::AppError;
+use crate::domain::usecase::customer_service::get_customer_service_tile::GetCustomerServiceTileUseCase;
+use crate::domain::vo::tile::Tile;
+use actix_web::http::header::{self, CacheControl, CacheDirective, EntityTag, IfNoneMatch};
+use actix_web::{HttpResponse, get, web};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+const MVT_CONTENT_TYPE: &str = "application/vnd.mapbox-vector-tile";
+const TILE_MAX_AGE_SECONDS: u32 = 60;
+
+// Strong validator over the encoded bytes, an unchanged tile answers 304 without a body
+fn tile_etag(tile_bytes: &[u8]) -> EntityTag {
+    EntityTag::new_strong(hex::encode(&Sha256::digest(tile_bytes)[..16]))
+}
+
+#[get("/customer-services/{z}/{x}/{y:\\d+}.mvt")]
+pub async fn get_customer_service_tile(
+    get_tile_use_case: web::Data<Arc<dyn GetCustomerServiceTileUseCase>>,
+    tile_path: web::Path<(u8, u32, u32)>,
+    if_none_match: Option<web::Header<IfNoneMatch>>,
+) -> HttpResponse {
+    let (z, x, y) = tile_path.into_inner();
+    let tile = match Tile::new(z, x, y) {
+        Ok(t) => t,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    let tile_bytes = match get_tile_use_case.get_tile(&tile).await {
+        Ok(bytes) => bytes,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    let etag = tile_etag(&tile_bytes);
+    let cache_control = CacheControl(vec![
+        CacheDirective::Public,
+        CacheDirective::MaxAge(TILE_MAX_AGE_SECONDS),
+    ]);
+
+    let not_modified = match if_none_match.map(|h| h.into_inner()) {
+        Some(IfNoneMatch::Any) => true,
+        Some(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&etag)),
+        None => false,
+    };
+    if not_modified {
+        return HttpResponse::NotModified()
+            .insert_header(header::ETag(etag))
+            .insert_header(cache_control)
+            .finish();
+    }
+
+    HttpResponse::Ok()
+        .content_type(MVT_CONTENT_TYPE)
+        .insert_header(header::ETag(etag))
+        .insert_header(cache_control)
+        .body(tile_bytes)
+}
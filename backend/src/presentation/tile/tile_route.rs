@@ -0,0 +1,6 @@
+use crate::presentation::tile::tile_handler::get_customer_service_tile;
+use actix_web::web;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(web::scope("/tiles").service(get_customer_service_tile));
+}
@@ -1,7 +1,9 @@
 use crate::domain::entity::user::{User, UserPartial};
 use crate::domain::vo::password::Password;
+use crate::domain::vo::role::Role;
 use crate::domain::vo::{email::Email, id::Id, name::Name, temporal::DateTime};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::error::Error;
 use std::sync::Arc;
 use validator::Validate;
@@ -40,8 +42,13 @@ pub struct UserDataResponseDto {
     email: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserRolesResponseDto {
+    roles: Vec<String>,
+}
+
 impl TryFrom<UserDataDto> for User {
-    type Error = Arc<dyn Error>;
+    type Error = Arc<dyn Error + Send + Sync>;
 
     fn try_from(value: UserDataDto) -> Result<Self, Self::Error> {
         let id = Id::new()?;
@@ -63,7 +70,7 @@ impl TryFrom<UserDataDto> for User {
 }
 
 impl TryFrom<UserPartialDataDto> for UserPartial {
-    type Error = Arc<dyn Error>;
+    type Error = Arc<dyn Error + Send + Sync>;
 
     fn try_from(value: UserPartialDataDto) -> Result<Self, Self::Error> {
         let id: Option<Id>;
@@ -129,3 +136,11 @@ impl From<&User> for UserDataResponseDto {
         }
     }
 }
+
+impl From<&HashSet<Role>> for UserRolesResponseDto {
+    fn from(value: &HashSet<Role>) -> Self {
+        let mut roles: Vec<String> = value.iter().map(|role| role.value()).collect();
+        roles.sort();
+        Self { roles }
+    }
+}
@@ -1,15 +1,18 @@
 use crate::common::error::AppError;
+use crate::domain::entity::principal::Principal;
 use crate::domain::entity::user::{User, UserPartial};
 use crate::domain::usecase::user::create_user::CreateUserUseCase;
 use crate::domain::usecase::user::delete_user::DeleteUserUseCase;
+use crate::domain::usecase::user::manage_user_roles::ManageUserRolesUseCase;
 use crate::domain::usecase::user::update_user::UpdateUserUseCase;
 use crate::domain::vo::email::Email;
 use crate::domain::vo::id::Id;
+use crate::domain::vo::role::Role;
 use crate::presentation::user::dto::{
-    QueryFilter, UserDataDto, UserDataResponseDto, UserPartialDataDto,
+    QueryFilter, UserDataDto, UserDataResponseDto, UserPartialDataDto, UserRolesResponseDto,
 };
 use crate::repositories::user::user_repository::UserRepository;
-use actix_web::{HttpResponse, delete, get, patch, post, web};
+use actix_web::{HttpResponse, delete, get, patch, post, put, web};
 use serde_json::json;
 use std::sync::Arc;
 use validator::Validate;
@@ -90,6 +93,7 @@ pub async fn get_user_by_email(
 #[patch("/users/{id}")]
 pub async fn patch_user_by_id(
     update_use_case: web::Data<Arc<dyn UpdateUserUseCase>>,
+    principal: Principal,
     id_path: web::Path<String>,
     user_partial_data: web::Json<UserPartialDataDto>,
 ) -> HttpResponse {
@@ -104,7 +108,7 @@ pub async fn patch_user_by_id(
     };
     user_partial.set_id(Id::new_from_string(id_path.into_inner()).unwrap());
 
-    let update_user_result = update_use_case.update_user(&user_partial).await;
+    let update_user_result = update_use_case.update_user(&principal, &user_partial).await;
     match update_user_result {
         Ok(user) => {
             let user_response = UserDataResponseDto::from(&user);
@@ -117,10 +121,13 @@ pub async fn patch_user_by_id(
 #[delete("/users/{id}")]
 pub async fn delete_user_by_id(
     delete_use_case: web::Data<Arc<dyn DeleteUserUseCase>>,
+    principal: Principal,
     id_path: web::Path<String>,
 ) -> HttpResponse {
     let user_id = Id::new_from_string(id_path.into_inner());
-    let delete_user_result = delete_use_case.delete_user(&user_id.unwrap()).await;
+    let delete_user_result = delete_use_case
+        .delete_user(&principal, &user_id.unwrap())
+        .await;
     match delete_user_result {
         Ok(user) => {
             let user_response = UserDataResponseDto::from(&user);
@@ -129,3 +136,53 @@ pub async fn delete_user_by_id(
         Err(error) => HttpResponse::from(AppError::from(error.clone())),
     }
 }
+
+#[put("/users/{id}/roles/{role}")]
+pub async fn grant_user_role(
+    manage_user_roles_use_case: web::Data<Arc<dyn ManageUserRolesUseCase>>,
+    principal: Principal,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (id, role) = path.into_inner();
+    let user_id = match Id::new_from_string(id) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let role = match Role::new(role) {
+        Ok(r) => r,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_user_roles_use_case
+        .grant_role(&principal, &user_id, role)
+        .await
+    {
+        Ok(roles) => HttpResponse::Ok().json(UserRolesResponseDto::from(&roles)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
+
+#[delete("/users/{id}/roles/{role}")]
+pub async fn revoke_user_role(
+    manage_user_roles_use_case: web::Data<Arc<dyn ManageUserRolesUseCase>>,
+    principal: Principal,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (id, role) = path.into_inner();
+    let user_id = match Id::new_from_string(id) {
+        Ok(i) => i,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+    let role = match Role::new(role) {
+        Ok(r) => r,
+        Err(error) => return HttpResponse::from(AppError::from(error)),
+    };
+
+    match manage_user_roles_use_case
+        .revoke_role(&principal, &user_id, role)
+        .await
+    {
+        Ok(roles) => HttpResponse::Ok().json(UserRolesResponseDto::from(&roles)),
+        Err(error) => HttpResponse::from(AppError::from(error)),
+    }
+}
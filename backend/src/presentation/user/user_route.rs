@@ -1,5 +1,7 @@
+use crate::presentation::person::person_handler::{get_user_person, put_user_person};
 use crate::presentation::user::user_handler::{
-    create_user, delete_user_by_id, get_user_by_email, get_user_by_id, patch_user_by_id,
+    create_user, delete_user_by_id, get_user_by_email, get_user_by_id, grant_user_role,
+    patch_user_by_id, revoke_user_role,
 };
 use actix_web::web;
 
@@ -10,6 +12,10 @@ pub fn routes(config: &mut web::ServiceConfig) {
             .service(get_user_by_id)
             .service(get_user_by_email)
             .service(patch_user_by_id)
-            .service(delete_user_by_id),
+            .service(delete_user_by_id)
+            .service(grant_user_role)
+            .service(revoke_user_role)
+            .service(get_user_person)
+            .service(put_user_person),
     );
 }
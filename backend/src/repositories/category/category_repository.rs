@@ -0,0 +1,287 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::category::Category;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::infrastructure::postgres::PostgresBaseRepository;
+use crate::repositories::category::model::CategoryModel;
+use crate::repositories::schema::categories;
+use crate::repositories::schema::categories::{id, slug};
+use crate::repositories::schema::customer_services;
+use async_trait::async_trait;
+use diesel::dsl::exists;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::sql_types::Text;
+use diesel::{delete, insert_into, select, sql_query, update};
+use std::sync::Arc;
+
+const CATEGORIES_SLUG_UNIQUE: &str = "categories_slug_unique";
+
+// The category followed by every category below it, parents always precede their children
+const FIND_SUBTREE_QUERY: &str = "
+    WITH RECURSIVE subtree AS (SELECT c.*, 0 AS depth
+                               FROM categories c
+                               WHERE c.slug = $1
+                               UNION ALL
+                               SELECT c.*, s.depth + 1
+                               FROM categories c
+                                        JOIN subtree s ON c.parent_slug = s.slug)
+    SELECT id, slug, parent_slug, labels, created_at, updated_at
+    FROM subtree
+    ORDER BY depth, slug";
+
+#[async_trait]
+pub trait CategoryRepository: Send + Sync {
+    async fn save(&self, category: &Category) -> ResultApp<Category>;
+    async fn find_by_slug(&self, slug: &CustomerServiceCategory) -> ResultApp<Option<Category>>;
+    async fn find_by_slugs(&self, slugs: &[CustomerServiceCategory]) -> ResultApp<Vec<Category>>;
+    async fn find_all(&self) -> ResultApp<Vec<Category>>;
+    // Empty when the slug is unknown
+    async fn find_subtree(&self, slug: &CustomerServiceCategory) -> ResultApp<Vec<Category>>;
+    // Whether a customer service that is not deleted references the slug
+    async fn is_in_use(&self, slug: &CustomerServiceCategory) -> ResultApp<bool>;
+    async fn update(&self, category: &Category) -> ResultApp<Option<Category>>;
+    async fn delete(&self, slug: &CustomerServiceCategory) -> ResultApp<Option<Category>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct CategoryRepositoryPostgres {
+    pub base_repository: PostgresBaseRepository,
+}
+
+impl CategoryRepositoryPostgres {
+    pub fn new(base_repository: PostgresBaseRepository) -> Self {
+        CategoryRepositoryPostgres { base_repository }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for CategoryRepositoryPostgres {
+    async fn save(&self, category: &Category) -> ResultApp<Category> {
+        let category_model = CategoryModel::from(category.clone());
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let insert_result = insert_into(categories::table)
+            .values(&category_model)
+            .get_result::<CategoryModel>(&mut connection_result.unwrap());
+
+        match insert_result {
+            Ok(model) => Ok(Category::from(model)),
+            Err(err) => Err(Arc::new(map_write_error(err))),
+        }
+    }
+
+    async fn find_by_slug(
+        &self,
+        category_slug: &CustomerServiceCategory,
+    ) -> ResultApp<Option<Category>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let category_response = categories::table
+            .filter(slug.eq(category_slug.value()))
+            .select(CategoryModel::as_select())
+            .first(&mut connection_result.unwrap())
+            .optional();
+
+        match category_response {
+            Ok(model) => Ok(model.map(Category::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_by_slugs(
+        &self,
+        category_slugs: &[CustomerServiceCategory],
+    ) -> ResultApp<Vec<Category>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let categories_response = categories::table
+            .filter(slug.eq_any(category_slugs.iter().map(|category| category.value())))
+            .order(slug.asc())
+            .select(CategoryModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match categories_response {
+            Ok(models) => Ok(models.into_iter().map(Category::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_all(&self) -> ResultApp<Vec<Category>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let categories_response = categories::table
+            .order((slug.asc(), id.asc()))
+            .select(CategoryModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match categories_response {
+            Ok(models) => Ok(models.into_iter().map(Category::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_subtree(
+        &self,
+        category_slug: &CustomerServiceCategory,
+    ) -> ResultApp<Vec<Category>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let subtree_response = sql_query(FIND_SUBTREE_QUERY)
+            .bind::<Text, _>(category_slug.value())
+            .load::<CategoryModel>(&mut connection_result.unwrap());
+
+        match subtree_response {
+            Ok(models) => Ok(models.into_iter().map(Category::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn is_in_use(&self, category_slug: &CustomerServiceCategory) -> ResultApp<bool> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let in_use_response = select(exists(
+            customer_services::table
+                .filter(customer_services::deleted.eq(false))
+                .filter(customer_services::categories.contains(vec![category_slug.value()])),
+        ))
+        .get_result::<bool>(&mut connection_result.unwrap());
+
+        match in_use_response {
+            Ok(in_use) => Ok(in_use),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn update(&self, category: &Category) -> ResultApp<Option<Category>> {
+        let mut category_model = CategoryModel::from(category.clone());
+        category_model.updated_at = chrono::Utc::now();
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let updated_result = update(categories::table.find(category.id.value()))
+            .set(&category_model)
+            .returning(CategoryModel::as_returning())
+            .get_result(&mut connection_result.unwrap())
+            .optional();
+
+        match updated_result {
+            Ok(model) => Ok(model.map(Category::from)),
+            Err(err) => Err(Arc::new(map_write_error(err))),
+        }
+    }
+
+    async fn delete(&self, category_slug: &CustomerServiceCategory) -> ResultApp<Option<Category>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let deleted_result = delete(categories::table.filter(slug.eq(category_slug.value())))
+            .returning(CategoryModel::as_returning())
+            .get_result(&mut connection_result.unwrap())
+            .optional();
+
+        match deleted_result {
+            Ok(model) => Ok(model.map(Category::from)),
+            Err(err) => Err(Arc::new(map_write_error(err))),
+        }
+    }
+}
+
+// A taken slug or a parent that still has children are business conflicts, not database failures
+fn map_write_error(err: DieselError) -> AppError {
+    match &err {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            if info.constraint_name() == Some(CATEGORIES_SLUG_UNIQUE) =>
+        {
+            AppError::Conflict(
+                ErrorData::new("category-already-exists", "category slug already taken")
+                    .with_cause(Some(Arc::new(err))),
+            )
+        }
+        DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+            AppError::Conflict(
+                ErrorData::new(
+                    "category-parent-conflict",
+                    "parent category is missing or the category still has children",
+                )
+                .with_cause(Some(Arc::new(err))),
+            )
+        }
+        _ => AppError::Database(
+            ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+        ),
+    }
+}
@@ -0,0 +1,2 @@
+pub mod category_repository;
+mod model;
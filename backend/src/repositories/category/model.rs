@@ -0,0 +1,57 @@
+use crate::domain::entity::category::Category;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::localized_labels::LocalizedLabels;
+use crate::domain::vo::temporal::DateTime;
+use chrono::{DateTime as ChronoDateTime, Utc};
+use diesel::{AsChangeset, Insertable, Queryable, QueryableByName, Selectable};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(
+    Queryable, QueryableByName, Selectable, Insertable, AsChangeset, Serialize, Deserialize,
+)]
+#[diesel(table_name = crate::repositories::schema::categories)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+// A top level category must write a NULL parent on update
+#[diesel(treat_none_as_null = true)]
+pub struct CategoryModel {
+    pub id: String,
+    pub slug: String,
+    pub parent_slug: Option<String>,
+    pub labels: Value,
+    pub created_at: ChronoDateTime<Utc>,
+    pub updated_at: ChronoDateTime<Utc>,
+}
+
+impl From<CategoryModel> for Category {
+    fn from(model: CategoryModel) -> Self {
+        let labels: HashMap<String, String> =
+            serde_json::from_value(model.labels).unwrap_or_default();
+
+        Self {
+            id: Id::new_from_string(model.id).unwrap(),
+            slug: CustomerServiceCategory::new(model.slug).unwrap(),
+            parent: model
+                .parent_slug
+                .map(|parent| CustomerServiceCategory::new(parent).unwrap()),
+            labels: LocalizedLabels::new(labels).unwrap(),
+            created_at: DateTime::new_from_date_time(model.created_at),
+            updated_at: DateTime::new_from_date_time(model.updated_at),
+        }
+    }
+}
+
+impl From<Category> for CategoryModel {
+    fn from(category: Category) -> Self {
+        Self {
+            id: category.id.value(),
+            slug: category.slug.value(),
+            parent_slug: category.parent.map(|parent| parent.value()),
+            labels: serde_json::to_value(category.labels.value()).unwrap(),
+            created_at: category.created_at.to_chono_date_time(),
+            updated_at: category.updated_at.to_chono_date_time(),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod model;
+pub mod ownership_claim_repository;
@@ -0,0 +1,53 @@
+use crate::domain::entity::ownership_claim::{ClaimStatus, OwnershipClaim};
+use crate::domain::vo::id::Id;
+use crate::domain::vo::tax_id::{Document, TaxId};
+use crate::domain::vo::temporal::DateTime;
+use chrono::{DateTime as ChronoDateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Queryable, Selectable, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = crate::repositories::schema::ownership_claims)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct OwnershipClaimModel {
+    pub id: String,
+    pub customer_service_id: String,
+    pub claimant_id: String,
+    pub tax_id: String,
+    pub status: String,
+    pub reviewer_id: Option<String>,
+    pub decided_at: Option<ChronoDateTime<Utc>>,
+    pub created_at: ChronoDateTime<Utc>,
+}
+
+impl From<OwnershipClaimModel> for OwnershipClaim {
+    fn from(model: OwnershipClaimModel) -> Self {
+        Self {
+            id: Id::new_from_string(model.id).unwrap(),
+            customer_service_id: Id::new_from_string(model.customer_service_id).unwrap(),
+            claimant_id: Id::new_from_string(model.claimant_id).unwrap(),
+            tax_id: TaxId::BrazilCnpj(model.tax_id),
+            status: ClaimStatus::new(model.status).unwrap(),
+            reviewer_id: model
+                .reviewer_id
+                .map(|reviewer_id| Id::new_from_string(reviewer_id).unwrap()),
+            decided_at: model.decided_at.map(DateTime::new_from_date_time),
+            created_at: DateTime::new_from_date_time(model.created_at),
+        }
+    }
+}
+
+impl From<OwnershipClaim> for OwnershipClaimModel {
+    fn from(claim: OwnershipClaim) -> Self {
+        Self {
+            id: claim.id.value(),
+            customer_service_id: claim.customer_service_id.value(),
+            claimant_id: claim.claimant_id.value(),
+            tax_id: Document::format(&claim.tax_id),
+            status: claim.status.value(),
+            reviewer_id: claim.reviewer_id.map(|reviewer_id| reviewer_id.value()),
+            decided_at: claim.decided_at.map(|dt| dt.to_chono_date_time()),
+            created_at: claim.created_at.to_chono_date_time(),
+        }
+    }
+}
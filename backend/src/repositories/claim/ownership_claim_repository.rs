@@ -0,0 +1,216 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::ownership_claim::{ClaimStatus, OwnershipClaim};
+use crate::domain::vo::id::Id;
+use crate::infrastructure::postgres::PostgresBaseRepository;
+use crate::repositories::claim::model::OwnershipClaimModel;
+use crate::repositories::schema::ownership_claims;
+use crate::repositories::schema::ownership_claims::{
+    claimant_id, created_at, customer_service_id, decided_at, id, reviewer_id, status,
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::{insert_into, update};
+use std::sync::Arc;
+
+const OWNERSHIP_CLAIMS_PENDING_UNIQUE: &str = "ownership_claims_pending_unique";
+const OWNERSHIP_CLAIMS_OWNER_UNIQUE: &str = "ownership_claims_owner_unique";
+
+#[async_trait]
+pub trait OwnershipClaimRepository: Send + Sync {
+    async fn save(&self, claim: &OwnershipClaim) -> ResultApp<OwnershipClaim>;
+    async fn find_by_id(&self, id: &Id) -> ResultApp<Option<OwnershipClaim>>;
+    // Oldest first, like the moderation queue
+    async fn find_pending(&self, limit: i64, offset: i64) -> ResultApp<Vec<OwnershipClaim>>;
+    // The claimant of the approved claim, if the place has one
+    async fn find_owner(&self, customer_service_id: &Id) -> ResultApp<Option<Id>>;
+    // None when the claim is no longer pending
+    async fn decide(
+        &self,
+        id: &Id,
+        decision: ClaimStatus,
+        reviewer: &Id,
+    ) -> ResultApp<Option<OwnershipClaim>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct OwnershipClaimRepositoryPostgres {
+    pub base_repository: PostgresBaseRepository,
+}
+
+impl OwnershipClaimRepositoryPostgres {
+    pub fn new(base_repository: PostgresBaseRepository) -> Self {
+        OwnershipClaimRepositoryPostgres { base_repository }
+    }
+}
+
+#[async_trait]
+impl OwnershipClaimRepository for OwnershipClaimRepositoryPostgres {
+    async fn save(&self, claim: &OwnershipClaim) -> ResultApp<OwnershipClaim> {
+        let claim_model = OwnershipClaimModel::from(claim.clone());
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let insert_result = insert_into(ownership_claims::table)
+            .values(&claim_model)
+            .returning(OwnershipClaimModel::as_returning())
+            .get_result(&mut connection_result.unwrap());
+
+        match insert_result {
+            Ok(model) => Ok(OwnershipClaim::from(model)),
+            Err(err) => Err(Arc::new(map_write_error(err))),
+        }
+    }
+
+    async fn find_by_id(&self, claim_id: &Id) -> ResultApp<Option<OwnershipClaim>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let claim_response = ownership_claims::table
+            .filter(id.eq(claim_id.value()))
+            .select(OwnershipClaimModel::as_select())
+            .first(&mut connection_result.unwrap())
+            .optional();
+
+        match claim_response {
+            Ok(model) => Ok(model.map(OwnershipClaim::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_pending(&self, limit: i64, offset: i64) -> ResultApp<Vec<OwnershipClaim>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let claims_response = ownership_claims::table
+            .filter(status.eq(ClaimStatus::Pending.value()))
+            .order((created_at.asc(), id.asc()))
+            .limit(limit)
+            .offset(offset)
+            .select(OwnershipClaimModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match claims_response {
+            Ok(models) => Ok(models.into_iter().map(OwnershipClaim::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_owner(&self, customer_service: &Id) -> ResultApp<Option<Id>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let owner_response = ownership_claims::table
+            .filter(customer_service_id.eq(customer_service.value()))
+            .filter(status.eq(ClaimStatus::Approved.value()))
+            .select(claimant_id)
+            .first::<String>(&mut connection_result.unwrap())
+            .optional();
+
+        match owner_response {
+            Ok(owner) => Ok(owner.map(|owner| Id::new_from_string(owner).unwrap())),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn decide(
+        &self,
+        claim_id: &Id,
+        decision: ClaimStatus,
+        reviewer: &Id,
+    ) -> ResultApp<Option<OwnershipClaim>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let decided_result = update(
+            ownership_claims::table
+                .filter(id.eq(claim_id.value()))
+                .filter(status.eq(ClaimStatus::Pending.value())),
+        )
+        .set((
+            status.eq(decision.value()),
+            reviewer_id.eq(reviewer.value()),
+            decided_at.eq(chrono::Utc::now()),
+        ))
+        .returning(OwnershipClaimModel::as_returning())
+        .get_result(&mut connection_result.unwrap())
+        .optional();
+
+        match decided_result {
+            Ok(model) => Ok(model.map(OwnershipClaim::from)),
+            Err(err) => Err(Arc::new(map_write_error(err))),
+        }
+    }
+}
+
+fn map_write_error(err: DieselError) -> AppError {
+    match &err {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            if info.constraint_name() == Some(OWNERSHIP_CLAIMS_PENDING_UNIQUE) =>
+        {
+            AppError::Conflict(
+                ErrorData::new(
+                    "claim-already-pending",
+                    "the user already has a pending claim for this customer service",
+                )
+                .with_cause(Some(Arc::new(err))),
+            )
+        }
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            if info.constraint_name() == Some(OWNERSHIP_CLAIMS_OWNER_UNIQUE) =>
+        {
+            AppError::Conflict(
+                ErrorData::new(
+                    "customer-service-already-owned",
+                    "the customer service already has an owner",
+                )
+                .with_cause(Some(Arc::new(err))),
+            )
+        }
+        _ => AppError::Database(
+            ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+        ),
+    }
+}
@@ -0,0 +1,712 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::customer_service::{
+    CellCount, CustomerService, CustomerServiceCluster, CustomerServiceFilter,
+    CustomerServiceSearchResult, CustomerServiceSort, NearbyCustomerService,
+};
+use crate::domain::vo::geohash::Geohash;
+use crate::domain::vo::geopoint::{BoundingBox, EARTH_RADIUS_METERS, GeoPoint};
+use crate::domain::vo::id::Id;
+use crate::domain::vo::search_query::SearchQuery;
+use crate::infrastructure::postgres::PostgresBaseRepository;
+use crate::repositories::customer_service::model::{
+    CellCountModel, ClusterModel, CustomerServiceModel, NearbyCustomerServiceModel,
+    SearchResultModel,
+};
+use crate::repositories::schema::customer_services;
+use crate::repositories::schema::customer_services::dsl::customer_services as customer_services_dsl;
+use crate::repositories::schema::customer_services::{
+    categories, created_at, deleted, deleted_at, geohash_4, geohash_6, geohash_8, hidden, id,
+    latitude, longitude, opening_hours, tags, updated_at,
+};
+use async_trait::async_trait;
+use diesel::dsl::sql;
+use diesel::expression::BoxableExpression;
+use diesel::insert_into;
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Bool, Float8, Integer, Text};
+use diesel::{sql_query, update};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait CustomerServiceRepository: Send + Sync {
+    async fn save(&self, customer_service: &CustomerService) -> ResultApp<CustomerService>;
+    async fn find_by_id(&self, id: &Id) -> ResultApp<Option<CustomerService>>;
+    async fn find_all(
+        &self,
+        filter: &CustomerServiceFilter,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<CustomerService>>;
+    async fn find_nearby(
+        &self,
+        center: &GeoPoint,
+        radius_meters: f64,
+        limit: i64,
+    ) -> ResultApp<Vec<NearbyCustomerService>>;
+    // Ranked by text relevance, discounted by distance when `near` holds a center and radius
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        near: Option<(&GeoPoint, f64)>,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<CustomerServiceSearchResult>>;
+    // Cells may mix precisions, at most 8 characters each
+    async fn find_in_cells(&self, cells: &[Geohash], limit: i64)
+    -> ResultApp<Vec<CustomerService>>;
+    async fn count_by_cell(
+        &self,
+        within: &[Geohash],
+        precision: usize,
+    ) -> ResultApp<Vec<CellCount>>;
+    async fn find_in_bounding_box(
+        &self,
+        bounding_box: &BoundingBox,
+        limit: i64,
+    ) -> ResultApp<Vec<CustomerService>>;
+    // One cluster per geohash cell of `precision` intersecting the bounding box
+    async fn find_clusters(
+        &self,
+        bounding_box: &BoundingBox,
+        precision: usize,
+    ) -> ResultApp<Vec<CustomerServiceCluster>>;
+    async fn update(
+        &self,
+        customer_service: &CustomerService,
+    ) -> ResultApp<Option<CustomerService>>;
+    async fn delete(&self, id: &Id) -> ResultApp<Option<CustomerService>>;
+    // Takes the place out of every public read, None when it is missing or already hidden
+    async fn hide(&self, id: &Id) -> ResultApp<Option<CustomerService>>;
+}
+
+// The bounding box predicates hit customer_services_location_idx, the haversine only runs on
+// the rows inside it
+const FIND_NEARBY_QUERY: &str = "
+    SELECT *
+    FROM (SELECT cs.*,
+                 2 * $3 * ASIN(LEAST(1, SQRT(
+                     POWER(SIN(RADIANS(cs.latitude - $1) / 2), 2) +
+                     COS(RADIANS($1)) * COS(RADIANS(cs.latitude)) *
+                     POWER(SIN(RADIANS(cs.longitude - $2) / 2), 2)))) AS distance_meters
+          FROM customer_services cs
+          WHERE cs.deleted = FALSE
+            AND cs.hidden = FALSE
+            AND cs.latitude BETWEEN $4 AND $5
+            AND (cs.longitude BETWEEN $6 AND $7 OR cs.longitude BETWEEN $8 AND $9)) nearby
+    WHERE nearby.distance_meters <= $10
+    ORDER BY nearby.distance_meters, nearby.id
+    LIMIT $11";
+
+// The vector expression must match customer_services_search_idx for the index to be used
+const SEARCH_QUERY: &str = "
+    SELECT cs.*,
+           TS_RANK_CD(customer_service_search_vector(cs.name, cs.description, cs.categories, cs.tags),
+                      query)::FLOAT8 AS score,
+           NULL::FLOAT8 AS distance_meters
+    FROM customer_services cs,
+         TO_TSQUERY('portuguese_unaccent', $1) query
+    WHERE cs.deleted = FALSE
+      AND cs.hidden = FALSE
+      AND customer_service_search_vector(cs.name, cs.description, cs.categories, cs.tags) @@ query
+    ORDER BY score DESC, cs.id
+    LIMIT $2 OFFSET $3";
+
+// Relevance halves at $4 meters away, rows outside the radius are dropped like in the nearby query
+const SEARCH_NEARBY_QUERY: &str = "
+    SELECT found.*, found.relevance / (1 + found.distance_meters / $4) AS score
+    FROM (SELECT cs.*,
+                 TS_RANK_CD(customer_service_search_vector(cs.name, cs.description, cs.categories,
+                                                           cs.tags), query)::FLOAT8 AS relevance,
+                 2 * $7 * ASIN(LEAST(1, SQRT(
+                     POWER(SIN(RADIANS(cs.latitude - $5) / 2), 2) +
+                     COS(RADIANS($5)) * COS(RADIANS(cs.latitude)) *
+                     POWER(SIN(RADIANS(cs.longitude - $6) / 2), 2)))) AS distance_meters
+          FROM customer_services cs,
+               TO_TSQUERY('portuguese_unaccent', $1) query
+          WHERE cs.deleted = FALSE
+            AND cs.hidden = FALSE
+            AND customer_service_search_vector(cs.name, cs.description, cs.categories, cs.tags) @@ query
+            AND cs.latitude BETWEEN $8 AND $9
+            AND (cs.longitude BETWEEN $10 AND $11 OR cs.longitude BETWEEN $12 AND $13)) found
+    WHERE found.distance_meters <= $14
+    ORDER BY score DESC, found.id
+    LIMIT $2 OFFSET $3";
+
+// Distance at which a match keeps half of its text relevance
+const SEARCH_DISTANCE_HALF_SCORE_METERS: f64 = 2_000.0;
+
+// Every word must match as a prefix, so partially typed words already find results
+fn prefix_ts_query(query: &SearchQuery) -> String {
+    query
+        .terms()
+        .iter()
+        .map(|term| format!("{}:*", term))
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
+
+const COUNT_BY_CELL_QUERY: &str = "
+    SELECT LEFT(cs.geohash_8, $1) AS cell, COUNT(*) AS total
+    FROM customer_services cs
+    WHERE cs.deleted = FALSE
+      AND cs.hidden = FALSE";
+
+// Ids are UUIDv7, so MIN(id) picks the oldest place of the cell as its representative
+const FIND_CLUSTERS_QUERY: &str = "
+    SELECT COUNT(*)          AS total,
+           AVG(cs.latitude)  AS latitude,
+           AVG(cs.longitude) AS longitude,
+           MIN(cs.id)        AS representative_id
+    FROM customer_services cs
+    WHERE cs.deleted = FALSE
+      AND cs.hidden = FALSE
+      AND cs.latitude BETWEEN $2 AND $3
+      AND (cs.longitude BETWEEN $4 AND $5 OR cs.longitude BETWEEN $6 AND $7)
+    GROUP BY LEFT(cs.geohash_8, $1)
+    ORDER BY total DESC, representative_id";
+
+// Bayesian average: every place starts with 5 virtual reviews at the mean rating of all reviews,
+// which real reviews outweigh as they accumulate
+const WEIGHTED_RATING_ORDER: &str = "
+    (customer_services.rating_sum + 5 * (SELECT COALESCE(SUM(rated.rating_sum)::FLOAT8 /
+                                                         NULLIF(SUM(rated.rating_count), 0), 3)
+                                         FROM customer_services rated
+                                         WHERE rated.deleted = FALSE
+                                           AND rated.hidden = FALSE))
+        / (customer_services.rating_count + 5)";
+
+type CellPredicate = Box<dyn BoxableExpression<customer_services::table, Pg, SqlType = Bool>>;
+
+// Exact match on the column of the same resolution, otherwise a prefix match on the next finer
+// one, both served by the pattern_ops indexes
+fn cell_predicate(cell: &Geohash) -> CellPredicate {
+    let value = cell.value();
+    match cell.precision() {
+        4 => Box::new(geohash_4.eq(value)),
+        6 => Box::new(geohash_6.eq(value)),
+        8 => Box::new(geohash_8.eq(value)),
+        p if p < 4 => Box::new(geohash_4.like(format!("{value}%"))),
+        p if p < 6 => Box::new(geohash_6.like(format!("{value}%"))),
+        _ => Box::new(geohash_8.like(format!("{value}%"))),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomerServiceRepositoryPostgres {
+    pub base_repository: PostgresBaseRepository,
+}
+
+impl CustomerServiceRepositoryPostgres {
+    pub fn new(base_repository: PostgresBaseRepository) -> Self {
+        CustomerServiceRepositoryPostgres { base_repository }
+    }
+}
+
+#[async_trait]
+impl CustomerServiceRepository for CustomerServiceRepositoryPostgres {
+    async fn save(&self, customer_service: &CustomerService) -> ResultApp<CustomerService> {
+        let customer_service_model = CustomerServiceModel::from(customer_service.clone());
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let insert_result = insert_into(customer_services::table)
+            .values(&customer_service_model)
+            .returning(CustomerServiceModel::as_returning())
+            .get_result(&mut connection_result.unwrap());
+
+        match insert_result {
+            Ok(model) => Ok(CustomerService::from(model)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_by_id(&self, customer_service_id: &Id) -> ResultApp<Option<CustomerService>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let customer_service_response = customer_services::table
+            .filter(id.eq(customer_service_id.value()))
+            .filter(deleted.eq(false))
+            .filter(hidden.eq(false))
+            .select(CustomerServiceModel::as_select())
+            .first(&mut connection_result.unwrap())
+            .optional();
+
+        match customer_service_response {
+            Ok(Some(model)) => Ok(Some(CustomerService::from(model))),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_all(
+        &self,
+        filter: &CustomerServiceFilter,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<CustomerService>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let mut query = customer_services::table
+            .filter(deleted.eq(false))
+            .filter(hidden.eq(false))
+            .into_boxed();
+        // Opening hours are evaluated by the caller, only places that have them can be open
+        if filter.open_at.is_some() {
+            query = query.filter(opening_hours.is_not_null());
+        }
+        if let Some(filter_categories) = &filter.categories {
+            let filter_categories: Vec<String> = filter_categories
+                .iter()
+                .map(|category| category.value())
+                .collect();
+            query = query.filter(categories.overlaps_with(filter_categories));
+        }
+        // JSONB containment is served by customer_services_tags_idx
+        if let Some(filter_tags) = &filter.tags {
+            query = query.filter(tags.contains(serde_json::to_value(filter_tags.value()).unwrap()));
+        }
+
+        query = match filter.sort {
+            CustomerServiceSort::Newest => query.order((created_at.desc(), id.asc())),
+            CustomerServiceSort::Rating => query.order((
+                sql::<Float8>(WEIGHTED_RATING_ORDER).desc(),
+                created_at.desc(),
+                id.asc(),
+            )),
+        };
+
+        let customer_services_response = query
+            .limit(limit)
+            .offset(offset)
+            .select(CustomerServiceModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match customer_services_response {
+            Ok(models) => Ok(models.into_iter().map(CustomerService::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_nearby(
+        &self,
+        center: &GeoPoint,
+        radius_meters: f64,
+        limit: i64,
+    ) -> ResultApp<Vec<NearbyCustomerService>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let bounding_box = center.bounding_box(radius_meters);
+        let [
+            (first_min_lon, first_max_lon),
+            (second_min_lon, second_max_lon),
+        ] = bounding_box.longitude_ranges();
+        let nearby_response = sql_query(FIND_NEARBY_QUERY)
+            .bind::<Float8, _>(center.lat)
+            .bind::<Float8, _>(center.lon)
+            .bind::<Float8, _>(EARTH_RADIUS_METERS)
+            .bind::<Float8, _>(bounding_box.min_lat)
+            .bind::<Float8, _>(bounding_box.max_lat)
+            .bind::<Float8, _>(first_min_lon)
+            .bind::<Float8, _>(first_max_lon)
+            .bind::<Float8, _>(second_min_lon)
+            .bind::<Float8, _>(second_max_lon)
+            .bind::<Float8, _>(radius_meters)
+            .bind::<BigInt, _>(limit)
+            .load::<NearbyCustomerServiceModel>(&mut connection_result.unwrap());
+
+        match nearby_response {
+            Ok(models) => Ok(models
+                .into_iter()
+                .map(|model| NearbyCustomerService {
+                    customer_service: CustomerService::from(model.customer_service),
+                    distance_meters: model.distance_meters,
+                })
+                .collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        near: Option<(&GeoPoint, f64)>,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<CustomerServiceSearchResult>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let ts_query = prefix_ts_query(query);
+        let search_response = match near {
+            None => sql_query(SEARCH_QUERY)
+                .bind::<Text, _>(ts_query)
+                .bind::<BigInt, _>(limit)
+                .bind::<BigInt, _>(offset)
+                .load::<SearchResultModel>(&mut connection_result.unwrap()),
+            Some((center, radius_meters)) => {
+                let bounding_box = center.bounding_box(radius_meters);
+                let [
+                    (first_min_lon, first_max_lon),
+                    (second_min_lon, second_max_lon),
+                ] = bounding_box.longitude_ranges();
+                sql_query(SEARCH_NEARBY_QUERY)
+                    .bind::<Text, _>(ts_query)
+                    .bind::<BigInt, _>(limit)
+                    .bind::<BigInt, _>(offset)
+                    .bind::<Float8, _>(SEARCH_DISTANCE_HALF_SCORE_METERS)
+                    .bind::<Float8, _>(center.lat)
+                    .bind::<Float8, _>(center.lon)
+                    .bind::<Float8, _>(EARTH_RADIUS_METERS)
+                    .bind::<Float8, _>(bounding_box.min_lat)
+                    .bind::<Float8, _>(bounding_box.max_lat)
+                    .bind::<Float8, _>(first_min_lon)
+                    .bind::<Float8, _>(first_max_lon)
+                    .bind::<Float8, _>(second_min_lon)
+                    .bind::<Float8, _>(second_max_lon)
+                    .bind::<Float8, _>(radius_meters)
+                    .load::<SearchResultModel>(&mut connection_result.unwrap())
+            }
+        };
+
+        match search_response {
+            Ok(models) => Ok(models
+                .into_iter()
+                .map(|model| CustomerServiceSearchResult {
+                    customer_service: CustomerService::from(model.customer_service),
+                    score: model.score,
+                    distance_meters: model.distance_meters,
+                })
+                .collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_in_cells(
+        &self,
+        cells: &[Geohash],
+        limit: i64,
+    ) -> ResultApp<Vec<CustomerService>> {
+        let Some(cells_predicate) = cells
+            .iter()
+            .map(cell_predicate)
+            .reduce(|a, b| Box::new(a.or(b)))
+        else {
+            return Ok(vec![]);
+        };
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let customer_services_response = customer_services::table
+            .filter(deleted.eq(false))
+            .filter(hidden.eq(false))
+            .filter(cells_predicate)
+            .order((geohash_8.asc(), id.asc()))
+            .limit(limit)
+            .select(CustomerServiceModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match customer_services_response {
+            Ok(models) => Ok(models.into_iter().map(CustomerService::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn count_by_cell(
+        &self,
+        within: &[Geohash],
+        precision: usize,
+    ) -> ResultApp<Vec<CellCount>> {
+        if within.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let prefixes = (0..within.len())
+            .map(|i| format!("cs.geohash_8 LIKE ${}", i + 2))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let query = format!("{COUNT_BY_CELL_QUERY} AND ({prefixes}) GROUP BY cell ORDER BY cell");
+        let mut count_query = sql_query(query)
+            .into_boxed::<Pg>()
+            .bind::<Integer, _>(precision as i32);
+        for cell in within {
+            count_query = count_query.bind::<Text, _>(format!("{}%", cell.value()));
+        }
+        let count_response = count_query.load::<CellCountModel>(&mut connection_result.unwrap());
+
+        match count_response {
+            Ok(models) => Ok(models
+                .into_iter()
+                .map(|model| CellCount {
+                    cell: Geohash::new(model.cell).unwrap(),
+                    count: model.total,
+                })
+                .collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_in_bounding_box(
+        &self,
+        bounding_box: &BoundingBox,
+        limit: i64,
+    ) -> ResultApp<Vec<CustomerService>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let [
+            (first_min_lon, first_max_lon),
+            (second_min_lon, second_max_lon),
+        ] = bounding_box.longitude_ranges();
+        let customer_services_response = customer_services::table
+            .filter(deleted.eq(false))
+            .filter(hidden.eq(false))
+            .filter(latitude.between(bounding_box.min_lat, bounding_box.max_lat))
+            .filter(
+                longitude
+                    .between(first_min_lon, first_max_lon)
+                    .or(longitude.between(second_min_lon, second_max_lon)),
+            )
+            .order(id.asc())
+            .limit(limit)
+            .select(CustomerServiceModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match customer_services_response {
+            Ok(models) => Ok(models.into_iter().map(CustomerService::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_clusters(
+        &self,
+        bounding_box: &BoundingBox,
+        precision: usize,
+    ) -> ResultApp<Vec<CustomerServiceCluster>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let [
+            (first_min_lon, first_max_lon),
+            (second_min_lon, second_max_lon),
+        ] = bounding_box.longitude_ranges();
+        let clusters_response = sql_query(FIND_CLUSTERS_QUERY)
+            .bind::<Integer, _>(precision as i32)
+            .bind::<Float8, _>(bounding_box.min_lat)
+            .bind::<Float8, _>(bounding_box.max_lat)
+            .bind::<Float8, _>(first_min_lon)
+            .bind::<Float8, _>(first_max_lon)
+            .bind::<Float8, _>(second_min_lon)
+            .bind::<Float8, _>(second_max_lon)
+            .load::<ClusterModel>(&mut connection_result.unwrap());
+
+        match clusters_response {
+            Ok(models) => Ok(models
+                .into_iter()
+                .map(|model| CustomerServiceCluster {
+                    centroid: GeoPoint::new(model.latitude, model.longitude).unwrap(),
+                    count: model.total,
+                    representative_id: Id::new_from_string(model.representative_id).unwrap(),
+                })
+                .collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn update(
+        &self,
+        customer_service: &CustomerService,
+    ) -> ResultApp<Option<CustomerService>> {
+        let mut customer_service_model = CustomerServiceModel::from(customer_service.clone());
+        customer_service_model.updated_at = chrono::Utc::now();
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let updated_result = update(customer_services_dsl.find(customer_service.id.value()))
+            .set(&customer_service_model)
+            .returning(CustomerServiceModel::as_returning())
+            .get_result(&mut connection_result.unwrap())
+            .optional();
+
+        match updated_result {
+            Ok(model) => Ok(model.map(CustomerService::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn delete(&self, customer_service_id: &Id) -> ResultApp<Option<CustomerService>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let current_time = chrono::Utc::now();
+        let updated_result = update(
+            customer_services::table
+                .filter(id.eq(customer_service_id.value()))
+                .filter(deleted.eq(false)),
+        )
+        .set((
+            updated_at.eq(current_time),
+            deleted.eq(true),
+            deleted_at.eq(current_time),
+        ))
+        .returning(CustomerServiceModel::as_returning())
+        .get_result(&mut connection_result.unwrap())
+        .optional();
+
+        match updated_result {
+            Ok(model) => Ok(model.map(CustomerService::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn hide(&self, customer_service_id: &Id) -> ResultApp<Option<CustomerService>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let updated_result = update(
+            customer_services::table
+                .filter(id.eq(customer_service_id.value()))
+                .filter(deleted.eq(false))
+                .filter(hidden.eq(false)),
+        )
+        .set((updated_at.eq(chrono::Utc::now()), hidden.eq(true)))
+        .returning(CustomerServiceModel::as_returning())
+        .get_result(&mut connection_result.unwrap())
+        .optional();
+
+        match updated_result {
+            Ok(model) => Ok(model.map(CustomerService::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod customer_service_repository;
+mod model;
@@ -0,0 +1,295 @@
+use crate::domain::entity::customer_service::CustomerService;
+use crate::domain::vo::address::{Address, AddressData};
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::customer_service_category::CustomerServiceCategory;
+use crate::domain::vo::description::Description;
+use crate::domain::vo::geohash::Geohash;
+use crate::domain::vo::geopoint::GeoPoint;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::opening_hours::OpeningHours;
+use crate::domain::vo::phone::Phone;
+use crate::domain::vo::photo::Photo;
+use crate::domain::vo::rating::RatingSummary;
+use crate::domain::vo::tags::Tags;
+use crate::domain::vo::temporal::DateTime;
+use crate::domain::vo::timezone::Timezone;
+use crate::domain::vo::url::Url;
+use chrono::{DateTime as ChronoDateTime, Utc};
+use diesel::sql_types::{BigInt, Float8, Nullable, Text};
+use diesel::{AsChangeset, Insertable, Queryable, QueryableByName, Selectable};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+struct PhotoModel {
+    url: String,
+    title: Option<String>,
+}
+
+#[derive(
+    Queryable, QueryableByName, Selectable, Insertable, AsChangeset, Serialize, Deserialize,
+)]
+#[diesel(table_name = crate::repositories::schema::customer_services)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+// Fields cleared by a PATCH must write NULL on update
+#[diesel(treat_none_as_null = true)]
+pub struct CustomerServiceModel {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub phone: String,
+    pub website: Option<String>,
+    pub photos: Value,
+    pub tags: Value,
+    pub categories: Vec<String>,
+    pub deleted: bool,
+    pub created_at: ChronoDateTime<Utc>,
+    pub updated_at: ChronoDateTime<Utc>,
+    pub deleted_at: Option<ChronoDateTime<Utc>>,
+    pub geohash_4: String,
+    pub geohash_6: String,
+    pub geohash_8: String,
+    pub opening_hours: Option<String>,
+    pub timezone: String,
+    pub state: Option<String>,
+    pub city: Option<String>,
+    // Only the review repository writes the rating totals
+    #[diesel(skip_insertion, skip_update)]
+    pub rating_count: i32,
+    #[diesel(skip_insertion, skip_update)]
+    pub rating_sum: i32,
+    pub address_street: Option<String>,
+    pub address_number: Option<String>,
+    pub address_complement: Option<String>,
+    pub address_neighbourhood: Option<String>,
+    pub address_postal_code: Option<String>,
+    pub address_country: Option<String>,
+}
+
+#[derive(QueryableByName)]
+pub struct NearbyCustomerServiceModel {
+    #[diesel(embed)]
+    pub customer_service: CustomerServiceModel,
+    #[diesel(sql_type = Float8)]
+    pub distance_meters: f64,
+}
+
+#[derive(QueryableByName)]
+pub struct SearchResultModel {
+    #[diesel(embed)]
+    pub customer_service: CustomerServiceModel,
+    #[diesel(sql_type = Float8)]
+    pub score: f64,
+    #[diesel(sql_type = Nullable<Float8>)]
+    pub distance_meters: Option<f64>,
+}
+
+#[derive(QueryableByName)]
+pub struct CellCountModel {
+    #[diesel(sql_type = Text)]
+    pub cell: String,
+    #[diesel(sql_type = BigInt)]
+    pub total: i64,
+}
+
+#[derive(QueryableByName)]
+pub struct ClusterModel {
+    #[diesel(sql_type = BigInt)]
+    pub total: i64,
+    #[diesel(sql_type = Float8)]
+    pub latitude: f64,
+    #[diesel(sql_type = Float8)]
+    pub longitude: f64,
+    #[diesel(sql_type = Text)]
+    pub representative_id: String,
+}
+
+impl From<CustomerServiceModel> for CustomerService {
+    fn from(model: CustomerServiceModel) -> Self {
+        let photos: Vec<PhotoModel> = serde_json::from_value(model.photos).unwrap_or_default();
+        let tags: HashMap<String, String> = serde_json::from_value(model.tags).unwrap_or_default();
+        // customer_services_address_complete keeps street, postal code, country and city together
+        let address = match (
+            model.address_street,
+            model.address_postal_code,
+            model.address_country,
+        ) {
+            (Some(street), Some(postal_code), Some(country)) => Some(
+                Address::new(AddressData {
+                    street,
+                    number: model.address_number,
+                    complement: model.address_complement,
+                    neighbourhood: model.address_neighbourhood,
+                    city: model.city.clone().unwrap_or_default(),
+                    state: model.state.clone(),
+                    postal_code,
+                    country,
+                })
+                .unwrap(),
+            ),
+            _ => None,
+        };
+        // Only Brazilian places observe state holidays, other states are kept in the address
+        let state = match &address {
+            Some(address) => address.brazil_state(),
+            None => model.state.map(|state| BrazilState::new(state).unwrap()),
+        };
+
+        Self {
+            id: Id::new_from_string(model.id).unwrap(),
+            name: Name::new(model.name).unwrap(),
+            description: Description::new(model.description).unwrap(),
+            location: GeoPoint::new(model.latitude, model.longitude).unwrap(),
+            address,
+            phone: Phone::new_lenient(model.phone),
+            website: model.website.map(|website| Url::new(website).unwrap()),
+            photos: photos
+                .into_iter()
+                .map(|photo| Photo::new(Url::new(photo.url).unwrap(), photo.title))
+                .collect(),
+            tags: Tags::new_lenient(tags),
+            categories: model
+                .categories
+                .into_iter()
+                .map(|category| CustomerServiceCategory::new(category).unwrap())
+                .collect(),
+            opening_hours: model
+                .opening_hours
+                .map(|opening_hours| OpeningHours::new(opening_hours).unwrap()),
+            timezone: Timezone::new(model.timezone).unwrap(),
+            state,
+            city: model.city.map(|city| Name::new(city).unwrap()),
+            rating: RatingSummary::new(model.rating_count.into(), model.rating_sum.into()),
+            deleted: model.deleted,
+            created_at: DateTime::new_from_date_time(model.created_at),
+            updated_at: DateTime::new_from_date_time(model.updated_at),
+            deleted_at: model.deleted_at.map(DateTime::new_from_date_time),
+        }
+    }
+}
+
+impl From<CustomerService> for CustomerServiceModel {
+    fn from(customer_service: CustomerService) -> Self {
+        let photos: Vec<PhotoModel> = customer_service
+            .photos
+            .iter()
+            .map(|photo| PhotoModel {
+                url: photo.url.as_str().to_string(),
+                title: photo.title.clone(),
+            })
+            .collect();
+        let (latitude, longitude) = customer_service.location.value();
+        let geohash_8 = Geohash::encode(&customer_service.location, 8).value();
+        let address = customer_service.address.as_ref();
+
+        Self {
+            id: customer_service.id.value(),
+            name: customer_service.name.value(),
+            description: customer_service.description.value(),
+            latitude,
+            longitude,
+            phone: customer_service.phone.value(),
+            website: customer_service
+                .website
+                .map(|website| website.as_str().to_string()),
+            photos: serde_json::to_value(photos).unwrap(),
+            tags: serde_json::to_value(customer_service.tags.stored()).unwrap(),
+            categories: customer_service
+                .categories
+                .iter()
+                .map(|category| category.value())
+                .collect(),
+            deleted: customer_service.deleted,
+            created_at: customer_service.created_at.to_chono_date_time(),
+            updated_at: customer_service.updated_at.to_chono_date_time(),
+            deleted_at: customer_service
+                .deleted_at
+                .map(|dt| dt.to_chono_date_time()),
+            geohash_4: geohash_8[..4].to_string(),
+            geohash_6: geohash_8[..6].to_string(),
+            geohash_8,
+            opening_hours: customer_service
+                .opening_hours
+                .map(|opening_hours| opening_hours.value()),
+            timezone: customer_service.timezone.value(),
+            state: match address {
+                Some(address) => address.state(),
+                None => customer_service.state.map(|state| state.value()),
+            },
+            city: match address {
+                Some(address) => Some(address.city().value()),
+                None => customer_service.city.map(|city| city.value()),
+            },
+            rating_count: customer_service.rating.count() as i32,
+            rating_sum: customer_service.rating.sum() as i32,
+            address_street: address.map(|address| address.street()),
+            address_number: address.and_then(|address| address.number()),
+            address_complement: address.and_then(|address| address.complement()),
+            address_neighbourhood: address.and_then(|address| address.neighbourhood()),
+            address_postal_code: address.map(|address| address.postal_code()),
+            address_country: address.map(|address| address.country().value()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn model() -> CustomerServiceModel {
+        let now = Utc::now();
+        CustomerServiceModel {
+            id: Id::new().unwrap().value(),
+            name: "Padaria Central".to_string(),
+            description: String::new(),
+            latitude: -23.5505,
+            longitude: -46.6333,
+            phone: "+5511912345678".to_string(),
+            website: None,
+            photos: Value::Array(vec![]),
+            tags: Value::Object(Default::default()),
+            categories: vec![],
+            deleted: false,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            geohash_4: "6gyf".to_string(),
+            geohash_6: "6gyf4b".to_string(),
+            geohash_8: "6gyf4bf1".to_string(),
+            opening_hours: None,
+            timezone: "America/Sao_Paulo".to_string(),
+            state: None,
+            city: None,
+            rating_count: 0,
+            rating_sum: 0,
+            address_street: None,
+            address_number: None,
+            address_complement: None,
+            address_neighbourhood: None,
+            address_postal_code: None,
+            address_country: None,
+        }
+    }
+
+    #[test]
+    fn tags_outside_the_registry_survive_a_round_trip() {
+        let mut legacy = model();
+        legacy.tags = serde_json::json!({"wifi": "true", "colour": "blue", "parking": "huge"});
+        let customer_service = CustomerService::from(legacy);
+        assert_eq!(
+            customer_service.tags.value(),
+            BTreeMap::from([("wifi".to_string(), "yes".to_string())])
+        );
+
+        let written = CustomerServiceModel::from(customer_service).tags;
+        assert_eq!(
+            written,
+            serde_json::json!({"wifi": "yes", "colour": "blue", "parking": "huge"})
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::holiday::Holiday;
+use crate::domain::vo::id::Id;
+use crate::infrastructure::postgres::PostgresBaseRepository;
+use crate::repositories::holiday::model::HolidayModel;
+use crate::repositories::schema::holidays;
+use crate::repositories::schema::holidays::{date, id, recurring};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use diesel::prelude::*;
+use diesel::{delete, insert_into};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait HolidayRepository: Send + Sync {
+    async fn save(&self, holiday: &Holiday) -> ResultApp<Holiday>;
+    // Recurring holidays plus the one-off ones dated inside the range
+    async fn find_between(&self, from: NaiveDate, to: NaiveDate) -> ResultApp<Vec<Holiday>>;
+    async fn delete(&self, id: &Id) -> ResultApp<Option<Holiday>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct HolidayRepositoryPostgres {
+    pub base_repository: PostgresBaseRepository,
+}
+
+impl HolidayRepositoryPostgres {
+    pub fn new(base_repository: PostgresBaseRepository) -> Self {
+        HolidayRepositoryPostgres { base_repository }
+    }
+}
+
+#[async_trait]
+impl HolidayRepository for HolidayRepositoryPostgres {
+    async fn save(&self, holiday: &Holiday) -> ResultApp<Holiday> {
+        let holiday_model = HolidayModel::from(holiday.clone());
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let insert_result = insert_into(holidays::table)
+            .values(&holiday_model)
+            .get_result::<HolidayModel>(&mut connection_result.unwrap());
+
+        match insert_result {
+            Ok(model) => Ok(Holiday::from(model)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_between(&self, from: NaiveDate, to: NaiveDate) -> ResultApp<Vec<Holiday>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let holidays_response = holidays::table
+            .filter(recurring.eq(true).or(date.between(from, to)))
+            .order((date.asc(), id.asc()))
+            .select(HolidayModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match holidays_response {
+            Ok(models) => Ok(models.into_iter().map(Holiday::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn delete(&self, holiday_id: &Id) -> ResultApp<Option<Holiday>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let deleted_result = delete(holidays::table.filter(id.eq(holiday_id.value())))
+            .returning(HolidayModel::as_returning())
+            .get_result(&mut connection_result.unwrap())
+            .optional();
+
+        match deleted_result {
+            Ok(model) => Ok(model.map(Holiday::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod holiday_repository;
+mod model;
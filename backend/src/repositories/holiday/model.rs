@@ -0,0 +1,49 @@
+use crate::domain::entity::holiday::Holiday;
+use crate::domain::vo::brazil_state::BrazilState;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::temporal::DateTime;
+use chrono::{DateTime as ChronoDateTime, NaiveDate, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Queryable, Selectable, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = crate::repositories::schema::holidays)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct HolidayModel {
+    pub id: String,
+    pub name: String,
+    pub date: NaiveDate,
+    pub recurring: bool,
+    pub state: Option<String>,
+    pub city: Option<String>,
+    pub created_at: ChronoDateTime<Utc>,
+}
+
+impl From<HolidayModel> for Holiday {
+    fn from(model: HolidayModel) -> Self {
+        Self {
+            id: Id::new_from_string(model.id).unwrap(),
+            name: Name::new(model.name).unwrap(),
+            date: model.date,
+            recurring: model.recurring,
+            state: model.state.map(|state| BrazilState::new(state).unwrap()),
+            city: model.city.map(|city| Name::new(city).unwrap()),
+            created_at: DateTime::new_from_date_time(model.created_at),
+        }
+    }
+}
+
+impl From<Holiday> for HolidayModel {
+    fn from(holiday: Holiday) -> Self {
+        Self {
+            id: holiday.id.value(),
+            name: holiday.name.value(),
+            date: holiday.date,
+            recurring: holiday.recurring,
+            state: holiday.state.map(|state| state.value()),
+            city: holiday.city.map(|city| city.value()),
+            created_at: holiday.created_at.to_chono_date_time(),
+        }
+    }
+}
@@ -1,2 +1,10 @@
+pub mod category;
+pub mod claim;
+pub mod customer_service;
+pub mod holiday;
+pub mod moderation;
+pub mod person;
+pub mod refresh_token;
+pub mod review;
 pub mod schema;
 pub mod user;
@@ -0,0 +1,2 @@
+mod model;
+pub mod moderation_flag_repository;
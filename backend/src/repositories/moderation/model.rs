@@ -0,0 +1,83 @@
+use crate::domain::entity::moderation_flag::ModerationFlag;
+use crate::domain::vo::description::Description;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::moderation::{
+    FlagReason, ModerationStatus, ModerationSubject, PreviousPlaceText,
+};
+use crate::domain::vo::name::Name;
+use crate::domain::vo::temporal::DateTime;
+use chrono::{DateTime as ChronoDateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Queryable, Selectable, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = crate::repositories::schema::moderation_flags)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ModerationFlagModel {
+    pub id: String,
+    pub subject_type: String,
+    pub subject_id: String,
+    pub reason: String,
+    pub reporter_id: Option<String>,
+    pub note: String,
+    pub status: String,
+    pub moderator_id: Option<String>,
+    pub decided_at: Option<ChronoDateTime<Utc>>,
+    pub created_at: ChronoDateTime<Utc>,
+    pub previous_name: Option<String>,
+    pub previous_description: Option<String>,
+}
+
+impl From<ModerationFlagModel> for ModerationFlag {
+    fn from(model: ModerationFlagModel) -> Self {
+        Self {
+            id: Id::new_from_string(model.id).unwrap(),
+            subject: ModerationSubject::new(
+                model.subject_type,
+                Id::new_from_string(model.subject_id).unwrap(),
+            )
+            .unwrap(),
+            reason: FlagReason::new(model.reason).unwrap(),
+            reporter_id: model
+                .reporter_id
+                .map(|reporter_id| Id::new_from_string(reporter_id).unwrap()),
+            note: Description::new(model.note).unwrap(),
+            status: ModerationStatus::new(model.status).unwrap(),
+            moderator_id: model
+                .moderator_id
+                .map(|moderator_id| Id::new_from_string(moderator_id).unwrap()),
+            decided_at: model.decided_at.map(DateTime::new_from_date_time),
+            created_at: DateTime::new_from_date_time(model.created_at),
+            previous_text: model.previous_name.zip(model.previous_description).map(
+                |(previous_name, previous_description)| PreviousPlaceText {
+                    name: Name::new(previous_name).unwrap(),
+                    description: Description::new(previous_description).unwrap(),
+                },
+            ),
+        }
+    }
+}
+
+impl From<ModerationFlag> for ModerationFlagModel {
+    fn from(flag: ModerationFlag) -> Self {
+        Self {
+            id: flag.id.value(),
+            subject_type: flag.subject.kind(),
+            subject_id: flag.subject.id().value(),
+            reason: flag.reason.value(),
+            reporter_id: flag.reporter_id.map(|reporter_id| reporter_id.value()),
+            note: flag.note.value(),
+            status: flag.status.value(),
+            moderator_id: flag.moderator_id.map(|moderator_id| moderator_id.value()),
+            decided_at: flag.decided_at.map(|dt| dt.to_chono_date_time()),
+            created_at: flag.created_at.to_chono_date_time(),
+            previous_name: flag
+                .previous_text
+                .as_ref()
+                .map(|previous_text| previous_text.name.value()),
+            previous_description: flag
+                .previous_text
+                .map(|previous_text| previous_text.description.value()),
+        }
+    }
+}
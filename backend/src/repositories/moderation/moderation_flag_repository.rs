@@ -0,0 +1,206 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::moderation_flag::ModerationFlag;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::moderation::{ModerationStatus, ModerationSubject};
+use crate::infrastructure::postgres::PostgresBaseRepository;
+use crate::repositories::moderation::model::ModerationFlagModel;
+use crate::repositories::schema::moderation_flags;
+use crate::repositories::schema::moderation_flags::{
+    created_at, decided_at, id, moderator_id, status, subject_id, subject_type,
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::{insert_into, update};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait ModerationFlagRepository: Send + Sync {
+    async fn save_all(&self, flags: &[ModerationFlag]) -> ResultApp<Vec<ModerationFlag>>;
+    async fn find_by_id(&self, id: &Id) -> ResultApp<Option<ModerationFlag>>;
+    // Oldest first, the queue is worked in arrival order
+    async fn find_pending(&self, limit: i64, offset: i64) -> ResultApp<Vec<ModerationFlag>>;
+    // Oldest first
+    async fn find_pending_by_subject(
+        &self,
+        subject: &ModerationSubject,
+    ) -> ResultApp<Vec<ModerationFlag>>;
+    // Settles every pending flag of the subject at once, empty when none was pending
+    async fn decide(
+        &self,
+        subject: &ModerationSubject,
+        decision: ModerationStatus,
+        moderator: &Id,
+    ) -> ResultApp<Vec<ModerationFlag>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct ModerationFlagRepositoryPostgres {
+    pub base_repository: PostgresBaseRepository,
+}
+
+impl ModerationFlagRepositoryPostgres {
+    pub fn new(base_repository: PostgresBaseRepository) -> Self {
+        ModerationFlagRepositoryPostgres { base_repository }
+    }
+}
+
+#[async_trait]
+impl ModerationFlagRepository for ModerationFlagRepositoryPostgres {
+    async fn save_all(&self, flags: &[ModerationFlag]) -> ResultApp<Vec<ModerationFlag>> {
+        if flags.is_empty() {
+            return Ok(vec![]);
+        }
+        let flag_models: Vec<ModerationFlagModel> = flags
+            .iter()
+            .cloned()
+            .map(ModerationFlagModel::from)
+            .collect();
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let insert_result = insert_into(moderation_flags::table)
+            .values(&flag_models)
+            .get_results::<ModerationFlagModel>(&mut connection_result.unwrap());
+
+        match insert_result {
+            Ok(models) => Ok(models.into_iter().map(ModerationFlag::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_by_id(&self, flag_id: &Id) -> ResultApp<Option<ModerationFlag>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let flag_response = moderation_flags::table
+            .filter(id.eq(flag_id.value()))
+            .select(ModerationFlagModel::as_select())
+            .first(&mut connection_result.unwrap())
+            .optional();
+
+        match flag_response {
+            Ok(model) => Ok(model.map(ModerationFlag::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_pending(&self, limit: i64, offset: i64) -> ResultApp<Vec<ModerationFlag>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let flags_response = moderation_flags::table
+            .filter(status.eq(ModerationStatus::Pending.value()))
+            .order((created_at.asc(), id.asc()))
+            .limit(limit)
+            .offset(offset)
+            .select(ModerationFlagModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match flags_response {
+            Ok(models) => Ok(models.into_iter().map(ModerationFlag::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_pending_by_subject(
+        &self,
+        subject: &ModerationSubject,
+    ) -> ResultApp<Vec<ModerationFlag>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let flags_response = moderation_flags::table
+            .filter(subject_type.eq(subject.kind()))
+            .filter(subject_id.eq(subject.id().value()))
+            .filter(status.eq(ModerationStatus::Pending.value()))
+            .order((created_at.asc(), id.asc()))
+            .select(ModerationFlagModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match flags_response {
+            Ok(models) => Ok(models.into_iter().map(ModerationFlag::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn decide(
+        &self,
+        subject: &ModerationSubject,
+        decision: ModerationStatus,
+        moderator: &Id,
+    ) -> ResultApp<Vec<ModerationFlag>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let decided_result = update(
+            moderation_flags::table
+                .filter(subject_type.eq(subject.kind()))
+                .filter(subject_id.eq(subject.id().value()))
+                .filter(status.eq(ModerationStatus::Pending.value())),
+        )
+        .set((
+            status.eq(decision.value()),
+            moderator_id.eq(moderator.value()),
+            decided_at.eq(chrono::Utc::now()),
+        ))
+        .returning(ModerationFlagModel::as_returning())
+        .get_results(&mut connection_result.unwrap());
+
+        match decided_result {
+            Ok(models) => Ok(models.into_iter().map(ModerationFlag::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+}
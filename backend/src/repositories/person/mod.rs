@@ -0,0 +1,2 @@
+mod model;
+pub mod person_repository;
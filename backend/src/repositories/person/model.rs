@@ -0,0 +1,56 @@
+use crate::domain::entity::person::Person;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::name::Name;
+use crate::domain::vo::tax_id::{Document, TaxId};
+use crate::domain::vo::temporal::{Date, DateTime};
+use chrono::{DateTime as ChronoDateTime, NaiveDate, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Queryable, Selectable, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = crate::repositories::schema::persons)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PersonModel {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub social_name: Option<String>,
+    pub birth_date: NaiveDate,
+    pub tax_id_kind: String,
+    pub tax_id: String,
+    pub created_at: ChronoDateTime<Utc>,
+    pub updated_at: ChronoDateTime<Utc>,
+}
+
+impl From<PersonModel> for Person {
+    fn from(model: PersonModel) -> Self {
+        Self {
+            id: Id::new_from_string(model.id).unwrap(),
+            user_id: Id::new_from_string(model.user_id).unwrap(),
+            name: Name::new(model.name).unwrap(),
+            birth_date: Date::new_from_naive_date(model.birth_date),
+            social_name: model
+                .social_name
+                .map(|social_name| Name::new(social_name).unwrap()),
+            tax_id: TaxId::new_from_kind(model.tax_id_kind, model.tax_id).unwrap(),
+            created_at: DateTime::new_from_date_time(model.created_at),
+            updated_at: DateTime::new_from_date_time(model.updated_at),
+        }
+    }
+}
+
+impl From<Person> for PersonModel {
+    fn from(person: Person) -> Self {
+        Self {
+            id: person.id.value(),
+            user_id: person.user_id.value(),
+            name: person.name.value(),
+            social_name: person.social_name.map(|social_name| social_name.value()),
+            birth_date: person.birth_date.to_naive_date(),
+            tax_id_kind: person.tax_id.kind(),
+            tax_id: Document::format(&person.tax_id),
+            created_at: person.created_at.to_chono_date_time(),
+            updated_at: person.updated_at.to_chono_date_time(),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::person::Person;
+use crate::domain::vo::id::Id;
+use crate::infrastructure::postgres::PostgresBaseRepository;
+use crate::repositories::person::model::PersonModel;
+use crate::repositories::schema::persons;
+use crate::repositories::schema::persons::{
+    birth_date, id, name, social_name, tax_id, tax_id_kind, updated_at, user_id,
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::{insert_into, update};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const PERSONS_USER_ID_UNIQUE: &str = "persons_user_id_unique";
+const PERSONS_TAX_ID_UNIQUE: &str = "persons_tax_id_unique";
+
+#[async_trait]
+pub trait PersonRepository: Send + Sync {
+    async fn save(&self, person: &Person) -> ResultApp<Person>;
+    async fn find_by_user_id(&self, user_id: &Id) -> ResultApp<Option<Person>>;
+    // Everything but the owning user and the creation date can change
+    async fn update(&self, person: &Person) -> ResultApp<Option<Person>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PersonRepositoryPostgres {
+    pub base_repository: PostgresBaseRepository,
+}
+
+impl PersonRepositoryPostgres {
+    pub fn new(base_repository: PostgresBaseRepository) -> Self {
+        PersonRepositoryPostgres { base_repository }
+    }
+}
+
+#[async_trait]
+impl PersonRepository for PersonRepositoryPostgres {
+    async fn save(&self, person: &Person) -> ResultApp<Person> {
+        let person_model = PersonModel::from(person.clone());
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let insert_result = insert_into(persons::table)
+            .values(&person_model)
+            .returning(PersonModel::as_returning())
+            .get_result(&mut connection_result.unwrap());
+
+        match insert_result {
+            Ok(model) => Ok(Person::from(model)),
+            Err(err) => Err(Arc::new(map_write_error(err, person))),
+        }
+    }
+
+    async fn find_by_user_id(&self, person_user_id: &Id) -> ResultApp<Option<Person>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let person_response = persons::table
+            .filter(user_id.eq(person_user_id.value()))
+            .select(PersonModel::as_select())
+            .first(&mut connection_result.unwrap())
+            .optional();
+
+        match person_response {
+            Ok(model) => Ok(model.map(Person::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn update(&self, person: &Person) -> ResultApp<Option<Person>> {
+        let person_model = PersonModel::from(person.clone());
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let update_result = update(persons::table.filter(id.eq(&person_model.id)))
+            .set((
+                name.eq(&person_model.name),
+                social_name.eq(&person_model.social_name),
+                birth_date.eq(person_model.birth_date),
+                tax_id_kind.eq(&person_model.tax_id_kind),
+                tax_id.eq(&person_model.tax_id),
+                updated_at.eq(chrono::Utc::now()),
+            ))
+            .returning(PersonModel::as_returning())
+            .get_result(&mut connection_result.unwrap())
+            .optional();
+
+        match update_result {
+            Ok(model) => Ok(model.map(Person::from)),
+            Err(err) => Err(Arc::new(map_write_error(err, person))),
+        }
+    }
+}
+
+fn map_write_error(err: DieselError, person: &Person) -> AppError {
+    match &err {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            if info.constraint_name() == Some(PERSONS_USER_ID_UNIQUE) =>
+        {
+            AppError::Conflict(
+                ErrorData::new(
+                    "person-already-exists",
+                    "the user already has a person profile",
+                )
+                .with_cause(Some(Arc::new(err))),
+            )
+        }
+        // Only the masked form goes back, the full number is personal data
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            if info.constraint_name() == Some(PERSONS_TAX_ID_UNIQUE) =>
+        {
+            AppError::Conflict(
+                ErrorData::new(
+                    "tax-id-already-registered",
+                    "the tax id belongs to another person",
+                )
+                .with_args(HashMap::from([(
+                    "tax_id".to_string(),
+                    person.tax_id.masked(),
+                )]))
+                .with_cause(Some(Arc::new(err))),
+            )
+        }
+        _ => AppError::Database(
+            ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+        ),
+    }
+}
@@ -0,0 +1,2 @@
+mod model;
+pub mod refresh_token_repository;
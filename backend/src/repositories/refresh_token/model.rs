@@ -0,0 +1,55 @@
+use crate::domain::entity::refresh_token::RefreshToken;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::temporal::DateTime;
+use chrono::{DateTime as ChronoDateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Queryable, Selectable, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = crate::repositories::schema::refresh_tokens)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RefreshTokenModel {
+    pub id: String,
+    pub user_id: String,
+    pub family_id: String,
+    pub token_hash: String,
+    pub expires_at: ChronoDateTime<Utc>,
+    pub revoked: bool,
+    pub revoked_at: Option<ChronoDateTime<Utc>>,
+    pub replaced_by: Option<String>,
+    pub created_at: ChronoDateTime<Utc>,
+}
+
+impl From<RefreshTokenModel> for RefreshToken {
+    fn from(model: RefreshTokenModel) -> Self {
+        Self {
+            id: Id::new_from_string(model.id).unwrap(),
+            user_id: Id::new_from_string(model.user_id).unwrap(),
+            family_id: Id::new_from_string(model.family_id).unwrap(),
+            token_hash: model.token_hash,
+            expires_at: DateTime::new_from_date_time(model.expires_at),
+            revoked: model.revoked,
+            revoked_at: model.revoked_at.map(DateTime::new_from_date_time),
+            replaced_by: model
+                .replaced_by
+                .map(|replaced_by| Id::new_from_string(replaced_by).unwrap()),
+            created_at: DateTime::new_from_date_time(model.created_at),
+        }
+    }
+}
+
+impl From<RefreshToken> for RefreshTokenModel {
+    fn from(token: RefreshToken) -> Self {
+        Self {
+            id: token.id.value(),
+            user_id: token.user_id.value(),
+            family_id: token.family_id.value(),
+            token_hash: token.token_hash,
+            expires_at: token.expires_at.to_chono_date_time(),
+            revoked: token.revoked,
+            revoked_at: token.revoked_at.map(|dt| dt.to_chono_date_time()),
+            replaced_by: token.replaced_by.map(|replaced_by| replaced_by.value()),
+            created_at: token.created_at.to_chono_date_time(),
+        }
+    }
+}
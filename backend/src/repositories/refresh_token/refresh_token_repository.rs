@@ -0,0 +1,153 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::refresh_token::RefreshToken;
+use crate::domain::vo::id::Id;
+use crate::infrastructure::postgres::PostgresBaseRepository;
+use crate::repositories::refresh_token::model::RefreshTokenModel;
+use crate::repositories::schema::refresh_tokens;
+use crate::repositories::schema::refresh_tokens::{
+    family_id, id, replaced_by, revoked, revoked_at, token_hash,
+};
+use async_trait::async_trait;
+use diesel::insert_into;
+use diesel::prelude::*;
+use diesel::update;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+    async fn save(&self, refresh_token: &RefreshToken) -> ResultApp<RefreshToken>;
+    async fn find_by_token_hash(&self, hash: &str) -> ResultApp<Option<RefreshToken>>;
+    // Returns false when the token had already been revoked by a concurrent call
+    async fn revoke(&self, token_id: &Id, replacement_id: Option<&Id>) -> ResultApp<bool>;
+    async fn revoke_family(&self, family: &Id) -> ResultApp<usize>;
+}
+
+#[derive(Debug, Clone)]
+pub struct RefreshTokenRepositoryPostgres {
+    pub base_repository: PostgresBaseRepository,
+}
+
+impl RefreshTokenRepositoryPostgres {
+    pub fn new(base_repository: PostgresBaseRepository) -> Self {
+        RefreshTokenRepositoryPostgres { base_repository }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RefreshTokenRepositoryPostgres {
+    async fn save(&self, refresh_token: &RefreshToken) -> ResultApp<RefreshToken> {
+        let refresh_token_model = RefreshTokenModel::from(refresh_token.clone());
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let insert_result = insert_into(refresh_tokens::table)
+            .values(&refresh_token_model)
+            .get_result::<RefreshTokenModel>(&mut connection_result.unwrap());
+
+        match insert_result {
+            Ok(model) => Ok(RefreshToken::from(model)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_by_token_hash(&self, hash: &str) -> ResultApp<Option<RefreshToken>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let refresh_token_response = refresh_tokens::table
+            .filter(token_hash.eq(hash))
+            .select(RefreshTokenModel::as_select())
+            .first(&mut connection_result.unwrap())
+            .optional();
+
+        match refresh_token_response {
+            Ok(Some(model)) => Ok(Some(RefreshToken::from(model))),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn revoke(&self, token_id: &Id, replacement_id: Option<&Id>) -> ResultApp<bool> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let current_time = chrono::Utc::now();
+        let updated_result = update(
+            refresh_tokens::table
+                .filter(id.eq(token_id.value()))
+                .filter(revoked.eq(false)),
+        )
+        .set((
+            revoked.eq(true),
+            revoked_at.eq(current_time),
+            replaced_by.eq(replacement_id.map(|r| r.value())),
+        ))
+        .execute(&mut connection_result.unwrap());
+
+        match updated_result {
+            Ok(rows) => Ok(rows == 1),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn revoke_family(&self, family: &Id) -> ResultApp<usize> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let current_time = chrono::Utc::now();
+        let updated_result = update(
+            refresh_tokens::table
+                .filter(family_id.eq(family.value()))
+                .filter(revoked.eq(false)),
+        )
+        .set((revoked.eq(true), revoked_at.eq(current_time)))
+        .execute(&mut connection_result.unwrap());
+
+        match updated_result {
+            Ok(rows) => Ok(rows),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+}
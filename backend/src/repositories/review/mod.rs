@@ -0,0 +1,2 @@
+mod model;
+pub mod review_repository;
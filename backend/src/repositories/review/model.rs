@@ -0,0 +1,55 @@
+use crate::domain::entity::review::Review;
+use crate::domain::vo::description::Description;
+use crate::domain::vo::id::Id;
+use crate::domain::vo::rating::Rating;
+use crate::domain::vo::temporal::DateTime;
+use chrono::{DateTime as ChronoDateTime, Utc};
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Serialize, Deserialize)]
+#[diesel(table_name = crate::repositories::schema::reviews)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ReviewModel {
+    pub id: String,
+    pub customer_service_id: String,
+    pub author_id: String,
+    pub rating: i16,
+    pub text: String,
+    pub deleted: bool,
+    pub created_at: ChronoDateTime<Utc>,
+    pub updated_at: ChronoDateTime<Utc>,
+    pub deleted_at: Option<ChronoDateTime<Utc>>,
+}
+
+impl From<ReviewModel> for Review {
+    fn from(model: ReviewModel) -> Self {
+        Self {
+            id: Id::new_from_string(model.id).unwrap(),
+            customer_service_id: Id::new_from_string(model.customer_service_id).unwrap(),
+            author_id: Id::new_from_string(model.author_id).unwrap(),
+            rating: Rating::new(model.rating).unwrap(),
+            text: Description::new(model.text).unwrap(),
+            deleted: model.deleted,
+            created_at: DateTime::new_from_date_time(model.created_at),
+            updated_at: DateTime::new_from_date_time(model.updated_at),
+            deleted_at: model.deleted_at.map(DateTime::new_from_date_time),
+        }
+    }
+}
+
+impl From<Review> for ReviewModel {
+    fn from(review: Review) -> Self {
+        Self {
+            id: review.id.value(),
+            customer_service_id: review.customer_service_id.value(),
+            author_id: review.author_id.value(),
+            rating: review.rating.value(),
+            text: review.text.value(),
+            deleted: review.deleted,
+            created_at: review.created_at.to_chono_date_time(),
+            updated_at: review.updated_at.to_chono_date_time(),
+            deleted_at: review.deleted_at.map(|dt| dt.to_chono_date_time()),
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use crate::common::error::{AppError, ErrorData};
+use crate::common::result::ResultApp;
+use crate::domain::entity::review::Review;
+use crate::domain::vo::id::Id;
+use crate::infrastructure::postgres::PostgresBaseRepository;
+use crate::repositories::review::model::ReviewModel;
+use crate::repositories::schema::customer_services;
+use crate::repositories::schema::reviews;
+use crate::repositories::schema::reviews::{
+    author_id, created_at, customer_service_id, deleted, deleted_at, hidden, id, rating, updated_at,
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::{insert_into, update};
+use std::sync::Arc;
+
+const REVIEWS_CUSTOMER_SERVICE_AUTHOR_UNIQUE: &str = "reviews_customer_service_author_unique";
+
+// Every write also moves the rating totals of the place in the same transaction, so they never
+// drift from the reviews that are not deleted
+#[async_trait]
+pub trait ReviewRepository: Send + Sync {
+    async fn save(&self, review: &Review) -> ResultApp<Review>;
+    async fn find_by_id(&self, id: &Id) -> ResultApp<Option<Review>>;
+    // Newest first
+    async fn find_by_customer_service(
+        &self,
+        customer_service_id: &Id,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<Review>>;
+    async fn update(&self, review: &Review) -> ResultApp<Option<Review>>;
+    async fn delete(&self, id: &Id) -> ResultApp<Option<Review>>;
+    // Takes the review out of public reads and out of the rating totals of the place
+    async fn hide(&self, id: &Id) -> ResultApp<Option<Review>>;
+    // Reviews written since the instant, deleted and hidden ones included
+    async fn count_by_author_since(
+        &self,
+        author: &Id,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> ResultApp<i64>;
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewRepositoryPostgres {
+    pub base_repository: PostgresBaseRepository,
+}
+
+impl ReviewRepositoryPostgres {
+    pub fn new(base_repository: PostgresBaseRepository) -> Self {
+        ReviewRepositoryPostgres { base_repository }
+    }
+}
+
+// Adds `count` reviews worth `sum` stars to the totals of the place, both negative on removal
+fn move_rating_totals(
+    connection: &mut PgConnection,
+    customer_service: &str,
+    count: i32,
+    sum: i32,
+) -> QueryResult<usize> {
+    update(customer_services::table.find(customer_service))
+        .set((
+            customer_services::rating_count.eq(customer_services::rating_count + count),
+            customer_services::rating_sum.eq(customer_services::rating_sum + sum),
+        ))
+        .execute(connection)
+}
+
+#[async_trait]
+impl ReviewRepository for ReviewRepositoryPostgres {
+    async fn save(&self, review: &Review) -> ResultApp<Review> {
+        let review_model = ReviewModel::from(review.clone());
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let mut connection = connection_result.unwrap();
+        let insert_result = connection.transaction::<_, DieselError, _>(|connection| {
+            let model = insert_into(reviews::table)
+                .values(&review_model)
+                .returning(ReviewModel::as_returning())
+                .get_result(connection)?;
+            move_rating_totals(
+                connection,
+                &model.customer_service_id,
+                1,
+                model.rating.into(),
+            )?;
+            Ok(model)
+        });
+
+        match insert_result {
+            Ok(model) => Ok(Review::from(model)),
+            Err(err) => Err(Arc::new(map_write_error(err))),
+        }
+    }
+
+    async fn find_by_id(&self, review_id: &Id) -> ResultApp<Option<Review>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let review_response = reviews::table
+            .filter(id.eq(review_id.value()))
+            .filter(deleted.eq(false))
+            .filter(hidden.eq(false))
+            .select(ReviewModel::as_select())
+            .first(&mut connection_result.unwrap())
+            .optional();
+
+        match review_response {
+            Ok(model) => Ok(model.map(Review::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_by_customer_service(
+        &self,
+        reviewed_id: &Id,
+        limit: i64,
+        offset: i64,
+    ) -> ResultApp<Vec<Review>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let reviews_response = reviews::table
+            .filter(customer_service_id.eq(reviewed_id.value()))
+            .filter(deleted.eq(false))
+            .filter(hidden.eq(false))
+            .order((created_at.desc(), id.asc()))
+            .limit(limit)
+            .offset(offset)
+            .select(ReviewModel::as_select())
+            .load(&mut connection_result.unwrap());
+
+        match reviews_response {
+            Ok(models) => Ok(models.into_iter().map(Review::from).collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn update(&self, review: &Review) -> ResultApp<Option<Review>> {
+        let mut review_model = ReviewModel::from(review.clone());
+        review_model.updated_at = chrono::Utc::now();
+
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let mut connection = connection_result.unwrap();
+        let updated_result = connection.transaction::<_, DieselError, _>(|connection| {
+            // Locked so a concurrent edit cannot apply its delta against a stale rating
+            let previous_rating = reviews::table
+                .filter(id.eq(&review_model.id))
+                .filter(deleted.eq(false))
+                .filter(hidden.eq(false))
+                .select(rating)
+                .for_update()
+                .first::<i16>(connection)
+                .optional()?;
+            let Some(previous_rating) = previous_rating else {
+                return Ok(None);
+            };
+
+            let model = update(reviews::table.find(&review_model.id))
+                .set(&review_model)
+                .returning(ReviewModel::as_returning())
+                .get_result(connection)?;
+            move_rating_totals(
+                connection,
+                &model.customer_service_id,
+                0,
+                (model.rating - previous_rating).into(),
+            )?;
+            Ok(Some(model))
+        });
+
+        match updated_result {
+            Ok(model) => Ok(model.map(Review::from)),
+            Err(err) => Err(Arc::new(map_write_error(err))),
+        }
+    }
+
+    async fn delete(&self, review_id: &Id) -> ResultApp<Option<Review>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let current_time = chrono::Utc::now();
+        let mut connection = connection_result.unwrap();
+        let deleted_result = connection.transaction::<_, DieselError, _>(|connection| {
+            let model = update(
+                reviews::table
+                    .filter(id.eq(review_id.value()))
+                    .filter(deleted.eq(false))
+                    .filter(hidden.eq(false)),
+            )
+            .set((
+                updated_at.eq(current_time),
+                deleted.eq(true),
+                deleted_at.eq(current_time),
+            ))
+            .returning(ReviewModel::as_returning())
+            .get_result(connection)
+            .optional()?;
+            if let Some(model) = &model {
+                move_rating_totals(
+                    connection,
+                    &model.customer_service_id,
+                    -1,
+                    -i32::from(model.rating),
+                )?;
+            }
+            Ok(model)
+        });
+
+        match deleted_result {
+            Ok(model) => Ok(model.map(Review::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn hide(&self, review_id: &Id) -> ResultApp<Option<Review>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let mut connection = connection_result.unwrap();
+        let hidden_result = connection.transaction::<_, DieselError, _>(|connection| {
+            let model = update(
+                reviews::table
+                    .filter(id.eq(review_id.value()))
+                    .filter(deleted.eq(false))
+                    .filter(hidden.eq(false)),
+            )
+            .set((updated_at.eq(chrono::Utc::now()), hidden.eq(true)))
+            .returning(ReviewModel::as_returning())
+            .get_result(connection)
+            .optional()?;
+            if let Some(model) = &model {
+                move_rating_totals(
+                    connection,
+                    &model.customer_service_id,
+                    -1,
+                    -i32::from(model.rating),
+                )?;
+            }
+            Ok(model)
+        });
+
+        match hidden_result {
+            Ok(model) => Ok(model.map(Review::from)),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn count_by_author_since(
+        &self,
+        author: &Id,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> ResultApp<i64> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let count_response = reviews::table
+            .filter(author_id.eq(author.value()))
+            .filter(created_at.ge(since))
+            .count()
+            .get_result::<i64>(&mut connection_result.unwrap());
+
+        match count_response {
+            Ok(count) => Ok(count),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+}
+
+fn map_write_error(err: DieselError) -> AppError {
+    match &err {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            if info.constraint_name() == Some(REVIEWS_CUSTOMER_SERVICE_AUTHOR_UNIQUE) =>
+        {
+            AppError::Conflict(
+                ErrorData::new(
+                    "review-already-exists",
+                    "the user already reviewed this customer service",
+                )
+                .with_cause(Some(Arc::new(err))),
+            )
+        }
+        _ => AppError::Database(
+            ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+        ),
+    }
+}
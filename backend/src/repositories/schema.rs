@@ -1,5 +1,229 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    categories (id) {
+        #[max_length = 36]
+        id -> Varchar,
+        #[max_length = 50]
+        slug -> Varchar,
+        #[max_length = 50]
+        parent_slug -> Nullable<Varchar>,
+        labels -> Jsonb,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    customer_services (id) {
+        #[max_length = 36]
+        id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 2048]
+        description -> Varchar,
+        latitude -> Float8,
+        longitude -> Float8,
+        #[max_length = 20]
+        phone -> Varchar,
+        #[max_length = 2048]
+        website -> Nullable<Varchar>,
+        photos -> Jsonb,
+        tags -> Jsonb,
+        categories -> Array<Text>,
+        deleted -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+        #[max_length = 4]
+        geohash_4 -> Varchar,
+        #[max_length = 6]
+        geohash_6 -> Varchar,
+        #[max_length = 8]
+        geohash_8 -> Varchar,
+        #[max_length = 1024]
+        opening_hours -> Nullable<Varchar>,
+        #[max_length = 64]
+        timezone -> Varchar,
+        #[max_length = 2]
+        state -> Nullable<Varchar>,
+        #[max_length = 120]
+        city -> Nullable<Varchar>,
+        rating_count -> Int4,
+        rating_sum -> Int4,
+        hidden -> Bool,
+        #[max_length = 200]
+        address_street -> Nullable<Varchar>,
+        #[max_length = 20]
+        address_number -> Nullable<Varchar>,
+        #[max_length = 100]
+        address_complement -> Nullable<Varchar>,
+        #[max_length = 120]
+        address_neighbourhood -> Nullable<Varchar>,
+        #[max_length = 10]
+        address_postal_code -> Nullable<Varchar>,
+        #[max_length = 2]
+        address_country -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    holidays (id) {
+        #[max_length = 36]
+        id -> Varchar,
+        #[max_length = 120]
+        name -> Varchar,
+        date -> Date,
+        recurring -> Bool,
+        #[max_length = 2]
+        state -> Nullable<Varchar>,
+        #[max_length = 120]
+        city -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    moderation_flags (id) {
+        #[max_length = 36]
+        id -> Varchar,
+        #[max_length = 20]
+        subject_type -> Varchar,
+        #[max_length = 36]
+        subject_id -> Varchar,
+        #[max_length = 20]
+        reason -> Varchar,
+        #[max_length = 36]
+        reporter_id -> Nullable<Varchar>,
+        #[max_length = 2048]
+        note -> Varchar,
+        #[max_length = 10]
+        status -> Varchar,
+        #[max_length = 36]
+        moderator_id -> Nullable<Varchar>,
+        decided_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+        #[max_length = 255]
+        previous_name -> Nullable<Varchar>,
+        #[max_length = 2048]
+        previous_description -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    ownership_claims (id) {
+        #[max_length = 36]
+        id -> Varchar,
+        #[max_length = 36]
+        customer_service_id -> Varchar,
+        #[max_length = 36]
+        claimant_id -> Varchar,
+        #[max_length = 14]
+        tax_id -> Varchar,
+        #[max_length = 10]
+        status -> Varchar,
+        #[max_length = 36]
+        reviewer_id -> Nullable<Varchar>,
+        decided_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    permissions (name) {
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 255]
+        description -> Varchar,
+    }
+}
+
+diesel::table! {
+    persons (id) {
+        #[max_length = 36]
+        id -> Varchar,
+        #[max_length = 36]
+        user_id -> Varchar,
+        #[max_length = 120]
+        name -> Varchar,
+        #[max_length = 120]
+        social_name -> Nullable<Varchar>,
+        birth_date -> Date,
+        #[max_length = 20]
+        tax_id_kind -> Varchar,
+        #[max_length = 14]
+        tax_id -> Varchar,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    refresh_tokens (id) {
+        #[max_length = 36]
+        id -> Varchar,
+        #[max_length = 36]
+        user_id -> Varchar,
+        #[max_length = 36]
+        family_id -> Varchar,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        expires_at -> Timestamptz,
+        revoked -> Bool,
+        revoked_at -> Nullable<Timestamptz>,
+        #[max_length = 36]
+        replaced_by -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    reviews (id) {
+        #[max_length = 36]
+        id -> Varchar,
+        #[max_length = 36]
+        customer_service_id -> Varchar,
+        #[max_length = 36]
+        author_id -> Varchar,
+        rating -> Int2,
+        #[max_length = 2048]
+        text -> Varchar,
+        deleted -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+        hidden -> Bool,
+    }
+}
+
+diesel::table! {
+    role_permissions (role_name, permission_name) {
+        #[max_length = 50]
+        role_name -> Varchar,
+        #[max_length = 100]
+        permission_name -> Varchar,
+    }
+}
+
+diesel::table! {
+    roles (name) {
+        #[max_length = 50]
+        name -> Varchar,
+        #[max_length = 255]
+        description -> Varchar,
+    }
+}
+
+diesel::table! {
+    user_roles (user_id, role_name) {
+        #[max_length = 36]
+        user_id -> Varchar,
+        #[max_length = 50]
+        role_name -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     users (id) {
         #[max_length = 36]
@@ -16,3 +240,29 @@ diesel::table! {
         deleted_at -> Nullable<Timestamptz>,
     }
 }
+
+diesel::joinable!(ownership_claims -> customer_services (customer_service_id));
+diesel::joinable!(persons -> users (user_id));
+diesel::joinable!(refresh_tokens -> users (user_id));
+diesel::joinable!(reviews -> customer_services (customer_service_id));
+diesel::joinable!(reviews -> users (author_id));
+diesel::joinable!(role_permissions -> permissions (permission_name));
+diesel::joinable!(role_permissions -> roles (role_name));
+diesel::joinable!(user_roles -> roles (role_name));
+diesel::joinable!(user_roles -> users (user_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    categories,
+    customer_services,
+    holidays,
+    moderation_flags,
+    ownership_claims,
+    permissions,
+    persons,
+    refresh_tokens,
+    reviews,
+    role_permissions,
+    roles,
+    user_roles,
+    users,
+);
@@ -51,3 +51,12 @@ impl From<User> for UserModel {
         }
     }
 }
+
+#[derive(Queryable, Selectable, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = crate::repositories::schema::user_roles)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct UserRoleModel {
+    pub user_id: String,
+    pub role_name: String,
+    pub created_at: ChronoDateTime<Utc>,
+}
@@ -3,26 +3,37 @@ use crate::common::result::ResultApp;
 use crate::domain::entity::user::User;
 use crate::domain::vo::email::Email;
 use crate::domain::vo::id::Id;
+use crate::domain::vo::permission::Permission;
+use crate::domain::vo::role::Role;
 use crate::infrastructure::postgres::PostgresBaseRepository;
-use crate::repositories::schema::users;
 use crate::repositories::schema::users::dsl::users as users_dsl;
 use crate::repositories::schema::users::{
     deleted, deleted_at, email, id, name, password, updated_at,
 };
-use crate::repositories::user::model::UserModel;
+use crate::repositories::schema::{role_permissions, user_roles, users};
+use crate::repositories::user::model::{UserModel, UserRoleModel};
 use async_trait::async_trait;
 use diesel::insert_into;
 use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 use diesel::update;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+const USERS_EMAIL_UNIQUE_INDEX: &str = "users_email_unique_idx";
+
 #[async_trait]
 pub trait UserRepository: Send + Sync {
-    async fn save(&self, user: &User) -> ResultApp<User>;
+    // The user and its first role are inserted in one transaction, no user is left without a role
+    async fn save_with_role(&self, user: &User, role: Role) -> ResultApp<User>;
     async fn find_by_id(&self, id: &Id) -> ResultApp<Option<User>>;
     async fn find_by_email(&self, email: &Email) -> ResultApp<Option<User>>;
     async fn delete(&self, id: &Id) -> ResultApp<Option<User>>;
     async fn update(&self, user: &User) -> ResultApp<Option<User>>;
+    async fn find_roles(&self, user_id: &Id) -> ResultApp<HashSet<Role>>;
+    async fn find_permissions(&self, user_id: &Id) -> ResultApp<HashSet<Permission>>;
+    async fn add_role(&self, user_id: &Id, role: Role) -> ResultApp<()>;
+    async fn remove_role(&self, user_id: &Id, role: Role) -> ResultApp<()>;
 }
 
 #[derive(Debug, Clone)]
@@ -38,8 +49,13 @@ impl UserRepositoryPostgres {
 
 #[async_trait]
 impl UserRepository for UserRepositoryPostgres {
-    async fn save(&self, user: &User) -> ResultApp<User> {
-        let payment_model = UserModel::from(user.clone());
+    async fn save_with_role(&self, user: &User, role: Role) -> ResultApp<User> {
+        let user_model = UserModel::from(user.clone());
+        let user_role_model = UserRoleModel {
+            user_id: user.id.value(),
+            role_name: role.value(),
+            created_at: chrono::Utc::now(),
+        };
 
         let connection_result = self.base_repository.pool.get();
         if let Err(err) = connection_result {
@@ -50,17 +66,18 @@ impl UserRepository for UserRepositoryPostgres {
         }
 
         let mut connection = connection_result.unwrap();
+        let insert_result = connection.transaction::<_, DieselError, _>(|connection| {
+            insert_into(users::table)
+                .values(&user_model)
+                .execute(connection)?;
+            insert_into(user_roles::table)
+                .values(&user_role_model)
+                .execute(connection)?;
+            Ok(())
+        });
 
-        let insert_result = insert_into(users::table)
-            .values(&payment_model)
-            .get_result::<UserModel>(&mut connection);
-
-        if insert_result.is_err() {
-            let app_error = AppError::Database(
-                ErrorData::new("internal", "database error")
-                    .with_cause(Some(Arc::new(insert_result.err().unwrap()))),
-            );
-            return Err(Arc::new(app_error));
+        if let Err(err) = insert_result {
+            return Err(Arc::new(map_write_error(err)));
         }
 
         Ok(user.clone())
@@ -172,6 +189,92 @@ impl UserRepository for UserRepositoryPostgres {
 
         match updated_result {
             Ok(user) => Ok(Some(User::from(user))),
+            Err(err) => Err(Arc::new(map_write_error(err))),
+        }
+    }
+
+    async fn find_roles(&self, user_id: &Id) -> ResultApp<HashSet<Role>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let roles_response = user_roles::table
+            .filter(user_roles::user_id.eq(user_id.value()))
+            .select(user_roles::role_name)
+            .load::<String>(&mut connection_result.unwrap());
+
+        match roles_response {
+            Ok(role_names) => Ok(role_names
+                .into_iter()
+                .filter_map(|role_name| Role::new(role_name).ok())
+                .collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn find_permissions(&self, user_id: &Id) -> ResultApp<HashSet<Permission>> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let user_role_names = user_roles::table
+            .filter(user_roles::user_id.eq(user_id.value()))
+            .select(user_roles::role_name);
+        let permissions_response = role_permissions::table
+            .filter(role_permissions::role_name.eq_any(user_role_names))
+            .select(role_permissions::permission_name)
+            .distinct()
+            .load::<String>(&mut connection_result.unwrap());
+
+        match permissions_response {
+            // Permissions unknown to this build are ignored rather than failing the login
+            Ok(permission_names) => Ok(permission_names
+                .into_iter()
+                .filter_map(|permission_name| Permission::new(permission_name).ok())
+                .collect()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+
+    async fn add_role(&self, user_id: &Id, role: Role) -> ResultApp<()> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let user_role_model = UserRoleModel {
+            user_id: user_id.value(),
+            role_name: role.value(),
+            created_at: chrono::Utc::now(),
+        };
+        let insert_result = insert_into(user_roles::table)
+            .values(&user_role_model)
+            .on_conflict_do_nothing()
+            .execute(&mut connection_result.unwrap());
+
+        match insert_result {
+            Ok(_) => Ok(()),
             Err(err) => {
                 let app_error = AppError::Database(
                     ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
@@ -180,4 +283,44 @@ impl UserRepository for UserRepositoryPostgres {
             }
         }
     }
+
+    async fn remove_role(&self, user_id: &Id, role: Role) -> ResultApp<()> {
+        let connection_result = self.base_repository.pool.get();
+        if let Err(err) = connection_result {
+            let app_error = AppError::Database(
+                ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+            );
+            return Err(Arc::new(app_error));
+        }
+
+        let delete_result = diesel::delete(
+            user_roles::table
+                .filter(user_roles::user_id.eq(user_id.value()))
+                .filter(user_roles::role_name.eq(role.value())),
+        )
+        .execute(&mut connection_result.unwrap());
+
+        match delete_result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                let app_error = AppError::Database(
+                    ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))),
+                );
+                Err(Arc::new(app_error))
+            }
+        }
+    }
+}
+
+// Unique violations on the email index are a business conflict, not a database failure
+fn map_write_error(err: DieselError) -> AppError {
+    if let DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info) = &err
+        && info.constraint_name() == Some(USERS_EMAIL_UNIQUE_INDEX)
+    {
+        return AppError::Conflict(
+            ErrorData::new("email-already-registered", "email already registered")
+                .with_cause(Some(Arc::new(err))),
+        );
+    }
+    AppError::Database(ErrorData::new("internal", "database error").with_cause(Some(Arc::new(err))))
 }